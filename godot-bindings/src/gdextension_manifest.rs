@@ -0,0 +1,287 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Builder for the `.gdextension` file, through which Godot loads a Rust library.
+///
+/// This is the one part of `godot-bindings` intended for direct use; add it as a dev-dependency with the same version as `godot`.
+///
+/// The entry symbol, the API version and class icons are only known to the compiled extension crate, so the file is written from a test
+/// (or small binary) of that crate. This keeps it in sync with `#[gdextension(entry_symbol = ...)]`, the `api-4-x` feature of `godot` and
+/// `#[class(icon = "res://...")]`, without a running engine.
+///
+/// # Example
+/// ```ignore
+/// // In the extension crate, e.g. as #[test] next to the `#[gdextension]` impl.
+/// use godot::init::{class_icons, compatibility_minimum, ExtensionEntrySymbol};
+/// use godot_bindings::GdextensionManifest;
+///
+/// #[test]
+/// fn write_gdextension_file() {
+///     GdextensionManifest::new(MyExtension::ENTRY_SYMBOL, compatibility_minimum())
+///         .reloadable(true)
+///         .standard_libraries("my_crate", "res://../rust/target")
+///         .icons(class_icons())
+///         .write("../godot/my_crate.gdextension")
+///         .expect("write .gdextension file");
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct GdextensionManifest {
+    entry_symbol: String,
+    compatibility_minimum: String,
+    reloadable: bool,
+    libraries: Vec<(String, String)>,
+    icons: Vec<(String, String)>,
+    dependencies: Vec<(String, Vec<String>)>,
+}
+
+impl GdextensionManifest {
+    /// Creates a manifest with the given entry symbol and minimum Godot version.
+    ///
+    /// Pass `godot::init::ExtensionEntrySymbol::ENTRY_SYMBOL` of the extension library and `godot::init::compatibility_minimum()`, so
+    /// that both are derived from the compiled crate.
+    pub fn new(entry_symbol: impl Into<String>, compatibility_minimum: impl Into<String>) -> Self {
+        Self {
+            entry_symbol: entry_symbol.into(),
+            compatibility_minimum: compatibility_minimum.into(),
+            reloadable: false,
+            libraries: Vec::new(),
+            icons: Vec::new(),
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Whether the extension can be hot-reloaded while the editor is running (`false` by default).
+    ///
+    /// Hot reloading is supported from Godot 4.2 onwards.
+    pub fn reloadable(mut self, reloadable: bool) -> Self {
+        self.reloadable = reloadable;
+        self
+    }
+
+    /// Adds a library path for the given feature tags, e.g. `"linux.debug.x86_64"` and `"res://bin/libmy_crate.so"`.
+    pub fn library(mut self, tags: impl Into<String>, path: impl Into<String>) -> Self {
+        self.libraries.push((tags.into(), path.into()));
+        self
+    }
+
+    /// Adds library paths for Linux, Windows and macOS, in debug and release builds, with Cargo's default file names.
+    ///
+    /// `crate_name` is the name of the library crate (dashes are converted to underscores, like Cargo does). `target_dir` is the path of
+    /// the Cargo target directory, relative to the Godot project, for example `"res://../rust/target"`.
+    ///
+    /// The entries don't specify an architecture, as a plain `cargo build` only produces a library for the host. For cross-compiled
+    /// builds (located in `target/<triple>/...`) or universal macOS libraries, use [`library()`][Self::library] instead.
+    pub fn standard_libraries(mut self, crate_name: &str, target_dir: &str) -> Self {
+        let lib = crate_name.replace('-', "_");
+        let dir = target_dir.trim_end_matches('/');
+
+        for profile in ["debug", "release"] {
+            self = self
+                .library(
+                    format!("linux.{profile}"),
+                    format!("{dir}/{profile}/lib{lib}.so"),
+                )
+                .library(
+                    format!("windows.{profile}"),
+                    format!("{dir}/{profile}/{lib}.dll"),
+                )
+                .library(
+                    format!("macos.{profile}"),
+                    format!("{dir}/{profile}/lib{lib}.dylib"),
+                );
+        }
+
+        self
+    }
+
+    /// Adds an icon for a class, replacing a previous icon for the same class.
+    pub fn icon(mut self, class_name: impl Into<String>, path: impl Into<String>) -> Self {
+        let class_name = class_name.into();
+        let path = path.into();
+
+        match self.icons.iter_mut().find(|(name, _)| *name == class_name) {
+            Some(entry) => entry.1 = path,
+            None => self.icons.push((class_name, path)),
+        }
+        self
+    }
+
+    /// Adds icons for multiple classes, e.g. the result of `godot::init::class_icons()`.
+    pub fn icons<C, P>(self, icons: impl IntoIterator<Item = (C, P)>) -> Self
+    where
+        C: Into<String>,
+        P: Into<String>,
+    {
+        icons
+            .into_iter()
+            .fold(self, |manifest, (class_name, path)| {
+                manifest.icon(class_name, path)
+            })
+    }
+
+    /// Adds a file that must be shipped alongside the library for the given feature tags, e.g. a dynamically linked C library.
+    ///
+    /// Multiple dependencies for the same tags are collected in one entry.
+    pub fn dependency(mut self, tags: impl Into<String>, path: impl Into<String>) -> Self {
+        let tags = tags.into();
+        let path = path.into();
+
+        match self.dependencies.iter_mut().find(|(t, _)| *t == tags) {
+            Some((_, paths)) => paths.push(path),
+            None => self.dependencies.push((tags, vec![path])),
+        }
+        self
+    }
+
+    /// Writes the manifest to `path`, typically a file with `.gdextension` extension inside the Godot project.
+    ///
+    /// The file is only touched if its contents change, to avoid unnecessary re-imports by the editor.
+    pub fn write(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        let content = self.to_string();
+
+        if std::fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+            return Ok(());
+        }
+
+        std::fs::write(path, content)
+    }
+}
+
+impl fmt::Display for GdextensionManifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut out = String::new();
+
+        writeln!(out, "[configuration]")?;
+        writeln!(out, "entry_symbol = {}", quoted(&self.entry_symbol))?;
+        writeln!(
+            out,
+            "compatibility_minimum = {}",
+            quoted(&self.compatibility_minimum)
+        )?;
+        writeln!(out, "reloadable = {}", self.reloadable)?;
+
+        if !self.libraries.is_empty() {
+            writeln!(out, "\n[libraries]")?;
+            for (tags, path) in &self.libraries {
+                writeln!(out, "{tags} = {}", quoted(path))?;
+            }
+        }
+
+        if !self.icons.is_empty() {
+            writeln!(out, "\n[icons]")?;
+            for (class_name, path) in &self.icons {
+                writeln!(out, "{class_name} = {}", quoted(path))?;
+            }
+        }
+
+        if !self.dependencies.is_empty() {
+            writeln!(out, "\n[dependencies]")?;
+            for (tags, paths) in &self.dependencies {
+                // Each entry maps source file to target directory within the export; empty means next to the library.
+                let files = paths
+                    .iter()
+                    .map(|path| format!("{}: \"\"", quoted(path)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                writeln!(out, "{tags} = {{ {files} }}")?;
+            }
+        }
+
+        f.write_str(&out)
+    }
+}
+
+/// Formats a string literal in Godot's `ConfigFile` syntax.
+fn quoted(value: &str) -> String {
+    let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{escaped}\"")
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_configuration() {
+        let manifest = GdextensionManifest::new("my_init", "4.2")
+            .reloadable(true)
+            .to_string();
+
+        assert_eq!(
+            manifest,
+            "[configuration]\nentry_symbol = \"my_init\"\ncompatibility_minimum = \"4.2\"\nreloadable = true\n"
+        );
+    }
+
+    #[test]
+    fn manifest_standard_libraries() {
+        let manifest = GdextensionManifest::new("gdext_rust_init", "4.3")
+            .standard_libraries("my-crate", "res://../rust/target/")
+            .to_string();
+
+        let libraries = manifest.split_once("[libraries]\n").unwrap().1;
+        assert_eq!(
+            libraries,
+            "linux.debug = \"res://../rust/target/debug/libmy_crate.so\"\n\
+             windows.debug = \"res://../rust/target/debug/my_crate.dll\"\n\
+             macos.debug = \"res://../rust/target/debug/libmy_crate.dylib\"\n\
+             linux.release = \"res://../rust/target/release/libmy_crate.so\"\n\
+             windows.release = \"res://../rust/target/release/my_crate.dll\"\n\
+             macos.release = \"res://../rust/target/release/libmy_crate.dylib\"\n"
+        );
+    }
+
+    #[test]
+    fn manifest_icons_and_dependencies() {
+        let manifest = GdextensionManifest::new("gdext_rust_init", "4.3")
+            .icons([("Player", "res://player.svg"), ("Enemy", "res://old.svg")])
+            .icon("Enemy", "res://icons/a\"b.svg")
+            .dependency("linux.debug", "res://bin/libdep.so")
+            .dependency("linux.debug", "res://bin/libdep2.so")
+            .to_string();
+
+        assert!(manifest.contains(
+            "\n[icons]\nPlayer = \"res://player.svg\"\nEnemy = \"res://icons/a\\\"b.svg\"\n"
+        ));
+        assert!(manifest.contains(
+            "\n[dependencies]\nlinux.debug = { \"res://bin/libdep.so\": \"\", \"res://bin/libdep2.so\": \"\" }\n"
+        ));
+    }
+
+    #[test]
+    fn manifest_write_only_on_change() {
+        let path = std::env::temp_dir().join(format!(
+            "godot_bindings_manifest_test_{}.gdextension",
+            std::process::id()
+        ));
+        let manifest = GdextensionManifest::new("gdext_rust_init", "4.3");
+
+        manifest.write(&path).unwrap();
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+
+        manifest.write(&path).unwrap();
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().modified().unwrap(),
+            modified
+        );
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            manifest.to_string()
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
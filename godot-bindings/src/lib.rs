@@ -9,6 +9,8 @@
 //!
 //! Do not depend on this crate directly, instead use the `godot` crate.
 //! No SemVer or other guarantees are provided.
+//!
+//! The only exception is [`GdextensionManifest`], which generates the `.gdextension` file from a test or binary of the extension crate.
 
 pub(crate) mod watch;
 
@@ -16,8 +18,11 @@ use std::path::Path;
 
 pub use watch::StopWatch;
 
+mod gdextension_manifest;
 mod import;
 
+pub use gdextension_manifest::GdextensionManifest;
+
 // This is outside of `godot_version` to allow us to use it even when we don't have the `api-custom`
 // feature enabled.
 #[derive(Eq, PartialEq, Debug)]
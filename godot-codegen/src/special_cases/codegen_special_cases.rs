@@ -153,7 +153,11 @@ const SELECTED_CLASSES: &[&str] = &[
     // I/O and save/load
     "ResourceLoader",
     "ResourceSaver",
+    "ResourceFormatLoader", // #[class(resource_format)]
+    "ResourceFormatSaver",
     "FileAccess",
+    "DirAccess",
+    "ProjectSettings", // GPath::globalize()
     //
     // Scene (node_test, rpc_test)
     "MainLoop", // base of SceneTree
@@ -189,5 +193,4 @@ const SELECTED_CLASSES: &[&str] = &[
     // Misc
     "Time", // usage: enum_test.enum_hash()
     "HTTPRequest",
-    "ResourceFormatLoader", // TODO: replace?
 ];
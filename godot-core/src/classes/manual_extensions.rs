@@ -4,9 +4,9 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
-use crate::builtin::NodePath;
-use crate::classes::{Node, PackedScene};
-use crate::meta::{arg_into_ref, AsArg};
+use crate::builtin::{NodePath, StringName};
+use crate::classes::{Ancestors, ChildrenOf, DescendantsOf, Node, PackedScene};
+use crate::meta::{arg_into_owned, arg_into_ref, AsArg};
 use crate::obj::{Gd, Inherits};
 
 /// Manual extensions for the `Node` class.
@@ -43,6 +43,66 @@ impl Node {
         self.get_node_or_null(path)
             .and_then(|node| node.try_cast::<T>().ok())
     }
+
+    /// Iterates over the direct children of type `T` (or inherited), skipping all others.
+    ///
+    /// Unlike `get_children()`, this does not allocate a Godot array. Internal children are not included.
+    pub fn children_of<T>(&self) -> ChildrenOf<T>
+    where
+        T: Inherits<Node>,
+    {
+        // SAFETY: ptr comes from valid internal API (and is non-null, so unwrap in from_obj_sys won't fail).
+        let parent = unsafe { Gd::from_obj_sys(self.__object_ptr()) };
+
+        ChildrenOf::new(parent)
+    }
+
+    /// Iterates over all descendants of type `T` (or inherited), skipping all others.
+    ///
+    /// Nodes are visited depth-first; use [`DescendantsOf::breadth_first()`] to change the order. This node itself is not included.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::classes::Sprite2D;
+    /// # fn test(node: Gd<Node>) {
+    /// for mut sprite in node.descendants_of::<Sprite2D>().breadth_first() {
+    ///     sprite.set_visible(false);
+    /// }
+    /// # }
+    /// ```
+    pub fn descendants_of<T>(&self) -> DescendantsOf<T>
+    where
+        T: Inherits<Node>,
+    {
+        DescendantsOf::new(self)
+    }
+
+    /// Iterates over the ancestors of this node, starting with its parent and ending with the root.
+    pub fn ancestors(&self) -> Ancestors {
+        Ancestors::new(self)
+    }
+
+    /// Finds the first descendant of type `T` (or inherited) matching the predicate, in depth-first order.
+    pub fn find_first<T>(&self, mut predicate: impl FnMut(&Gd<T>) -> bool) -> Option<Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        self.descendants_of::<T>().find(|node| predicate(node))
+    }
+
+    /// Iterates over descendants of type `T` (or inherited) which are in the given group.
+    ///
+    /// Unlike [`SceneTree::get_nodes_in_group()`][crate::classes::SceneTree::get_nodes_in_group], this is limited to the subtree of this
+    /// node, and does not allocate a Godot array. To search the whole tree, call it on the root node.
+    pub fn nodes_in_group_as<T>(&self, group: impl AsArg<StringName>) -> impl Iterator<Item = Gd<T>>
+    where
+        T: Inherits<Node>,
+    {
+        arg_into_owned!(group);
+
+        self.descendants_of::<T>()
+            .filter(move |node| node.upcast_ref::<Node>().is_in_group(&group))
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -19,11 +19,13 @@
 mod class_runtime;
 mod manual_extensions;
 mod match_class;
+mod node_traversal;
 
 // Re-exports all generated classes, interface traits and sidecar modules.
 pub use crate::gen::classes::*;
 // Macro re-export.
 pub use crate::match_class;
+pub use node_traversal::{Ancestors, ChildrenOf, DescendantsOf};
 
 /// Support for Godot _native structures_.
 ///
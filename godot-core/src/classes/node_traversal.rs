@@ -0,0 +1,143 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Iterators over the scene tree, returned by `Node` methods such as [`children_of()`][Node::children_of].
+
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use crate::classes::Node;
+use crate::obj::{Gd, Inherits};
+
+/// Iterator over the direct children of a node that have type `T` (or derived).
+///
+/// Returned by [`Node::children_of()`]. Children are queried one by one, so no Godot array is allocated.
+/// Internal children are not included.
+pub struct ChildrenOf<T> {
+    parent: Gd<Node>,
+    index: i32,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Inherits<Node>> ChildrenOf<T> {
+    pub(crate) fn new(parent: Gd<Node>) -> Self {
+        Self {
+            parent,
+            index: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Inherits<Node>> Iterator for ChildrenOf<T> {
+    type Item = Gd<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Count is re-queried, in case children are added or removed during iteration.
+        while self.index < self.parent.get_child_count() {
+            let child = self.parent.get_child(self.index);
+            self.index += 1;
+
+            if let Some(child) = child.and_then(|c| c.try_cast::<T>().ok()) {
+                return Some(child);
+            }
+        }
+
+        None
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Iterator over all descendants of a node that have type `T` (or derived).
+///
+/// Returned by [`Node::descendants_of()`]. Visits nodes depth-first (pre-order) by default; call [`breadth_first()`][Self::breadth_first]
+/// to visit them level by level. Nodes of other types are skipped, but their children are still visited. Internal children are not included.
+pub struct DescendantsOf<T> {
+    pending: VecDeque<Gd<Node>>,
+    breadth_first: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Inherits<Node>> DescendantsOf<T> {
+    pub(crate) fn new(root: &Node) -> Self {
+        let mut iter = Self {
+            pending: VecDeque::new(),
+            breadth_first: false,
+            _marker: PhantomData,
+        };
+
+        iter.push_children(root);
+        iter
+    }
+
+    /// Visits nodes in breadth-first order: all children first, then all grandchildren, and so on.
+    pub fn breadth_first(mut self) -> Self {
+        self.breadth_first = true;
+        self
+    }
+
+    fn push_children(&mut self, node: &Node) {
+        let count = node.get_child_count();
+
+        if self.breadth_first {
+            self.pending
+                .extend((0..count).filter_map(|i| node.get_child(i)));
+        } else {
+            // Pre-order: children are visited next, in their original order.
+            for i in (0..count).rev() {
+                if let Some(child) = node.get_child(i) {
+                    self.pending.push_front(child);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Inherits<Node>> Iterator for DescendantsOf<T> {
+    type Item = Gd<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.pending.pop_front() {
+            self.push_children(&node);
+
+            if let Ok(node) = node.try_cast::<T>() {
+                return Some(node);
+            }
+        }
+
+        None
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Iterator over the ancestors of a node, starting with its parent and ending with the root.
+///
+/// Returned by [`Node::ancestors()`].
+pub struct Ancestors {
+    next: Option<Gd<Node>>,
+}
+
+impl Ancestors {
+    pub(crate) fn new(node: &Node) -> Self {
+        Self {
+            next: node.get_parent(),
+        }
+    }
+}
+
+impl Iterator for Ancestors {
+    type Item = Gd<Node>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next.take()?;
+        self.next = current.get_parent();
+
+        Some(current)
+    }
+}
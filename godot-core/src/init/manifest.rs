@@ -0,0 +1,45 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::private::{ClassPlugin, PluginItem};
+
+/// Entry symbol of an [`ExtensionLibrary`][crate::init::ExtensionLibrary], as declared in `#[gdextension]`.
+///
+/// Used for the `entry_symbol` of the `.gdextension` file, see `godot_bindings::GdextensionManifest`. Implemented by the `#[gdextension]`
+/// macro; not intended for manual implementation.
+pub trait ExtensionEntrySymbol {
+    /// Name of the exported entry function, `gdext_rust_init` unless changed with `#[gdextension(entry_symbol = ...)]`.
+    const ENTRY_SYMBOL: &'static str;
+}
+
+/// Minimum Godot version of the extension as `major.minor`, e.g. `"4.3"`, for the `compatibility_minimum` of the `.gdextension` file.
+///
+/// This is the API level the crate is compiled against, selected through the `api-4-x` feature of `godot`. The patch version is omitted,
+/// since the API of a minor version does not change between patch releases.
+pub fn compatibility_minimum() -> String {
+    let (major, minor, _patch) = crate::init::GdextBuild::godot_static_version_triple();
+    format!("{major}.{minor}")
+}
+
+/// Returns class names and paths of all icons declared with `#[class(icon = "res://...")]`, sorted by class name.
+///
+/// Does not need a running engine. The `.gdextension` file itself is written by `godot_bindings::GdextensionManifest`, from a test or small
+/// binary of the extension crate, which passes the icons on with `GdextensionManifest::icons(class_icons())`.
+pub fn class_icons() -> Vec<(String, String)> {
+    let mut icons = Vec::new();
+    crate::private::iterate_plugins(|elem: &ClassPlugin| {
+        if let PluginItem::Struct(s) = &elem.item {
+            if let Some(icon) = s.icon {
+                icons.push((elem.class_name.to_cow_str().into_owned(), icon.to_string()));
+            }
+        }
+    });
+
+    // Plugin registration order is unspecified; sort for a reproducible file.
+    icons.sort();
+    icons
+}
@@ -13,6 +13,10 @@ use sys::GodotFfi;
 use crate::builtin::{GString, StringName};
 use crate::out;
 
+mod manifest;
+
+pub use manifest::*;
+
 mod reexport_pub {
     #[cfg(not(wasm_nothreads))]
     pub use super::sys::main_thread_id;
@@ -177,10 +181,25 @@ unsafe fn gdext_on_level_init(level: InitLevel) {
     }
 
     crate::registry::class::auto_register_classes(level);
+
+    if level == InitLevel::Scene {
+        #[cfg(since_api = "4.2")]
+        crate::registry::script_language::add_script_languages();
+        crate::registry::resource_format::add_resource_formats();
+    }
 }
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
 fn gdext_on_level_deinit(level: InitLevel) {
+    if level == InitLevel::Scene {
+        crate::registry::resource_format::remove_resource_formats();
+        #[cfg(since_api = "4.2")]
+        crate::registry::script_language::remove_script_languages();
+
+        // User classes are still registered, so leaked instances can be reported with their class.
+        crate::tools::report_leaks_on_deinit();
+    }
+
     crate::registry::class::unregister_classes(level);
 
     if level == InitLevel::Core {
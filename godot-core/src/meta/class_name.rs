@@ -177,6 +177,13 @@ impl ClassName {
         Self { global_index }
     }
 
+    /// Allocates a new entry for a name that is not a class itself, such as the qualified name `Class.Enum` of a class-scoped enum.
+    pub(crate) fn new_dynamic(name: String) -> Self {
+        let global_index = insert_class(ClassNameSource::Owned(name));
+
+        Self { global_index }
+    }
+
     #[doc(hidden)]
     pub fn is_none(&self) -> bool {
         self.global_index == 0
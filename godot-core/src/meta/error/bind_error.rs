@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when binding a `Gd<T>` fails, because the Rust instance is already bound.
+///
+/// Returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind] and [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut].
+#[derive(Debug)]
+pub struct BindError {
+    is_mut: bool,
+    type_name: &'static str,
+    reason: String,
+    previous_borrow: Option<String>,
+}
+
+impl BindError {
+    pub(crate) fn new<T>(
+        is_mut: bool,
+        reason: Box<dyn Error>,
+        previous_borrow: &impl fmt::Display,
+    ) -> Self {
+        // Empty in Release mode, where borrows are not tracked.
+        let previous_borrow = previous_borrow.to_string();
+
+        Self {
+            is_mut,
+            type_name: std::any::type_name::<T>(),
+            reason: reason.to_string(),
+            previous_borrow: (!previous_borrow.is_empty()).then_some(previous_borrow),
+        }
+    }
+
+    /// Whether the failed borrow was exclusive (`bind_mut`) rather than shared (`bind`).
+    pub fn is_mut(&self) -> bool {
+        self.is_mut
+    }
+
+    /// Rust type name of the class that could not be bound.
+    pub fn type_name(&self) -> &str {
+        self.type_name
+    }
+
+    /// Information about the previous borrow, including its backtrace if `RUST_BACKTRACE` is set.
+    ///
+    /// Borrows are only tracked in Debug mode; in Release mode, this returns `None`.
+    pub fn previous_borrow(&self) -> Option<&str> {
+        self.previous_borrow.as_deref()
+    }
+
+    pub(crate) fn method_name(&self) -> &'static str {
+        if self.is_mut {
+            "bind_mut"
+        } else {
+            "bind"
+        }
+    }
+
+    pub(crate) fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for BindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Gd<T>::{}() failed, already bound; T = {}. Details: {}.",
+            self.method_name(),
+            self.type_name,
+            self.reason
+        )
+    }
+}
+
+impl Error for BindError {}
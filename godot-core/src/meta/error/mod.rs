@@ -7,12 +7,18 @@
 
 //! Errors in the gdext library.
 
+mod bind_error;
 mod call_error;
 mod convert_error;
 mod io_error;
+mod signal_error;
 mod string_error;
+mod text_format_error;
 
+pub use bind_error::*;
 pub use call_error::*;
 pub use convert_error::*;
 pub use io_error::*;
+pub use signal_error::*;
 pub use string_error::*;
+pub use text_format_error::*;
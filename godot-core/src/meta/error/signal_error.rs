@@ -0,0 +1,121 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error when accessing a dynamically declared signal with static types.
+///
+/// Returned by [`Gd::dyn_signal()`][crate::obj::Gd::dyn_signal], if the signal does not exist on the object, or if its declared parameters
+/// do not match the requested parameter tuple.
+#[derive(Debug)]
+pub struct DynSignalError {
+    class_name: String,
+    signal_name: String,
+    kind: ErrorKind,
+}
+
+#[derive(Debug)]
+enum ErrorKind {
+    NotFound,
+    Arity {
+        declared: usize,
+        requested: usize,
+    },
+    ParamType {
+        index: usize,
+        declared: String,
+        requested: String,
+    },
+}
+
+impl DynSignalError {
+    pub(crate) fn not_found(class_name: String, signal_name: String) -> Self {
+        Self {
+            class_name,
+            signal_name,
+            kind: ErrorKind::NotFound,
+        }
+    }
+
+    pub(crate) fn arity(
+        class_name: String,
+        signal_name: String,
+        declared: usize,
+        requested: usize,
+    ) -> Self {
+        Self {
+            class_name,
+            signal_name,
+            kind: ErrorKind::Arity {
+                declared,
+                requested,
+            },
+        }
+    }
+
+    pub(crate) fn param_type(
+        class_name: String,
+        signal_name: String,
+        index: usize,
+        declared: String,
+        requested: String,
+    ) -> Self {
+        Self {
+            class_name,
+            signal_name,
+            kind: ErrorKind::ParamType {
+                index,
+                declared,
+                requested,
+            },
+        }
+    }
+
+    /// Name of the signal that was accessed.
+    pub fn signal_name(&self) -> &str {
+        &self.signal_name
+    }
+
+    /// Returns `true` if the object has no signal with the given name.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self.kind, ErrorKind::NotFound)
+    }
+}
+
+impl fmt::Display for DynSignalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            class_name,
+            signal_name,
+            kind,
+        } = self;
+
+        match kind {
+            ErrorKind::NotFound => {
+                write!(f, "signal `{signal_name}` not found on object of class `{class_name}`")
+            }
+            ErrorKind::Arity {
+                declared,
+                requested,
+            } => write!(
+                f,
+                "signal `{class_name}::{signal_name}` is declared with {declared} parameter(s), but accessed with {requested}"
+            ),
+            ErrorKind::ParamType {
+                index,
+                declared,
+                requested,
+            } => write!(
+                f,
+                "signal `{class_name}::{signal_name}`: parameter #{index} is declared as `{declared}`, but accessed as `{requested}`"
+            ),
+        }
+    }
+}
+
+impl Error for DynSignalError {}
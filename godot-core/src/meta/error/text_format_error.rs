@@ -0,0 +1,56 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+/// Error while parsing Godot's text serialization format.
+///
+/// Returned by [`TextValue::parse()`][crate::tools::TextValue::parse] and [`TextResource::parse()`][crate::tools::TextResource::parse].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TextFormatError {
+    message: String,
+    line: usize,
+    column: usize,
+}
+
+impl TextFormatError {
+    pub(crate) fn new(message: impl Into<String>, line: usize, column: usize) -> Self {
+        Self {
+            message: message.into(),
+            line,
+            column,
+        }
+    }
+
+    /// Description of the error, without location.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Line of the error, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column of the error in characters, starting at 1.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for TextFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (line {}, column {})",
+            self.message, self.line, self.column
+        )
+    }
+}
+
+impl Error for TextFormatError {}
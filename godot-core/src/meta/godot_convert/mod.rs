@@ -11,6 +11,7 @@ use crate::builtin::Variant;
 use crate::meta::error::ConvertError;
 use crate::meta::traits::GodotFfiVariant;
 use crate::meta::GodotType;
+use crate::registry::constant::ClassEnumInfo;
 
 /// Indicates that a type can be passed to/from Godot, either directly or through an intermediate "via" type.
 ///
@@ -29,6 +30,15 @@ use crate::meta::GodotType;
 pub trait GodotConvert {
     /// The type through which `Self` is represented in Godot.
     type Via: GodotType;
+
+    /// Class-scoped enum or bitfield, as which `Self` is declared in Godot's metadata.
+    ///
+    /// Returns `Some` for types attached to a class with `#[export_enum]`, so that properties, parameters and return types show up as
+    /// e.g. `Player.State` in GDScript instead of `int`. Implemented by `#[derive(GodotConvert)]` and `#[derive(EngineBitfield)]`.
+    #[doc(hidden)]
+    fn class_enum() -> Option<ClassEnumInfo> {
+        None
+    }
 }
 
 /// Defines the canonical conversion to Godot for a type.
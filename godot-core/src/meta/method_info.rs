@@ -7,7 +7,7 @@
 
 use godot_ffi::conv::u32_to_usize;
 
-use crate::builtin::{StringName, Variant};
+use crate::builtin::{Array, Dictionary, StringName, Variant, VariantArray};
 use crate::global::MethodFlags;
 use crate::meta::{ClassName, PropertyInfo};
 use crate::sys;
@@ -29,6 +29,27 @@ pub struct MethodInfo {
 }
 
 impl MethodInfo {
+    /// Converts to the dictionary format used by reflection APIs such as `Object.get_method_list()` and `Script` virtual methods.
+    pub(crate) fn to_dictionary(&self) -> Dictionary {
+        use crate::obj::EngineBitfield as _;
+
+        let args: Array<Dictionary> = self
+            .arguments
+            .iter()
+            .map(PropertyInfo::to_dictionary)
+            .collect();
+        let default_args: VariantArray = self.default_arguments.iter().cloned().collect();
+
+        let mut dict = Dictionary::new();
+        dict.set("name", self.method_name.clone());
+        dict.set("args", args);
+        dict.set("default_args", default_args);
+        dict.set("flags", self.flags.ord());
+        dict.set("id", self.id);
+        dict.set("return", self.return_type.to_dictionary());
+        dict
+    }
+
     /// Consumes self and turns it into a `sys::GDExtensionMethodInfo`, should be used together with
     /// [`free_owned_method_sys`](Self::free_owned_method_sys).
     ///
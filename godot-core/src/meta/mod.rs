@@ -99,5 +99,7 @@ pub(crate) use reexport_crate::*;
 /// # Safety
 /// Must not use meta facilities (e.g. `ClassName`) after this call.
 pub(crate) unsafe fn cleanup() {
+    // Holds `ClassName`s, so must be cleared together with them.
+    crate::registry::constant::cleanup();
     class_name::cleanup();
 }
@@ -7,10 +7,10 @@
 
 use godot_ffi::VariantType;
 
-use crate::builtin::{GString, StringName};
+use crate::builtin::{Dictionary, GString, StringName};
 use crate::global::{PropertyHint, PropertyUsageFlags};
 use crate::meta::{
-    element_godot_type_name, ArrayElement, ClassName, GodotType, PackedArrayElement,
+    element_godot_type_name, ArrayElement, ClassName, GodotConvert, GodotType, PackedArrayElement,
 };
 use crate::obj::{bounds, Bounds, EngineBitfield, EngineEnum, GodotClass};
 use crate::registry::class::get_dyn_property_hint_string;
@@ -66,14 +66,18 @@ impl PropertyInfo {
     ///
     /// This will generate property info equivalent to what a `#[var]` attribute would.
     pub fn new_var<T: Var>(property_name: &str) -> Self {
-        T::Via::property_info(property_name).with_hint_info(T::var_hint())
+        T::Via::property_info(property_name)
+            .with_hint_info(T::var_hint())
+            .with_class_enum_of::<T>()
     }
 
     /// Create a new `PropertyInfo` representing an exported property named `property_name` with type `T`.
     ///
     /// This will generate property info equivalent to what an `#[export]` attribute would.
     pub fn new_export<T: Export>(property_name: &str) -> Self {
-        T::Via::property_info(property_name).with_hint_info(T::export_hint())
+        T::Via::property_info(property_name)
+            .with_hint_info(T::export_hint())
+            .with_class_enum_of::<T>()
     }
 
     /// Change the `hint` and `hint_string` to be the given `hint_info`.
@@ -108,6 +112,21 @@ impl PropertyInfo {
         Self { hint_info, ..self }
     }
 
+    /// If `T` is a class-scoped enum or bitfield (see `#[export_enum]`), declares the property with that type instead of `int`.
+    pub(crate) fn with_class_enum_of<T: GodotConvert>(mut self) -> Self {
+        if let Some(class_enum) = T::class_enum() {
+            self.class_name = class_enum.qualified_name;
+            self.usage = self.usage
+                | if class_enum.is_bitfield {
+                    PropertyUsageFlags::CLASS_IS_BITFIELD
+                } else {
+                    PropertyUsageFlags::CLASS_IS_ENUM
+                };
+        }
+
+        self
+    }
+
     /// Create a new `PropertyInfo` representing a group in Godot.
     ///
     /// See [`EditorInspector`](https://docs.godotengine.org/en/latest/classes/class_editorinspector.html#class-editorinspector) in Godot for
@@ -154,6 +173,21 @@ impl PropertyInfo {
             && self.hint_info.hint_string == T::Via::godot_type_name().into()
     }
 
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Dictionary conversion
+
+    /// Converts to the dictionary format used by reflection APIs such as `Object.get_property_list()` and `Script` virtual methods.
+    pub(crate) fn to_dictionary(&self) -> Dictionary {
+        let mut dict = Dictionary::new();
+        dict.set("name", self.property_name.clone());
+        dict.set("class_name", self.class_name.to_string_name());
+        dict.set("type", self.variant_type.ord());
+        dict.set("hint", self.hint_info.hint.ord());
+        dict.set("hint_string", self.hint_info.hint_string.clone());
+        dict.set("usage", self.usage.ord());
+        dict
+    }
+
     // ------------------------------------------------------------------------------------------------------------------------------------------
     // FFI conversion functions
 
@@ -106,6 +106,24 @@ impl<T: GodotClass> Base<T> {
         }
     }
 
+    /// Creates the `Base<T>` of a Rust base class, for the same object as the `Base<D>` of a derived class.
+    ///
+    /// The initialization state is shared, so the base part counts as initialized once the derived object is.
+    ///
+    /// # Safety
+    /// Same as [`from_base()`][Self::from_base]. Additionally, `T` must be `D` or one of its base classes.
+    pub(crate) unsafe fn from_derived_base<D: GodotClass>(base: &Base<D>) -> Base<T> {
+        debug_assert!(base.obj.is_instance_valid());
+
+        let obj = Gd::from_obj_sys_weak(base.obj.obj_sys());
+
+        Self {
+            obj: ManuallyDrop::new(obj),
+            #[cfg(debug_assertions)]
+            init_state: Rc::clone(&base.init_state),
+        }
+    }
+
     /// Create base from existing object (used in script instances).
     ///
     /// The return value is a weak pointer, so it will not keep the instance alive.
@@ -410,7 +410,9 @@ impl Declarer for DeclEngine {
 pub enum DeclUser {}
 impl Sealed for DeclUser {}
 impl Declarer for DeclUser {
-    type DerefTarget<T: GodotClass> = T::Base;
+    // Skips Rust base classes, so that `Gd<T>` always dereferences to an engine class.
+    type DerefTarget<T: GodotClass> =
+        <<T::Base as Bounds>::Declarer as Declarer>::DerefTarget<T::Base>;
     type InstanceCache = std::cell::Cell<sys::GDExtensionClassInstancePtr>;
 
     unsafe fn is_currently_bound<T>(obj: &RawGd<T>) -> bool
@@ -73,3 +73,28 @@ where
         callable.call_deferred(&[]);
     }
 }
+
+/// Defers a closure to the main thread's [idle time](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-method-call-deferred),
+/// from any thread.
+///
+/// If the engine shuts down before the call happens, the closure is dropped without being run.
+#[cfg(all(since_api = "4.2", feature = "experimental-threads"))]
+pub(crate) fn call_deferred_on_main<F>(name: &str, function: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    // Mutex provides the Sync bound of from_sync_fn(); it is never contended, as the callable is only called once.
+    let function = std::sync::Mutex::new(Some(function));
+
+    let callable = Callable::from_sync_fn(name, move |_args| {
+        let function = function
+            .lock()
+            .unwrap()
+            .take()
+            .expect("deferred callable has already been consumed");
+
+        function();
+        Ok(Variant::nil())
+    });
+    callable.call_deferred(&[]);
+}
@@ -12,14 +12,15 @@ use godot_ffi as sys;
 use sys::{static_assert_eq_size_align, SysPtr as _};
 
 use crate::builtin::{Callable, GString, NodePath, StringName, Variant};
-use crate::meta::error::{ConvertError, FromFfiError};
+use crate::meta::error::{BindError, ConvertError, FromFfiError};
 use crate::meta::{
     ArrayElement, AsArg, ByRef, CallContext, ClassName, CowArg, FromGodot, GodotConvert, GodotType,
     ParamType, PropertyHintInfo, RefArg, ToGodot,
 };
+use crate::obj::rtti::ObjectRtti;
 use crate::obj::{
     bounds, cap, Bounds, DynGd, GdDerefTarget, GdMut, GdRef, GodotClass, Inherits, InstanceId,
-    OnEditor, RawGd, WithSignals,
+    OnEditor, RawGd, WeakGd, WithSignals,
 };
 use crate::private::{callbacks, PanicPayload};
 use crate::registry::class::try_dynify_object;
@@ -151,6 +152,7 @@ where
     ///
     /// # Panics
     /// Panics occurring in the `init` function are propagated to the caller.
+    #[track_caller]
     pub fn from_init_fn<F>(init: F) -> Self
     where
         F: FnOnce(crate::obj::Base<T::Base>) -> T,
@@ -158,13 +160,18 @@ where
         let object_ptr = callbacks::create_custom(init) // or propagate panic.
             .unwrap_or_else(|payload| PanicPayload::repanic(payload));
 
-        unsafe { Gd::from_obj_sys(object_ptr) }
+        let gd = unsafe { Gd::from_obj_sys(object_ptr) };
+        if crate::tools::is_leak_tracking_enabled() {
+            crate::tools::relocate_creation(gd.instance_id(), std::panic::Location::caller());
+        }
+        gd
     }
 
     /// Moves a user-created object into this smart pointer, submitting ownership to the Godot engine.
     ///
     /// This is only useful for types `T` which do not store their base objects (if they have a base,
     /// you cannot construct them standalone).
+    #[track_caller]
     pub fn from_object(user_object: T) -> Self {
         Self::from_init_fn(move |_base| user_object)
     }
@@ -203,6 +210,34 @@ where
     pub fn bind_mut(&mut self) -> GdMut<'_, T> {
         self.raw.bind_mut()
     }
+
+    /// Like [`bind()`][Self::bind], but returns an error instead of panicking if the instance is already bound.
+    ///
+    /// Useful for re-entrant code paths such as signal handlers, which may skip or defer their work if the object is busy.
+    /// The error carries information about the conflicting borrow.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # #[derive(GodotClass)]
+    /// # #[class(init)]
+    /// # struct MyClass { value: i32 }
+    /// fn on_signal(obj: &Gd<MyClass>) {
+    ///     match obj.try_bind() {
+    ///         Ok(guard) => godot_print!("value: {}", guard.value),
+    ///         Err(err) => godot_warn!("skipped, object busy: {err}"),
+    ///     }
+    /// }
+    /// ```
+    pub fn try_bind(&self) -> Result<GdRef<'_, T>, BindError> {
+        self.raw.try_bind()
+    }
+
+    /// Like [`bind_mut()`][Self::bind_mut], but returns an error instead of panicking if the instance is already bound.
+    ///
+    /// See [`try_bind()`][Self::try_bind] for details.
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<'_, T>, BindError> {
+        self.raw.try_bind_mut()
+    }
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
@@ -253,6 +288,18 @@ impl<T: GodotClass> Gd<T> {
         }
     }
 
+    /// Creates a non-owning [`WeakGd<T>`] handle to this object.
+    ///
+    /// The handle does not keep `RefCounted` objects alive. Use [`WeakGd::upgrade()`] to obtain a `Gd<T>` again, as long as the object lives.
+    ///
+    /// # Panics
+    /// If this object is no longer alive.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        self.raw.check_rtti("downgrade");
+
+        WeakGd::from_rtti(ObjectRtti::of::<T>(self.instance_id()))
+    }
+
     /// ⚠️ Returns the instance ID of this object (panics when dead).
     ///
     /// # Panics
@@ -820,6 +867,43 @@ where
     }
 }
 
+impl<T: GodotClass> Gd<T> {
+    /// Access a signal that is only declared at runtime, with statically typed parameters `Ps`.
+    ///
+    /// This is meant for signals declared in scripts (e.g. GDScript), or added through [`Object::add_user_signal()`][classes::Object::add_user_signal],
+    /// which are not covered by [`signals()`][Self::signals]. The signal's declaration (from `Object::get_signal_list()`) is checked against
+    /// the parameter tuple `Ps` once; afterward, the returned [`TypedSignal`][crate::registry::signal::TypedSignal] offers the same API as for
+    /// `#[signal]` declarations -- `connect*()`, `builder()`, `emit_tuple()`, `to_future()` and more.
+    ///
+    /// The check is lenient where the declaration carries no type information: untyped parameters are compatible with any Rust type, and
+    /// `Variant` in `Ps` accepts any declared type. Objects are compared by class name, if both classes are known to `ClassDB`.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # let node: Gd<Node> = unimplemented!();
+    /// // GDScript: signal hit(damage: int, source: String)
+    /// let mut hit = node.dyn_signal::<(i32, GString)>("hit").expect("signal `hit` not declared as expected");
+    ///
+    /// hit.connect(|damage, source| println!("{source} dealt {damage} damage"));
+    /// hit.emit_tuple((10, "trap".into()));
+    /// ```
+    ///
+    /// Returns an error if the signal does not exist, or if its parameter count or types do not match `Ps`.
+    #[cfg(since_api = "4.2")]
+    pub fn dyn_signal<Ps: crate::meta::ParamTuple>(
+        &self,
+        signal_name: &str,
+    ) -> Result<
+        crate::registry::signal::TypedSignal<'static, classes::Object, Ps>,
+        crate::meta::error::DynSignalError,
+    > {
+        crate::registry::signal::TypedSignal::try_from_dynamic(
+            self.clone().upcast_object(),
+            signal_name,
+        )
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Trait impls
 
@@ -22,10 +22,13 @@ mod on_editor;
 mod on_ready;
 mod raw_gd;
 mod traits;
+mod weak_gd;
 
 pub(crate) mod rtti;
 
 pub use base::*;
+#[cfg(all(since_api = "4.2", feature = "experimental-threads"))]
+pub(crate) use call_deferred::call_deferred_on_main;
 pub use call_deferred::WithDeferredCall;
 pub use dyn_gd::DynGd;
 pub use gd::*;
@@ -35,6 +38,7 @@ pub use on_editor::*;
 pub use on_ready::*;
 pub use raw_gd::*;
 pub use traits::*;
+pub use weak_gd::WeakGd;
 
 pub mod bounds;
 pub mod script;
@@ -46,5 +50,5 @@ pub use bounds::private::Bounds;
 ///
 /// This type alias abstracts over the two `Declarer` options for Godot objects:
 /// - [`bounds::DeclEngine`]: for all engine-provided classes, `DerefTarget<T>` is `T`.
-/// - [`bounds::DeclUser`]: for Rust-defined user classes, `DerefTarget<T>` is `T::Base`.
-type GdDerefTarget<T> = <<T as Bounds>::Declarer as bounds::Declarer>::DerefTarget<T>;
+/// - [`bounds::DeclUser`]: for Rust-defined user classes, `DerefTarget<T>` is the nearest engine class among the bases of `T`.
+pub(crate) type GdDerefTarget<T> = <<T as Bounds>::Declarer as bounds::Declarer>::DerefTarget<T>;
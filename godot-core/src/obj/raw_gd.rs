@@ -11,7 +11,7 @@ use godot_ffi as sys;
 use sys::{interface_fn, ExtVariantType, GodotFfi, GodotNullableFfi, PtrcallType};
 
 use crate::builtin::{Variant, VariantType};
-use crate::meta::error::{ConvertError, FromVariantError};
+use crate::meta::error::{BindError, ConvertError, FromVariantError};
 use crate::meta::{
     CallContext, ClassName, FromGodot, GodotConvert, GodotFfiVariant, GodotType, RefArg, ToGodot,
 };
@@ -334,7 +334,7 @@ impl<T: GodotClass> RawGd<T> {
     {
         // SAFETY: There are two possible Declarer::DerefTarget types:
         // - T, if T is an engine class
-        // - T::Base, if T is a user class
+        // - the nearest engine class among T's bases, if T is a user class
         // Both are valid targets for upcast. And both are always engine types.
         unsafe { self.as_upcast_ref::<GdDerefTarget<T>>() }
     }
@@ -437,6 +437,18 @@ where
         GdMut::from_guard(self.storage().unwrap().get_mut())
     }
 
+    /// Fallible version of [`bind()`][Self::bind], returning an error if the instance is already bound.
+    pub(crate) fn try_bind(&self) -> Result<GdRef<'_, T>, BindError> {
+        self.check_rtti("try_bind");
+        self.storage().unwrap().try_get().map(GdRef::from_guard)
+    }
+
+    /// Fallible version of [`bind_mut()`][Self::bind_mut], returning an error if the instance is already bound.
+    pub(crate) fn try_bind_mut(&mut self) -> Result<GdMut<'_, T>, BindError> {
+        self.check_rtti("try_bind_mut");
+        self.storage().unwrap().try_get_mut().map(GdMut::from_guard)
+    }
+
     /// Storage object associated with the extension instance.
     ///
     /// Returns `None` if self is null.
@@ -38,6 +38,15 @@ mod reexport_4_2 {
 #[cfg(since_api = "4.2")]
 pub use reexport_4_2::*;
 
+#[cfg(since_api = "4.2")]
+mod language;
+#[cfg(since_api = "4.2")]
+mod placeholder;
+#[cfg(since_api = "4.2")]
+pub use language::*;
+#[cfg(since_api = "4.2")]
+pub use placeholder::*;
+
 // Re-export guards.
 pub use crate::obj::guards::{ScriptBaseMut, ScriptBaseRef};
 
@@ -245,6 +254,8 @@ impl<T: ScriptInstance> Drop for ScriptInstanceData<T> {
 ///
 /// The exact GDExtension type of the pointer is `sys::GDExtensionScriptInstancePtr`, but you can treat it like an opaque pointer.
 ///
+/// For placeholder instances (e.g. of non-tool scripts in the editor), see `PlaceholderInstances` (Godot 4.2+).
+///
 /// # Safety
 /// The caller must ensure that `for_object` is not freed before passing the returned pointer back to Godot.
 #[must_use]
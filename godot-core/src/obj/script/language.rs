@@ -0,0 +1,265 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::{StringName, Variant};
+use crate::classes::Object;
+use crate::meta::{MethodInfo, PropertyInfo};
+use crate::obj::{Gd, GodotClass};
+use crate::sys;
+
+/// Custom scripting language, implemented in Rust.
+///
+/// Implement this trait for a class annotated with `#[class(no_init, script_language)]`. godot-rust then generates:
+/// - a [`ScriptLanguageExtension`][crate::classes::ScriptLanguageExtension] class, which is registered with the engine once the `Scene`
+///   init level is loaded,
+/// - a [`ScriptExtension`][crate::classes::ScriptExtension] class for the script resources of this language,
+/// - a [`ResourceFormatLoader`][crate::classes::ResourceFormatLoader] and [`ResourceFormatSaver`][crate::classes::ResourceFormatSaver]
+///   for files with the extension [`EXTENSION`][Self::EXTENSION].
+///
+/// From then on, files of the language can be loaded and attached to objects as scripts. All required engine callbacks are implemented
+/// by godot-rust; an implementation only needs to provide the language's semantics:
+/// - [`compile()`][Self::compile] turns source code into a [`Program`][Self::Program], once per script (and again on reload).
+/// - [`create_state()`][Self::create_state] creates the per-object [`State`][Self::State] whenever the script is attached to an object.
+/// - [`call()`][Self::call], [`get_property()`][Self::get_property] and [`set_property()`][Self::set_property] run on that state.
+///
+/// Optional hooks, such as [`reserved_words()`][Self::reserved_words] for syntax highlighting, have defaults that can be overridden.
+///
+/// # Example
+/// A language in which each line `name = value` declares an integer property.
+/// ```no_run
+/// use std::collections::HashMap;
+/// use godot::prelude::*;
+/// use godot::meta::PropertyInfo;
+/// use godot::obj::script::{RustScriptLanguage, ScriptError};
+///
+/// #[derive(GodotClass)]
+/// #[class(no_init, script_language)]
+/// struct PropScript {}
+///
+/// impl RustScriptLanguage for PropScript {
+///     const NAME: &'static str = "PropScript";
+///     const EXTENSION: &'static str = "props";
+///
+///     type Program = Vec<(String, i64)>;
+///     type State = HashMap<String, i64>;
+///
+///     fn compile(source: &str) -> Result<Self::Program, ScriptError> {
+///         let mut props = Vec::new();
+///         for (line, text) in source.lines().enumerate() {
+///             let Some((name, value)) = text.split_once('=') else { continue };
+///             let value = value.trim().parse()
+///                 .map_err(|_| ScriptError::new("expected integer").at(line as u32 + 1, 1))?;
+///             props.push((name.trim().to_string(), value));
+///         }
+///         Ok(props)
+///     }
+///
+///     fn create_state(program: &Self::Program) -> Self::State {
+///         program.iter().cloned().collect()
+///     }
+///
+///     fn property_list(program: &Self::Program) -> Vec<PropertyInfo> {
+///         program.iter().map(|(name, _)| PropertyInfo::new_var::<i64>(name)).collect()
+///     }
+///
+///     fn get_property(_program: &Self::Program, state: &Self::State, name: &StringName) -> Option<Variant> {
+///         state.get(&name.to_string()).map(|value| value.to_variant())
+///     }
+/// }
+/// ```
+pub trait RustScriptLanguage: GodotClass {
+    /// Name of the language, as shown in the editor.
+    const NAME: &'static str;
+
+    /// File extension of scripts in this language, without leading dot.
+    const EXTENSION: &'static str;
+
+    /// Compiled form of a script, shared by all objects using the script.
+    type Program: 'static;
+
+    /// State of one object using the script, e.g. its script-defined variables.
+    type State: 'static;
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Scripts
+
+    /// Compiles source code into a program.
+    ///
+    /// Called when a script is loaded, and whenever it is reloaded (e.g. after editing). Errors are reported in the editor.
+    fn compile(source: &str) -> Result<Self::Program, ScriptError>;
+
+    /// Creates the state of an object, when the script is attached to it.
+    fn create_state(program: &Self::Program) -> Self::State;
+
+    /// Calls a script method on an object.
+    ///
+    /// `owner` is the object the script is attached to. Calls from the script back into the same object, which reach this script again,
+    /// are not supported and panic.
+    ///
+    /// The default implementation returns an `INVALID_METHOD` error, for languages without methods.
+    fn call(
+        program: &Self::Program,
+        state: &mut Self::State,
+        owner: &Gd<Object>,
+        method: &StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType> {
+        let _ = (program, state, owner, method, args);
+        Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD)
+    }
+
+    /// Methods that the script defines. Used for `has_method()` and the editor.
+    fn method_list(program: &Self::Program) -> Vec<MethodInfo> {
+        let _ = program;
+        Vec::new()
+    }
+
+    /// Properties that the script defines. Used for the inspector, `get_property_list()` and saving scenes.
+    fn property_list(program: &Self::Program) -> Vec<PropertyInfo> {
+        let _ = program;
+        Vec::new()
+    }
+
+    /// Reads a script property. Returns `None` if the script has no such property, so that Godot falls back to the object's own properties.
+    fn get_property(
+        program: &Self::Program,
+        state: &Self::State,
+        name: &StringName,
+    ) -> Option<Variant> {
+        let _ = (program, state, name);
+        None
+    }
+
+    /// Writes a script property. Returns `false` if the script has no such property, so that Godot falls back to the object's own properties.
+    fn set_property(
+        program: &Self::Program,
+        state: &mut Self::State,
+        name: &StringName,
+        value: &Variant,
+    ) -> bool {
+        let _ = (program, state, name, value);
+        false
+    }
+
+    /// Engine class that objects must inherit to use the script, e.g. `"Node"`.
+    ///
+    /// Defaults to `Object`, i.e. the script can be attached to any object.
+    fn instance_base_type(program: &Self::Program) -> StringName {
+        let _ = program;
+        StringName::from("Object")
+    }
+
+    /// Global class name declared by the script (like `class_name` in GDScript), or an empty name.
+    fn global_name(program: &Self::Program) -> StringName {
+        let _ = program;
+        StringName::default()
+    }
+
+    /// Whether the script also runs in the editor (like `@tool` in GDScript). Defaults to `false`.
+    fn is_tool(program: &Self::Program) -> bool {
+        let _ = program;
+        false
+    }
+
+    // ------------------------------------------------------------------------------------------------------------------------------------------
+    // Language hooks, mostly used by the editor
+
+    /// Keywords of the language, for syntax highlighting.
+    fn reserved_words() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Whether `keyword` is a control-flow keyword (e.g. `if`, `return`), which the editor highlights separately.
+    fn is_control_flow_keyword(keyword: &str) -> bool {
+        let _ = keyword;
+        false
+    }
+
+    /// Comment delimiters, each as `"start end"` or `"start"` for line comments. Defaults to `#` line comments.
+    fn comment_delimiters() -> &'static [&'static str] {
+        &["#"]
+    }
+
+    /// String delimiters, each as `"start end"`. Defaults to double- and single-quoted strings.
+    fn string_delimiters() -> &'static [&'static str] {
+        &["\" \"", "' '"]
+    }
+
+    /// Source code of a new script, as created by the editor's "Attach Script" dialog. Defaults to an empty script.
+    fn template(class_name: &str, base_class_name: &str) -> String {
+        let _ = (class_name, base_class_name);
+        String::new()
+    }
+
+    /// Checks source code for errors, while it is edited.
+    ///
+    /// The default implementation compiles the source and reports the compile error, if any.
+    fn validate(source: &str) -> Vec<ScriptError> {
+        match Self::compile(source) {
+            Ok(_) => Vec::new(),
+            Err(error) => vec![error],
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Error in the source code of a [`RustScriptLanguage`] script, reported by [`compile()`][RustScriptLanguage::compile].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScriptError {
+    message: String,
+    line: u32,
+    column: u32,
+}
+
+impl ScriptError {
+    /// Creates an error without location.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            line: 0,
+            column: 0,
+        }
+    }
+
+    /// Sets the location of the error; `line` and `column` start at 1.
+    pub fn at(mut self, line: u32, column: u32) -> Self {
+        self.line = line;
+        self.column = column;
+        self
+    }
+
+    /// Description of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Line of the error, starting at 1; or 0 if unknown.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Column of the error, starting at 1; or 0 if unknown.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "{}:{}: {}", self.line, self.column, self.message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+impl Error for ScriptError {}
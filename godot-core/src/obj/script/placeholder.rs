@@ -0,0 +1,170 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::ffi::c_void;
+
+use sys::GodotFfi as _;
+
+use crate::builtin::{Array, Dictionary, StringName, Variant};
+use crate::classes::{Object, Script, ScriptLanguage};
+use crate::meta::PropertyInfo;
+use crate::obj::Gd;
+use crate::sys;
+
+/// Placeholder script instances of one script.
+///
+/// Godot uses placeholder instances when a script cannot run, most notably for non-tool scripts in the editor. A placeholder does not
+/// execute any script code, but stores the values of the script's exported properties, so that they can be shown in the inspector and
+/// saved with the scene.
+///
+/// A script typically owns one `PlaceholderInstances` object, and uses it in these [`IScriptExtension`][crate::classes::IScriptExtension]
+/// methods:
+/// - `placeholder_instance_create_rawptr()`: return the result of [`create()`][Self::create].
+/// - `placeholder_erased_rawptr()`: call [`erase()`][Self::erase].
+/// - `reload()`: call [`update()`][Self::update] with the new properties of the script.
+///
+/// ```ignore
+/// #[derive(GodotClass)]
+/// #[class(base = ScriptExtension, init, tool)]
+/// struct MyScript {
+///     placeholders: PlaceholderInstances,
+///     language: Option<Gd<ScriptLanguage>>,
+///     base: Base<ScriptExtension>,
+/// }
+///
+/// #[godot_api]
+/// impl IScriptExtension for MyScript {
+///     unsafe fn placeholder_instance_create_rawptr(&self, for_object: Gd<Object>) -> *mut c_void {
+///         let language = self.language.as_ref().unwrap();
+///         let script = self.to_gd().upcast::<Script>();
+///
+///         // SAFETY: placeholder_erased_rawptr() below erases the placeholder.
+///         unsafe { self.placeholders.create(language, &script, &for_object) }
+///     }
+///
+///     unsafe fn placeholder_erased_rawptr(&mut self, placeholder: *mut c_void) {
+///         self.placeholders.erase(placeholder);
+///     }
+///
+///     fn reload(&mut self, keep_state: bool) -> godot::global::Error {
+///         // ... compile the script ...
+///         let properties = vec![PropertyInfo::new_export::<i64>("health")];
+///         let default_values = vec![(StringName::from("health"), 100.to_variant())];
+///         self.placeholders.update(properties, default_values);
+///
+///         godot::global::Error::OK
+///     }
+///
+///     // ... other methods ...
+/// }
+/// ```
+#[derive(Default)]
+pub struct PlaceholderInstances {
+    // RefCell: placeholders are created in `placeholder_instance_create_rawptr(&self)`.
+    inner: RefCell<PlaceholderState>,
+}
+
+#[derive(Default)]
+struct PlaceholderState {
+    instances: Vec<sys::GDExtensionScriptInstancePtr>,
+    properties: Array<Dictionary>,
+    values: Dictionary,
+}
+
+impl PlaceholderInstances {
+    /// Creates an empty set, with no properties.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a placeholder instance of `script` for `for_object`, with the properties of the last [`update()`][Self::update].
+    ///
+    /// Returns the instance pointer, which should be returned from `IScriptExtension::placeholder_instance_create_rawptr()`.
+    /// Discarding it leaks the placeholder.
+    ///
+    /// # Safety
+    /// - Once Godot frees the placeholder, [`erase()`][Self::erase] must be called with its pointer. Godot notifies the script through
+    ///   `IScriptExtension::placeholder_erased_rawptr()`. Otherwise, later updates access freed memory.
+    /// - `language` must be the language of `script`.
+    #[must_use]
+    pub unsafe fn create(
+        &self,
+        language: &Gd<ScriptLanguage>,
+        script: &Gd<Script>,
+        for_object: &Gd<Object>,
+    ) -> *mut c_void {
+        let create_fn = sys::interface_fn!(placeholder_script_instance_create);
+
+        // SAFETY: all objects are alive; their sys pointers are valid.
+        let placeholder =
+            unsafe { create_fn(language.obj_sys(), script.obj_sys(), for_object.obj_sys()) };
+
+        let mut state = self.inner.borrow_mut();
+
+        // SAFETY: the placeholder was just created, and the property containers are valid for the duration of the call.
+        unsafe { update_placeholder(placeholder, &state.properties, &state.values) };
+
+        state.instances.push(placeholder);
+        placeholder as *mut c_void
+    }
+
+    /// Forgets a placeholder that Godot has freed.
+    ///
+    /// Call this from `IScriptExtension::placeholder_erased_rawptr()`. Pointers of other placeholders are ignored.
+    pub fn erase(&self, placeholder: *mut c_void) {
+        self.inner
+            .borrow_mut()
+            .instances
+            .retain(|&instance| instance as *mut c_void != placeholder);
+    }
+
+    /// Sets the properties of all current and future placeholders.
+    ///
+    /// `properties` are the exported properties of the script; `default_values` holds the values that new placeholders show. Godot keeps
+    /// values that were already edited, as long as the property still exists.
+    ///
+    /// Call this whenever the script is reloaded, so that the inspector reflects the edited script.
+    pub fn update(
+        &self,
+        properties: Vec<PropertyInfo>,
+        default_values: Vec<(StringName, Variant)>,
+    ) {
+        let mut state = self.inner.borrow_mut();
+
+        state.properties = properties.iter().map(PropertyInfo::to_dictionary).collect();
+        state.values = default_values.into_iter().collect();
+
+        for &placeholder in state.instances.iter() {
+            // SAFETY: placeholders are removed through `erase()` once Godot frees them (required by `create()`), so they are all alive.
+            unsafe { update_placeholder(placeholder, &state.properties, &state.values) };
+        }
+    }
+
+    /// Number of placeholders that are currently alive.
+    pub fn len(&self) -> usize {
+        self.inner.borrow().instances.len()
+    }
+
+    /// Returns `true` if no placeholders are alive.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// # Safety
+/// `placeholder` must point to a live placeholder instance.
+unsafe fn update_placeholder(
+    placeholder: sys::GDExtensionScriptInstancePtr,
+    properties: &Array<Dictionary>,
+    values: &Dictionary,
+) {
+    let update_fn = sys::interface_fn!(placeholder_script_instance_update);
+
+    // SAFETY: forwarded to caller; `properties` and `values` are valid for the duration of the call.
+    unsafe { update_fn(placeholder, properties.sys(), values.sys()) };
+}
@@ -32,7 +32,9 @@ pub trait GodotClass: Bounds + 'static
 where
     Self: Sized,
 {
-    /// The immediate superclass of `T`. This is always a Godot engine class.
+    /// The immediate superclass of `T`.
+    ///
+    /// This is a Godot engine class, or another Rust class declared with `#[class(base = ..., rust_base)]`.
     type Base: GodotClass; // not EngineClass because it can be ()
 
     /// The name of the class, under which it is registered in Godot.
@@ -60,6 +62,16 @@ where
             Self::Base::inherits::<U>()
         }
     }
+
+    /// Creates the Rust part of the object that belongs to a Rust base class, if `Base` is one.
+    ///
+    /// Overridden by `#[derive(GodotClass)]` for `#[class(rust_base)]`.
+    #[doc(hidden)]
+    fn __create_base_part(
+        _base: &crate::obj::Base<Self::Base>,
+    ) -> Result<Option<crate::storage::ErasedBaseStorage>, crate::private::PanicPayload> {
+        Ok(None)
+    }
 }
 
 /// Type representing the absence of a base class, at the root of the hierarchy.
@@ -617,8 +629,13 @@ impl<T> NewGd for T
 where
     T: cap::GodotDefault + Bounds<Memory = bounds::MemRefCounted>,
 {
+    #[track_caller]
     fn new_gd() -> Gd<Self> {
-        Gd::default()
+        let gd = Gd::default();
+        if crate::tools::is_leak_tracking_enabled() {
+            crate::tools::relocate_creation(gd.instance_id(), std::panic::Location::caller());
+        }
+        gd
     }
 }
 
@@ -639,10 +656,16 @@ impl<T> NewAlloc for T
 where
     T: cap::GodotDefault + Bounds<Memory = bounds::MemManual>,
 {
+    #[track_caller]
     fn new_alloc() -> Gd<Self> {
         use crate::obj::bounds::Declarer as _;
 
-        <Self as Bounds>::Declarer::create_gd()
+        let gd = <Self as Bounds>::Declarer::create_gd();
+        if crate::tools::is_leak_tracking_enabled() {
+            let location = Some(std::panic::Location::caller());
+            crate::tools::track_creation(gd.instance_id(), Self::class_name(), location);
+        }
+        gd
     }
 }
 
@@ -0,0 +1,182 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::classes;
+use crate::meta::error::ConvertError;
+use crate::meta::{ClassName, FromGodot, GodotConvert, PropertyHintInfo, ToGodot};
+use crate::obj::rtti::ObjectRtti;
+use crate::obj::{bounds, Bounds, Gd, GodotClass, InstanceId};
+use crate::registry::property::{Export, Var};
+
+/// Non-owning reference to a Godot object, keeping its static type `T`.
+///
+/// Unlike [`Gd<T>`], a `WeakGd<T>` does not increment the reference count of `RefCounted` objects and thus does not keep them alive.
+/// This makes it suitable to break reference cycles, e.g. for back-references from children to parents. Unlike a plain [`InstanceId`],
+/// the type `T` is retained: it is verified once on [`Gd::downgrade()`], so [`upgrade()`][Self::upgrade] needs no further dynamic cast.
+///
+/// A `WeakGd<T>` can also be empty, which is the [`Default`] value. Empty handles never upgrade.
+///
+/// # Properties
+/// `WeakGd<T>` can be used in `#[var]` and `#[export]` fields. The property is visible to Godot as a nullable object, which is
+/// `null` if the handle is empty or the object has been destroyed. Assigning an object stores a weak reference to it.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::obj::WeakGd;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Branch {
+///     #[var]
+///     parent: WeakGd<Branch>,
+///     #[var]
+///     children: Array<Gd<Branch>>,
+/// }
+///
+/// fn parent_of(item: &Gd<Branch>) -> Option<Gd<Branch>> {
+///     item.bind().parent.upgrade()
+/// }
+/// ```
+pub struct WeakGd<T: GodotClass> {
+    // None if empty. Contains instance ID and (in Debug mode) the class verified on downgrade.
+    rtti: Option<ObjectRtti>,
+
+    // Same thread-safety as Gd<T>.
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    pub(super) fn from_rtti(rtti: ObjectRtti) -> Self {
+        Self {
+            rtti: Some(rtti),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a strong reference to the object, or `None` if the handle is empty or the object has been destroyed.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        let rtti = self.rtti.as_ref()?;
+
+        let object_ptr = classes::object_ptr_from_id(rtti.instance_id());
+        if object_ptr.is_null() {
+            return None;
+        }
+
+        // No dynamic cast needed: the type was verified on downgrade(), and instance IDs are never reused, so the object is still a T.
+        // SAFETY: object_ptr is a live, non-null object of type T (or derived).
+        Some(unsafe { Gd::from_obj_sys(object_ptr) })
+    }
+
+    /// Returns `true` if the handle refers to an object that is still alive.
+    ///
+    /// This does not keep the object alive; prefer [`upgrade()`][Self::upgrade] if you intend to use the object.
+    pub fn is_alive(&self) -> bool {
+        self.rtti
+            .as_ref()
+            .is_some_and(|rtti| rtti.instance_id().lookup_validity())
+    }
+
+    /// Returns `true` if the handle was created empty, via [`Default`].
+    ///
+    /// A non-empty handle stays non-empty after the object is destroyed. Use [`is_alive()`][Self::is_alive] to check for that.
+    pub fn is_empty(&self) -> bool {
+        self.rtti.is_none()
+    }
+
+    /// Instance ID of the referenced object, or `None` if the handle is empty.
+    ///
+    /// The object may have been destroyed in the meantime.
+    pub fn instance_id(&self) -> Option<InstanceId> {
+        self.rtti.as_ref().map(|rtti| rtti.instance_id())
+    }
+}
+
+impl<T: GodotClass> Default for WeakGd<T> {
+    /// Creates an empty handle, which never upgrades.
+    fn default() -> Self {
+        Self {
+            rtti: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// Manual impls, as derives would require T: Clone/PartialEq.
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rtti: self.rtti.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: GodotClass> PartialEq for WeakGd<T> {
+    /// Two handles are equal if they refer to the same object (even if destroyed), or are both empty.
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id() == other.instance_id()
+    }
+}
+
+impl<T: GodotClass> Eq for WeakGd<T> {}
+
+impl<T: GodotClass> fmt::Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.instance_id() {
+            Some(id) => write!(f, "WeakGd<{}>({id})", T::class_name()),
+            None => write!(f, "WeakGd<{}>(empty)", T::class_name()),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Trait impls for properties
+
+impl<T: GodotClass> GodotConvert for WeakGd<T> {
+    type Via = Option<Gd<T>>;
+}
+
+impl<T: GodotClass> ToGodot for WeakGd<T> {
+    type ToVia<'v> = Option<Gd<T>>;
+
+    fn to_godot(&self) -> Self::ToVia<'_> {
+        self.upgrade()
+    }
+}
+
+impl<T: GodotClass> FromGodot for WeakGd<T> {
+    fn try_from_godot(via: Self::Via) -> Result<Self, ConvertError> {
+        Ok(via.map(|gd| gd.downgrade()).unwrap_or_default())
+    }
+}
+
+impl<T: GodotClass> Var for WeakGd<T> {
+    fn get_property(&self) -> Self::Via {
+        self.upgrade()
+    }
+
+    fn set_property(&mut self, value: Self::Via) {
+        *self = FromGodot::from_godot(value);
+    }
+}
+
+/// Exported like `Option<Gd<T>>`; see [`Gd` Exporting](struct.Gd.html#exporting).
+impl<T> Export for WeakGd<T>
+where
+    T: GodotClass + Bounds<Exportable = bounds::Yes>,
+{
+    fn export_hint() -> PropertyHintInfo {
+        PropertyHintInfo::export_gd::<T>()
+    }
+
+    #[doc(hidden)]
+    fn as_node_class() -> Option<ClassName> {
+        PropertyHintInfo::object_as_node_class::<T>()
+    }
+}
@@ -28,12 +28,12 @@ mod reexport_pub {
     pub use crate::obj::rtti::ObjectRtti;
     pub use crate::registry::callbacks;
     pub use crate::registry::plugin::{
-        ClassPlugin, DynTraitImpl, ErasedDynGd, ErasedRegisterFn, ITraitImpl, InherentImpl,
-        PluginItem, Struct,
+        ClassEnum, ClassPlugin, DynTraitImpl, ErasedDynGd, ErasedRegisterFn, ITraitImpl,
+        InherentImpl, PluginItem, Struct,
     };
     #[cfg(since_api = "4.2")]
     pub use crate::registry::signal::priv_re_export::*;
-    pub use crate::storage::{as_storage, Storage};
+    pub use crate::storage::{as_storage, ErasedBaseStorage, Storage};
     pub use crate::sys::out;
 }
 pub use reexport_pub::*;
@@ -50,6 +50,8 @@ static CALL_ERRORS: Global<CallErrors> = Global::default();
 static ERROR_PRINT_LEVEL: atomic::AtomicU8 = atomic::AtomicU8::new(2);
 
 sys::plugin_registry!(pub __GODOT_PLUGIN_REGISTRY: ClassPlugin);
+#[cfg(feature = "translation-template")]
+sys::plugin_registry!(pub __GODOT_TR_REGISTRY: crate::tools::TranslationMessage);
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Call error handling
@@ -144,6 +146,29 @@ pub(crate) fn iterate_plugins(mut visitor: impl FnMut(&ClassPlugin)) {
     sys::plugin_foreach!(__GODOT_PLUGIN_REGISTRY; visitor);
 }
 
+#[cfg(feature = "translation-template")]
+pub(crate) fn iterate_tr_messages(mut visitor: impl FnMut(&crate::tools::TranslationMessage)) {
+    sys::plugin_foreach!(__GODOT_TR_REGISTRY; visitor);
+}
+
+/// Returns the instance of a `#[class(singleton)]` class, as registered with the engine.
+///
+/// Used by the generated `singleton()` accessor.
+pub fn user_singleton<T: crate::obj::GodotClass>() -> Gd<T> {
+    let class_name = T::class_name();
+
+    // SAFETY: valid class name; the returned object (if any) was registered under this class name, thus has dynamic type `T`.
+    unsafe {
+        let object_ptr = sys::interface_fn!(global_get_singleton)(class_name.string_sys());
+        assert!(
+            !object_ptr.is_null(),
+            "singleton `{class_name}` is not registered; is its init level already loaded?"
+        );
+
+        Gd::from_obj_sys(object_ptr)
+    }
+}
+
 #[cfg(feature = "codegen-full")] // Remove if used in other scenarios.
 pub(crate) fn find_inherent_impl(class_name: crate::meta::ClassName) -> Option<InherentImpl> {
     // We do this manually instead of using `iterate_plugins()` because we want to break as soon as we find a match.
@@ -191,6 +216,62 @@ pub unsafe fn has_virtual_script_method(
     sys::interface_fn!(object_has_script_method)(sys::to_const_ptr(object_ptr), method_sname) != 0
 }
 
+/// Whether a Rust class deriving from `declaring_class` overrides its `#[func(virtual)]` method `method_sname`.
+///
+/// Derived Rust classes override a method by registering one with the same name. Walks the class hierarchy from the object's dynamic class
+/// up to (excluding) `declaring_class`.
+#[cfg(since_api = "4.3")]
+pub unsafe fn has_virtual_rust_override(
+    object_ptr: sys::GDExtensionObjectPtr,
+    declaring_class: crate::meta::ClassName,
+    method_sname: &crate::builtin::StringName,
+) -> bool {
+    // Fast path: most classes with virtual functions have no Rust subclasses, so the object's class need not be queried.
+    if !crate::registry::class::has_rust_subclasses(declaring_class) {
+        return false;
+    }
+
+    let mut class_name = crate::builtin::StringName::new_with_string_uninit(|ptr| {
+        let success = sys::interface_fn!(object_get_class_name)(
+            sys::to_const_ptr(object_ptr),
+            sys::get_library(),
+            ptr,
+        );
+
+        assert!(
+            sys::conv::bool_from_sys(success),
+            "failed to get class name for object"
+        );
+    });
+
+    // Object is an instance of `declaring_class` itself.
+    let Some(subclass) = crate::registry::class::find_rust_subclass(declaring_class, &class_name)
+    else {
+        return false;
+    };
+
+    // Result only depends on the classes, so the engine is queried once per class and method.
+    let lookup = || {
+        let declaring_class_name = declaring_class.to_string_name();
+        let class_db = classes::ClassDb::singleton();
+        while class_name != declaring_class_name && class_name.len() > 0 {
+            if class_db
+                .class_has_method_ex(&class_name, method_sname)
+                .no_inheritance(true)
+                .done()
+            {
+                return true;
+            }
+
+            class_name = class_db.get_parent_class(&class_name);
+        }
+
+        false
+    };
+
+    crate::registry::class::has_virtual_override(declaring_class, subclass, method_sname, lookup)
+}
+
 /// Ensure `T` is an editor plugin.
 pub const fn is_editor_plugin<T: crate::obj::Inherits<crate::classes::EditorPlugin>>() {}
 
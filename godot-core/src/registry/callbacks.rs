@@ -20,10 +20,14 @@ use crate::builder::ClassBuilder;
 use crate::builtin::{StringName, Variant};
 use crate::classes::Object;
 use crate::meta::PropertyInfo;
-use crate::obj::{bounds, cap, AsDyn, Base, Bounds, Gd, GodotClass, Inherits, UserClass};
+use crate::obj::{
+    bounds, cap, AsDyn, Base, Bounds, Gd, GdDerefTarget, GodotClass, Inherits, InstanceId,
+    UserClass,
+};
 use crate::private::{handle_panic, PanicPayload};
+use crate::registry::class::VirtualChain;
 use crate::registry::plugin::ErasedDynGd;
-use crate::storage::{as_storage, InstanceStorage, Storage, StorageRefCounted};
+use crate::storage::{as_storage, ErasedBaseStorage, InstanceStorage, Storage, StorageRefCounted};
 
 /// Godot FFI default constructor.
 ///
@@ -63,6 +67,16 @@ pub unsafe extern "C" fn create_null<T>(
     std::ptr::null_mut()
 }
 
+/// Creates the instance of a `#[class(singleton)]` class, which is then registered with the engine.
+///
+/// Unlike [`create`], this is not a Godot FFI callback; it runs once during class registration.
+pub fn create_singleton<T>() -> Gd<Object>
+where
+    T: cap::GodotDefault + Inherits<Object> + Bounds<Memory = bounds::MemManual>,
+{
+    Gd::<T>::default_instance().upcast()
+}
+
 /// Godot FFI function for recreating a GDExtension instance, e.g. after a hot reload.
 ///
 /// If the `init()` constructor panics, null is returned.
@@ -93,7 +107,8 @@ where
     T: GodotClass,
     F: FnOnce(Base<T::Base>) -> T,
 {
-    let base_class_name = T::Base::class_name();
+    // Only the engine part is constructed by Godot. Parts of Rust base classes are created together with the Rust part of T.
+    let base_class_name = GdDerefTarget::<T>::class_name();
     let base_ptr = unsafe { interface_fn!(classdb_construct_object)(base_class_name.string_sys()) };
 
     match create_rust_part_for_existing_godot_part(make_user_instance, base_ptr) {
@@ -127,18 +142,7 @@ where
     //out!("create callback: {}", class_name.backing);
 
     let base = unsafe { Base::from_sys(base_ptr) };
-
-    // User constructor init() can panic, which crashes the engine if unhandled.
-    let context = || format!("panic during {class_name}::init() constructor");
-    let code = || make_user_instance(unsafe { Base::from_base(&base) });
-    let user_instance = handle_panic(context, std::panic::AssertUnwindSafe(code))?;
-
-    // Print shouldn't be necessary as panic itself is printed. If this changes, re-enable in error case:
-    // godot_error!("failed to create instance of {class_name}; Rust init() panicked");
-
-    let mut base_copy = unsafe { Base::from_base(&base) };
-
-    let instance = InstanceStorage::<T>::construct(user_instance, base);
+    let (instance, mut base_copy) = create_storage(make_user_instance, base)?;
     let instance_rust_ptr = instance.into_raw();
     let instance_ptr = instance_rust_ptr as sys::GDExtensionClassInstancePtr;
 
@@ -156,10 +160,65 @@ where
     // Mark initialization as complete, now that user constructor has finished.
     base_copy.mark_initialized();
 
+    // Location is filled in by Rust-side constructors such as Gd::from_init_fn(); stays empty if instantiated by Godot.
+    if crate::tools::is_leak_tracking_enabled() {
+        let raw_id = unsafe { interface_fn!(object_get_instance_id)(base_ptr) };
+        if let Some(instance_id) = InstanceId::try_from_u64(raw_id) {
+            crate::tools::track_creation(instance_id, class_name, None);
+        }
+    }
+
     // No std::mem::forget(base_copy) here, since Base may stores other fields that need deallocation.
     Ok(instance_ptr)
 }
 
+/// Creates the part of an object that belongs to the Rust base class `T`, for a derived Rust class.
+///
+/// The returned storage is owned by the derived storage; it is not linked to the Godot object on its own. Its `Base` shares the
+/// initialization state with `derived_base`, so it is marked as initialized together with the derived object.
+pub fn create_base_part<T: cap::GodotDefault>(
+    derived_base: &Base<T>,
+) -> Result<Option<ErasedBaseStorage>, PanicPayload> {
+    let base = unsafe { Base::from_derived_base(derived_base) };
+    let (instance, _base_copy) = create_storage(T::__godot_user_init, base)?;
+
+    Ok(Some(ErasedBaseStorage::new(instance)))
+}
+
+/// Runs the user constructor and creates the storage, including the parts of Rust base classes.
+///
+/// Returns a copy of the `Base`, which must be marked as initialized once the object is complete.
+fn create_storage<T, F>(
+    make_user_instance: F,
+    base: Base<T::Base>,
+) -> Result<(InstanceStorage<T>, Base<T::Base>), PanicPayload>
+where
+    T: GodotClass,
+    F: FnOnce(Base<T::Base>) -> T,
+{
+    let class_name = T::class_name();
+
+    // Rust base classes are constructed first, like base classes in the engine.
+    let base_storage = T::__create_base_part(&base)?;
+
+    // User constructor init() can panic, which crashes the engine if unhandled.
+    let context = || format!("panic during {class_name}::init() constructor");
+    let code = || make_user_instance(unsafe { Base::from_base(&base) });
+    let user_instance = handle_panic(context, std::panic::AssertUnwindSafe(code))?;
+
+    // Print shouldn't be necessary as panic itself is printed. If this changes, re-enable in error case:
+    // godot_error!("failed to create instance of {class_name}; Rust init() panicked");
+
+    let base_copy = unsafe { Base::from_base(&base) };
+
+    let mut instance = InstanceStorage::<T>::construct(user_instance, base);
+    if let Some(base_storage) = base_storage {
+        instance.set_base_storage(base_storage);
+    }
+
+    Ok((instance, base_copy))
+}
+
 pub unsafe extern "C" fn free<T: GodotClass>(
     _class_user_data: *mut std::ffi::c_void,
     instance: sys::GDExtensionClassInstancePtr,
@@ -197,6 +256,31 @@ pub unsafe extern "C" fn get_virtual<T: cap::ImplementsGodotVirtual>(
     T::__virtual_call(method_name.as_str())
 }
 
+/// Godot FFI function for virtual method lookup of a Rust class deriving from another Rust class.
+///
+/// Virtual methods not overridden by the class itself are looked up in the base class. `class_user_data` points to a `VirtualChain`.
+#[cfg(since_api = "4.4")]
+pub unsafe extern "C" fn get_virtual_chained(
+    class_user_data: *mut std::ffi::c_void,
+    name: sys::GDExtensionConstStringNamePtr,
+    hash: u32,
+) -> sys::GDExtensionClassCallVirtual {
+    let chain = &*(class_user_data as *const VirtualChain);
+
+    (chain.own)(std::ptr::null_mut(), name, hash)
+        .or_else(|| (chain.base)(chain.base_userdata, name, hash))
+}
+
+#[cfg(before_api = "4.4")]
+pub unsafe extern "C" fn get_virtual_chained(
+    class_user_data: *mut std::ffi::c_void,
+    name: sys::GDExtensionConstStringNamePtr,
+) -> sys::GDExtensionClassCallVirtual {
+    let chain = &*(class_user_data as *const VirtualChain);
+
+    (chain.own)(std::ptr::null_mut(), name).or_else(|| (chain.base)(chain.base_userdata, name))
+}
+
 #[cfg(since_api = "4.4")]
 pub unsafe extern "C" fn default_get_virtual<T: UserClass>(
     _class_user_data: *mut std::ffi::c_void,
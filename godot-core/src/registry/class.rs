@@ -11,11 +11,13 @@ use std::{any, ptr};
 use godot_ffi::join_with;
 use sys::{interface_fn, out, Global, GlobalGuard, GlobalLockError};
 
+#[cfg(since_api = "4.3")]
+use crate::builtin::StringName;
 use crate::classes::ClassDb;
 use crate::init::InitLevel;
 use crate::meta::error::FromGodotError;
 use crate::meta::ClassName;
-use crate::obj::{cap, DynGd, Gd, GodotClass};
+use crate::obj::{cap, DynGd, Gd, GodotClass, InstanceId};
 use crate::private::{ClassPlugin, PluginItem};
 use crate::registry::callbacks;
 use crate::registry::plugin::{DynTraitImpl, ErasedRegisterFn, ITraitImpl, InherentImpl, Struct};
@@ -46,6 +48,16 @@ fn global_loaded_classes_by_name() -> GlobalGuard<'static, HashMap<ClassName, Cl
     lock_or_panic(&LOADED_CLASSES_BY_NAME, "loaded classes (by name)")
 }
 
+/// Returns a lock to the global state of `#[class(singleton)]` instances.
+///
+/// Singletons are created after all classes of a level have been registered, and destroyed before the classes of that level are
+/// unregistered. Only instance IDs are stored, as `Gd` pointers cannot be sent across threads.
+fn global_singletons() -> GlobalGuard<'static, LoadedSingletons> {
+    static SINGLETONS: Global<LoadedSingletons> = Global::default();
+
+    lock_or_panic(&SINGLETONS, "singletons")
+}
+
 fn global_dyn_traits_by_typeid() -> GlobalGuard<'static, HashMap<any::TypeId, Vec<DynTraitImpl>>> {
     static DYN_TRAITS_BY_TYPEID: Global<HashMap<any::TypeId, Vec<DynTraitImpl>>> =
         Global::default();
@@ -63,10 +75,66 @@ pub struct LoadedClass {
     is_editor_plugin: bool,
 }
 
+/// Instances of `#[class(singleton)]` classes.
+#[derive(Default)]
+struct LoadedSingletons {
+    /// Singletons of classes below `Scene` level, whose creation is deferred until `Scene` is loaded.
+    pending: Vec<PendingSingleton>,
+
+    /// Singletons that are registered with the engine, by the level at which they were created.
+    by_init_level: HashMap<InitLevel, Vec<LoadedSingleton>>,
+}
+
+struct PendingSingleton {
+    class_name: ClassName,
+    create_fn: fn() -> Gd<classes::Object>,
+}
+
+/// Instance of a `#[class(singleton)]` class, registered with the engine.
+struct LoadedSingleton {
+    class_name: ClassName,
+    instance_id: InstanceId,
+}
+
 /// Represents a class which is currently loaded and retained in memory -- including metadata.
-//
-// Currently empty, but should already work for per-class queries.
-pub struct ClassMetadata {}
+pub struct ClassMetadata {
+    /// Callbacks that Rust subclasses fall back to, if they don't provide their own. Set once the class is registered with Godot.
+    inheritable_callbacks: Option<InheritableCallbacks>,
+
+    /// Virtual method lookup passed to Godot, if the class derives from a Rust class. Freed once the class is unregistered.
+    virtual_chain: Option<Box<VirtualChain>>,
+
+    /// Rust classes deriving from this class, directly or indirectly. Recorded when they are registered.
+    #[cfg(since_api = "4.3")]
+    rust_subclasses: Vec<ClassName>,
+
+    /// Whether a Rust subclass overrides a `#[func(virtual)]` method of this class, by (subclass, method name).
+    #[cfg(since_api = "4.3")]
+    virtual_overrides: HashMap<(ClassName, StringName), bool>,
+}
+
+/// Creation parameters of a registered class, which Rust subclasses inherit unless they provide their own callbacks.
+///
+/// Godot only invokes the callbacks of the most-derived extension class, so inheritance between Rust classes is emulated on our side.
+#[derive(Copy, Clone)]
+struct InheritableCallbacks(GodotCreationInfo);
+
+// SAFETY: apart from function pointers, the only pointer is `class_userdata`, which is null or points to an immutable VirtualChain.
+// The chain is owned by the class' metadata, which outlives its registration with Godot.
+unsafe impl Send for InheritableCallbacks {}
+
+/// Virtual method lookup of a Rust class deriving from another Rust class; passed to Godot as class userdata.
+pub(crate) struct VirtualChain {
+    /// Lookup of the class itself.
+    pub own: GodotGetVirtual,
+
+    /// Lookup of the base class, with its own class userdata.
+    pub base: GodotGetVirtual,
+    pub base_userdata: *mut std::ffi::c_void,
+}
+
+// SAFETY: immutable after creation; `base_userdata` points to the chain of the base class, which is unregistered after this one.
+unsafe impl Send for VirtualChain {}
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
@@ -103,6 +171,9 @@ struct ClassRegistrationInfo {
     init_level: InitLevel,
     is_editor_plugin: bool,
 
+    /// Set if `#[class(singleton)]` was used; creates the instance to be registered with the engine.
+    create_singleton_fn: Option<fn() -> Gd<classes::Object>>,
+
     /// One entry for each `dyn Trait` implemented (and registered) for this class.
     dynify_fns_by_trait: HashMap<any::TypeId, DynTraitImpl>,
 
@@ -123,6 +194,9 @@ impl ClassRegistrationInfo {
             // Multiple dyn traits can be registered, thus don't validate for uniqueness.
             // (Still keep array size, so future additions don't have to regard this).
             PluginItem::DynTraitImpl { .. } => return,
+
+            // A class can declare several enums; they are only looked up through class_enum_info().
+            PluginItem::ClassEnum(_) => return,
         };
 
         if self.component_already_filled[index] {
@@ -180,6 +254,7 @@ pub(crate) fn register_class<
         godot_params,
         init_level: T::INIT_LEVEL,
         is_editor_plugin: false,
+        create_singleton_fn: None,
         dynify_fns_by_trait: HashMap::new(),
         component_already_filled: Default::default(), // [false; N]
     });
@@ -215,14 +290,18 @@ pub fn auto_register_classes(init_level: InitLevel) {
     // We need all the dyn classes in the registry to properly register DynGd properties;
     // one can do it directly inside the loop – by locking and unlocking the mutex –
     // but it is much slower and doesn't guarantee that all the dependent classes will be already loaded in most cases.
-    register_classes_and_dyn_traits(&mut map, init_level);
+    let mut classes = order_by_inheritance(map);
+    register_classes_and_dyn_traits(&mut classes, init_level);
 
     // Editor plugins should be added to the editor AFTER all the classes has been registered.
     // Adding EditorPlugin to the Editor before registering all the classes it depends on might result in crash.
     let mut editor_plugins: Vec<ClassName> = Vec::new();
 
+    // Singletons are instantiated once all classes of this level are registered, as their init() may depend on other classes.
+    let mut singletons: Vec<PendingSingleton> = Vec::new();
+
     // Actually register all the classes.
-    for info in map.into_values() {
+    for info in classes {
         #[cfg(feature = "debug-log")]
         let class_name = info.class_name;
 
@@ -230,6 +309,15 @@ pub fn auto_register_classes(init_level: InitLevel) {
             editor_plugins.push(info.class_name);
         }
 
+        if let Some(create_fn) = info.create_singleton_fn {
+            if validate_singleton_constraints(&info) {
+                singletons.push(PendingSingleton {
+                    class_name: info.class_name,
+                    create_fn,
+                });
+            }
+        }
+
         register_class_raw(info);
 
         out!("Class {class_name} loaded.");
@@ -243,18 +331,119 @@ pub fn auto_register_classes(init_level: InitLevel) {
         unsafe { interface_fn!(editor_add_plugin)(editor_plugin_class_name.string_sys()) };
     }
 
+    register_singletons(singletons, init_level);
+
     out!("All classes for level `{init_level:?}` auto-registered.");
 }
 
-fn register_classes_and_dyn_traits(
-    map: &mut HashMap<ClassName, ClassRegistrationInfo>,
-    init_level: InitLevel,
-) {
+/// Creates the instances of `#[class(singleton)]` classes and makes them available through `Engine.get_singleton()`.
+fn register_singletons(singletons: Vec<PendingSingleton>, init_level: InitLevel) {
+    // The engine's singleton registry is not reliably available before `Scene` level. Singletons of lower-level classes (e.g. those
+    // inheriting `Object` directly) are thus created once `Scene` is loaded, and destroyed when it is unloaded.
+    let singletons = {
+        let mut global = global_singletons();
+        if init_level < InitLevel::Scene {
+            global.pending.extend(singletons);
+            return;
+        }
+
+        let mut all = std::mem::take(&mut global.pending);
+        all.extend(singletons);
+        all
+    };
+
+    if singletons.is_empty() {
+        return;
+    }
+
+    // Lock is not held while creating instances, since user-defined init() may run arbitrary code.
+    let mut engine = classes::Engine::singleton();
+    let mut loaded_singletons = Vec::with_capacity(singletons.len());
+
+    for PendingSingleton {
+        class_name,
+        create_fn,
+    } in singletons
+    {
+        let instance = create_fn();
+        engine.register_singleton(&class_name.to_string_name(), &instance);
+
+        out!("Singleton {class_name} registered.");
+        loaded_singletons.push(LoadedSingleton {
+            class_name,
+            instance_id: instance.instance_id(),
+        });
+    }
+
+    global_singletons()
+        .by_init_level
+        .entry(init_level)
+        .or_default()
+        .extend(loaded_singletons);
+}
+
+/// Removes `#[class(singleton)]` instances from the engine and frees them, before their classes are unregistered.
+fn unregister_singletons(init_level: InitLevel) {
+    let loaded_singletons = {
+        let mut global = global_singletons();
+        global.pending.clear();
+        global.by_init_level.remove(&init_level).unwrap_or_default()
+    };
+
+    if loaded_singletons.is_empty() {
+        return;
+    }
+
+    let mut engine = classes::Engine::singleton();
+    for singleton in loaded_singletons.into_iter().rev() {
+        let class_name = singleton.class_name;
+        engine.unregister_singleton(&class_name.to_string_name());
+
+        // User may have freed the instance manually; don't double-free.
+        if let Ok(instance) = Gd::<classes::Object>::try_from_instance_id(singleton.instance_id) {
+            instance.free();
+        }
+
+        out!("Singleton {class_name} unregistered.");
+    }
+}
+
+/// Orders classes so that Rust base classes are registered before the classes deriving from them.
+///
+/// Apart from that, the order is unspecified.
+fn order_by_inheritance(
+    mut map: HashMap<ClassName, ClassRegistrationInfo>,
+) -> Vec<ClassRegistrationInfo> {
+    let mut ordered = Vec::with_capacity(map.len());
+
+    while !map.is_empty() {
+        // Classes whose base is not waiting for registration: engine classes, or Rust classes already in `ordered`.
+        let ready = map
+            .values()
+            .filter(|info| {
+                info.parent_class_name
+                    .is_none_or(|parent| !map.contains_key(&parent))
+            })
+            .map(|info| info.class_name)
+            .collect::<Vec<_>>();
+
+        // Cycles cannot occur, as the Rust type system rejects recursive `GodotClass::Base` chains.
+        assert!(!ready.is_empty(), "cyclic inheritance between Rust classes");
+
+        for class_name in ready {
+            ordered.extend(map.remove(&class_name));
+        }
+    }
+
+    ordered
+}
+
+fn register_classes_and_dyn_traits(classes: &mut [ClassRegistrationInfo], init_level: InitLevel) {
     let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
     let mut loaded_classes_by_name = global_loaded_classes_by_name();
     let mut dyn_traits_by_typeid = global_dyn_traits_by_typeid();
 
-    for info in map.values_mut() {
+    for info in classes.iter_mut() {
         let class_name = info.class_name;
         out!("Register class:   {class_name} at level `{init_level:?}`");
 
@@ -262,7 +451,14 @@ fn register_classes_and_dyn_traits(
             name: class_name,
             is_editor_plugin: info.is_editor_plugin,
         };
-        let metadata = ClassMetadata {};
+        let metadata = ClassMetadata {
+            inheritable_callbacks: None,
+            virtual_chain: None,
+            #[cfg(since_api = "4.3")]
+            rust_subclasses: Vec::new(),
+            #[cfg(since_api = "4.3")]
+            virtual_overrides: HashMap::new(),
+        };
 
         // Transpose Class->Trait relations to Trait->Class relations.
         for (trait_type_id, mut dyn_trait_impl) in info.dynify_fns_by_trait.drain() {
@@ -285,6 +481,9 @@ fn register_classes_and_dyn_traits(
 }
 
 pub fn unregister_classes(init_level: InitLevel) {
+    // Singleton instances must be destroyed while their class is still registered.
+    unregister_singletons(init_level);
+
     let mut loaded_classes_by_level = global_loaded_classes_by_init_level();
     let mut loaded_classes_by_name = global_loaded_classes_by_name();
     // TODO clean up dyn traits
@@ -295,11 +494,12 @@ pub fn unregister_classes(init_level: InitLevel) {
 
     out!("Unregister classes of level {init_level:?}...");
     for class in loaded_classes_current_level.into_iter().rev() {
-        // Remove from other map.
-        loaded_classes_by_name.remove(&class.name);
+        // Remove from other map. Metadata owns the virtual chain, so it must outlive the registration with Godot.
+        let metadata = loaded_classes_by_name.remove(&class.name);
 
         // Unregister from Godot.
         unregister_class_raw(class);
+        drop(metadata);
     }
 }
 
@@ -426,6 +626,11 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             is_editor_plugin,
             is_internal,
             is_instantiable,
+            create_singleton_fn,
+            create_resource_format_fn: _, // Added in resource_format::add_resource_formats().
+            #[cfg(since_api = "4.2")]
+                create_script_language_fn: _, // Added in script_language::add_script_languages().
+            icon: _,                      // Only used by init::class_icons().
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
                 docs: _,
             reference_fn,
@@ -435,6 +640,7 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
             c.default_virtual_fn = default_get_virtual_fn;
             c.register_properties_fn = Some(register_properties_fn);
             c.is_editor_plugin = is_editor_plugin;
+            c.create_singleton_fn = create_singleton_fn;
 
             // Classes marked #[class(no_init)] are translated to "abstract" in Godot. This disables their default constructor.
             // "Abstract" is a misnomer -- it's not an abstract base class, but rather a "utility/static class" (although it can have instance
@@ -548,6 +754,9 @@ fn fill_class_info(item: PluginItem, c: &mut ClassRegistrationInfo) {
                 c.class_name
             );
         }
+        PluginItem::ClassEnum(_) => {
+            // Constants are registered with the inherent impl; the type link is resolved in constant::class_enum_info().
+        }
     }
     // out!("|   reg (after):     {c:?}");
     // out!();
@@ -581,6 +790,9 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         info.godot_params.get_virtual_func = info.user_virtual_fn.or(info.default_virtual_fn);
     }
 
+    // If the base is a Rust class, it has been registered before; fall back to its callbacks.
+    let virtual_chain = inherit_base_callbacks(&mut info.godot_params, parent_class_name);
+
     // The explicit () type notifies us if Godot API ever adds a return type.
     let registration_failed = unsafe {
         // Try to register class...
@@ -616,6 +828,16 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
         godot_error!(
             "Failed to register class `{class_name}`; check preceding Godot stderr messages."
         );
+    } else {
+        let mut loaded_classes_by_name = global_loaded_classes_by_name();
+
+        if let Some(metadata) = loaded_classes_by_name.get_mut(&class_name) {
+            metadata.inheritable_callbacks = Some(InheritableCallbacks(info.godot_params));
+            metadata.virtual_chain = virtual_chain;
+        }
+
+        #[cfg(since_api = "4.3")]
+        record_rust_subclass(&mut loaded_classes_by_name, class_name, parent_class_name);
     }
 
     // ...then custom symbols
@@ -640,10 +862,169 @@ fn register_class_raw(mut info: ClassRegistrationInfo) {
     }
 }
 
+/// Fills callbacks that a class derived from a Rust base class doesn't provide itself, with those of the base class.
+///
+/// Godot only invokes callbacks of the most-derived extension class. The base class callbacks can be reused as-is, since they find their
+/// part of the object through the storage chain (see `as_storage()`).
+///
+/// Returns the virtual method lookup passed as class userdata, if any. It must be kept alive until the class is unregistered.
+fn inherit_base_callbacks(
+    params: &mut GodotCreationInfo,
+    base_class_name: ClassName,
+) -> Option<Box<VirtualChain>> {
+    let base = match global_loaded_classes_by_name().get(&base_class_name) {
+        Some(ClassMetadata {
+            inheritable_callbacks: Some(callbacks),
+            ..
+        }) => callbacks.0,
+
+        // Engine class.
+        _ => return None,
+    };
+
+    // Virtual methods that are not overridden are looked up in the base class.
+    let mut virtual_chain = None;
+    match (params.get_virtual_func, base.get_virtual_func) {
+        (None, _) => {
+            params.get_virtual_func = base.get_virtual_func;
+            params.class_userdata = base.class_userdata;
+        }
+        (Some(own), Some(base_get_virtual)) => {
+            // Moving the box doesn't move the chain, so the pointer stays valid while the metadata holds it.
+            let chain = virtual_chain.insert(Box::new(VirtualChain {
+                own,
+                base: base_get_virtual,
+                base_userdata: base.class_userdata,
+            }));
+
+            params.get_virtual_func = Some(callbacks::get_virtual_chained);
+            params.class_userdata = ptr::from_ref::<VirtualChain>(chain).cast_mut().cast();
+        }
+        (Some(_), None) => {}
+    }
+
+    params.to_string_func = params.to_string_func.or(base.to_string_func);
+    params.notification_func = params.notification_func.or(base.notification_func);
+    params.set_func = params.set_func.or(base.set_func);
+    params.get_func = params.get_func.or(base.get_func);
+    params.property_can_revert_func = params
+        .property_can_revert_func
+        .or(base.property_can_revert_func);
+    params.property_get_revert_func = params
+        .property_get_revert_func
+        .or(base.property_get_revert_func);
+
+    // Allocation and deallocation of the property list must match.
+    if params.get_property_list_func.is_none() {
+        params.get_property_list_func = base.get_property_list_func;
+        params.free_property_list_func = base.free_property_list_func;
+    }
+
+    #[cfg(since_api = "4.2")]
+    {
+        params.validate_property_func = params
+            .validate_property_func
+            .or(base.validate_property_func);
+    }
+
+    virtual_chain
+}
+
+/// Adds `class_name` to the Rust subclasses of its base class and all further Rust ancestors. Engine base classes are not tracked.
+#[cfg(since_api = "4.3")]
+fn record_rust_subclass(
+    loaded_classes_by_name: &mut HashMap<ClassName, ClassMetadata>,
+    class_name: ClassName,
+    base_class_name: ClassName,
+) {
+    if !loaded_classes_by_name.contains_key(&base_class_name) {
+        return;
+    }
+
+    // Ancestors of the base already list the base among their subclasses, since it was registered before.
+    for (ancestor_name, metadata) in loaded_classes_by_name.iter_mut() {
+        let is_ancestor = *ancestor_name == base_class_name
+            || metadata.rust_subclasses.contains(&base_class_name);
+
+        // A class may be registered again on hot reload, while its base stays loaded.
+        if is_ancestor && !metadata.rust_subclasses.contains(&class_name) {
+            metadata.rust_subclasses.push(class_name);
+        }
+    }
+}
+
+/// Whether any Rust class derives from the Rust class `class_name`.
+#[cfg(since_api = "4.3")]
+pub(crate) fn has_rust_subclasses(class_name: ClassName) -> bool {
+    global_loaded_classes_by_name()
+        .get(&class_name)
+        .is_some_and(|metadata| !metadata.rust_subclasses.is_empty())
+}
+
+/// Returns the Rust subclass of `declaring_class` with the Godot name `subclass_name`, if any.
+#[cfg(since_api = "4.3")]
+pub(crate) fn find_rust_subclass(
+    declaring_class: ClassName,
+    subclass_name: &StringName,
+) -> Option<ClassName> {
+    let loaded_classes_by_name = global_loaded_classes_by_name();
+    let metadata = loaded_classes_by_name.get(&declaring_class)?;
+
+    metadata
+        .rust_subclasses
+        .iter()
+        .copied()
+        .find(|subclass| subclass.to_string_name() == *subclass_name)
+}
+
+/// Whether `subclass` overrides the `#[func(virtual)]` method `method` of the Rust class `declaring_class`.
+///
+/// The result of `lookup` is cached in the metadata of `declaring_class`, so it's discarded when the class is unregistered (e.g. on hot reload).
+#[cfg(since_api = "4.3")]
+pub(crate) fn has_virtual_override(
+    declaring_class: ClassName,
+    subclass: ClassName,
+    method: &StringName,
+    lookup: impl FnOnce() -> bool,
+) -> bool {
+    let key = (subclass, method.clone());
+    if let Some(metadata) = global_loaded_classes_by_name().get(&declaring_class) {
+        if let Some(&is_overridden) = metadata.virtual_overrides.get(&key) {
+            return is_overridden;
+        }
+    }
+
+    // Not locked during lookup, which calls into the engine.
+    let is_overridden = lookup();
+
+    if let Some(metadata) = global_loaded_classes_by_name().get_mut(&declaring_class) {
+        metadata.virtual_overrides.insert(key, is_overridden);
+    }
+
+    is_overridden
+}
+
 fn validate_class_constraints(_class: &ClassRegistrationInfo) {
     // TODO: if we add builder API, the proc-macro checks in parse_struct_attributes() etc. should be duplicated here.
 }
 
+/// Checks that a `#[class(singleton)]` class cannot be instantiated from Godot, as this would undermine the single instance.
+///
+/// The proc-macro already rejects this combination; the check covers classes registered through other means. Returns whether the
+/// singleton may be registered.
+fn validate_singleton_constraints(class: &ClassRegistrationInfo) -> bool {
+    // Do not panic; otherwise the remaining classes of this level are not registered.
+    if !sys::conv::bool_from_sys(class.godot_params.is_abstract) {
+        godot_error!(
+            "Class `{}` is a #[class(singleton)], but is also instantiable from Godot; the singleton is not registered.",
+            class.class_name
+        );
+        return false;
+    }
+
+    true
+}
+
 fn unregister_class_raw(class: LoadedClass) {
     let class_name = class.name;
     out!("Unregister class: {class_name}");
@@ -668,7 +1049,7 @@ fn unregister_class_raw(class: LoadedClass) {
     out!("Class {class_name} unloaded");
 }
 
-fn lock_or_panic<T>(global: &'static Global<T>, ctx: &str) -> GlobalGuard<'static, T> {
+pub(crate) fn lock_or_panic<T>(global: &'static Global<T>, ctx: &str) -> GlobalGuard<'static, T> {
     match global.try_lock() {
         Ok(it) => it,
         Err(err) => match err {
@@ -698,6 +1079,7 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
         godot_params: default_creation_info(),
         init_level: InitLevel::Scene,
         is_editor_plugin: false,
+        create_singleton_fn: None,
         dynify_fns_by_trait: HashMap::new(),
         component_already_filled: Default::default(), // [false; N]
     }
@@ -5,11 +5,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::any::TypeId;
+use std::collections::HashMap;
+
 use godot_ffi as sys;
-use sys::interface_fn;
+use sys::{interface_fn, Global};
 
 use crate::builtin::StringName;
 use crate::meta::ClassName;
+use crate::private::{ClassPlugin, PluginItem};
+use crate::registry::class::lock_or_panic;
 
 /// A constant named `name` with the value `value`.
 pub struct IntegerConstant {
@@ -59,6 +64,21 @@ pub enum ConstantKind {
 }
 
 impl ConstantKind {
+    /// Enum or bitfield named `name`, with the enumerators of `E`.
+    pub fn from_export_enum<E: ExportEnum>(name: &str) -> Self {
+        let name = StringName::from(name);
+        let enumerators = E::enumerators();
+
+        if E::IS_BITFIELD {
+            ConstantKind::Bitfield {
+                name,
+                flags: enumerators,
+            }
+        } else {
+            ConstantKind::Enum { name, enumerators }
+        }
+    }
+
     fn register(&self, class_name: ClassName) {
         match self {
             ConstantKind::Integer(integer) => {
@@ -93,3 +113,69 @@ impl ExportConstant {
         self.kind.register(self.class_name)
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Class-scoped enums
+
+/// Rust enum or bitfield, which can be registered as class-scoped constants with `#[export_enum]`.
+///
+/// Implemented by `#[derive(EngineEnum)]` and `#[derive(EngineBitfield)]`.
+pub trait ExportEnum: 'static {
+    /// Whether the constants are flags that can be combined, rather than distinct enumerators.
+    const IS_BITFIELD: bool;
+
+    /// All enumerators (or flags), in order of declaration.
+    fn enumerators() -> Vec<IntegerConstant>;
+}
+
+/// Describes a Rust type that is registered as a class-scoped enum or bitfield, e.g. `Player.State`.
+///
+/// Returned by [`GodotConvert::class_enum()`][crate::meta::GodotConvert::class_enum] and used to declare properties, parameters and
+/// return types of that type in Godot's metadata, so that GDScript sees the enum rather than a plain `int`.
+#[derive(Copy, Clone, Debug)]
+pub struct ClassEnumInfo {
+    /// Enum name qualified with the class, e.g. `Player.State`.
+    pub(crate) qualified_name: ClassName,
+    pub(crate) is_bitfield: bool,
+}
+
+/// Cache for [`class_enum_info()`]; cleared in [`cleanup()`] as it holds dynamic `ClassName`s.
+static CLASS_ENUMS: Global<Option<HashMap<TypeId, ClassEnumInfo>>> = Global::default();
+
+/// Returns the class-scoped enum as which `E` is registered, if any.
+///
+/// Looks up `#[export_enum]` declarations across all classes. Lazily initialized on first use.
+pub fn class_enum_info<E: 'static>() -> Option<ClassEnumInfo> {
+    let mut class_enums = lock_or_panic(&CLASS_ENUMS, "class enums");
+    let class_enums = class_enums.get_or_insert_with(load_class_enums);
+
+    class_enums.get(&TypeId::of::<E>()).copied()
+}
+
+/// Forgets the cached enum infos, whose `ClassName`s are invalidated by `ClassName` cleanup (e.g. on hot reload).
+pub(crate) fn cleanup() {
+    *lock_or_panic(&CLASS_ENUMS, "class enums") = None;
+}
+
+fn load_class_enums() -> HashMap<TypeId, ClassEnumInfo> {
+    let mut declared = Vec::new();
+    crate::private::iterate_plugins(|elem: &ClassPlugin| {
+        if let PluginItem::ClassEnum(class_enum) = &elem.item {
+            declared.push((elem.class_name, class_enum.clone()));
+        }
+    });
+
+    // Allocate names only after the plugin registry is unlocked again.
+    declared
+        .into_iter()
+        .map(|(class_name, class_enum)| {
+            let qualified = format!("{}.{}", class_name.to_cow_str(), class_enum.enum_name);
+            let info = ClassEnumInfo {
+                qualified_name: ClassName::new_dynamic(qualified),
+                is_bitfield: class_enum.is_bitfield,
+            };
+
+            (class_enum.enum_typeid, info)
+        })
+        .collect()
+}
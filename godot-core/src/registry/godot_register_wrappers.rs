@@ -52,7 +52,8 @@ pub fn register_var<C: GodotClass, T: Var>(
         property_name: StringName::from(property_name),
         hint_info,
         usage,
-    };
+    }
+    .with_class_enum_of::<T>();
 
     let class_name = C::class_name();
 
@@ -23,6 +23,14 @@ impl MethodParamOrReturnInfo {
     pub fn new(info: PropertyInfo, metadata: sys::GDExtensionClassMethodArgumentMetadata) -> Self {
         Self { info, metadata }
     }
+
+    /// Declares the parameter or return type as class-scoped enum, if `T` is one.
+    pub(crate) fn with_class_enum_of<T: GodotConvert>(self) -> Self {
+        Self {
+            info: self.info.with_class_enum_of::<T>(),
+            ..self
+        }
+    }
 }
 
 /// All info needed to register a method for a class with Godot.
@@ -61,7 +69,7 @@ impl ClassMethodInfo {
         param_names: &[&str],
         // default_arguments: Vec<Variant>, - not yet implemented
     ) -> Self {
-        let return_value = Ret::Via::return_info();
+        let return_value = Ret::Via::return_info().map(|info| info.with_class_enum_of::<Ret>());
         let arguments = Signature::<Params, Ret>::param_names(param_names);
 
         let default_arguments = vec![]; // not yet implemented.
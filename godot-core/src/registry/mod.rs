@@ -32,3 +32,13 @@ pub use rpc_config::RpcConfig;
 
 #[doc(hidden)]
 pub mod godot_register_wrappers;
+#[doc(hidden)]
+pub mod resource_format;
+#[doc(hidden)]
+#[cfg(since_api = "4.2")]
+pub mod script_language;
+
+// Re-exported in `godot` crate; `#[class(script_language)]` requires Godot 4.2.
+#[doc(hidden)]
+#[cfg(before_api = "4.2")]
+pub mod script_language {}
@@ -12,9 +12,11 @@ use std::{any, fmt};
 use crate::docs::*;
 use crate::init::InitLevel;
 use crate::meta::ClassName;
-use crate::obj::{bounds, cap, Bounds, DynGd, Gd, GodotClass, Inherits, UserClass};
-use crate::registry::callbacks;
+use crate::obj::{bounds, cap, Bounds, DynGd, Gd, GodotClass, Inherits, NewGd, UserClass};
 use crate::registry::class::GodotGetVirtual;
+#[cfg(since_api = "4.2")]
+use crate::registry::script_language;
+use crate::registry::{callbacks, constant, resource_format};
 use crate::{classes, sys};
 
 // TODO(bromeon): some information coming from the proc-macro API is deferred through PluginItem, while others is directly
@@ -130,6 +132,9 @@ pub enum PluginItem {
 
     /// Collected from `#[godot_dyn]` macro invocations.
     DynTraitImpl(DynTraitImpl),
+
+    /// Collected from `#[export_enum]` declarations inside `#[godot_api] impl MyClass`.
+    ClassEnum(ClassEnum),
 }
 
 /// Helper function which checks that the field has not been set before.
@@ -198,6 +203,25 @@ pub struct Struct {
     /// Whether the class has a default constructor.
     pub(crate) is_instantiable: bool,
 
+    /// Creates the engine singleton instance, if `#[class(singleton)]` was used.
+    ///
+    /// Always implemented as [`callbacks::create_singleton`].
+    pub(crate) create_singleton_fn: Option<fn() -> Gd<classes::Object>>,
+
+    /// Creates loader and saver, if `#[class(resource_format)]` was used.
+    ///
+    /// Always implemented as [`resource_format::create_resource_format`].
+    pub(crate) create_resource_format_fn: Option<resource_format::CreateResourceFormatFn>,
+
+    /// Creates the language object, if `#[class(script_language)]` was used.
+    ///
+    /// Always implemented as [`script_language::create_script_language`].
+    #[cfg(since_api = "4.2")]
+    pub(crate) create_script_language_fn: Option<script_language::CreateScriptLanguageFn>,
+
+    /// Editor icon path, if `#[class(icon = "res://...")]` was used. Only listed by `init::class_icons()`, for the `.gdextension` file.
+    pub(crate) icon: Option<&'static str>,
+
     /// Documentation extracted from the struct's RustDoc.
     #[cfg(all(since_api = "4.3", feature = "register-docs"))]
     pub(crate) docs: StructDocs,
@@ -222,6 +246,11 @@ impl Struct {
             is_editor_plugin: false,
             is_internal: false,
             is_instantiable: false,
+            create_singleton_fn: None,
+            create_resource_format_fn: None,
+            #[cfg(since_api = "4.2")]
+            create_script_language_fn: None,
+            icon: None,
             #[cfg(all(since_api = "4.3", feature = "register-docs"))]
             docs,
             // While Godot doesn't do anything with these callbacks for non-RefCounted classes, we can avoid instantiating them in Rust.
@@ -278,6 +307,52 @@ impl Struct {
         self.is_instantiable = true;
         self
     }
+
+    pub fn with_singleton<T>(mut self) -> Self
+    where
+        T: GodotClass
+            + cap::GodotDefault
+            + Inherits<classes::Object>
+            + Bounds<Memory = bounds::MemManual>,
+    {
+        set(
+            &mut self.create_singleton_fn,
+            callbacks::create_singleton::<T>,
+        );
+        self
+    }
+
+    /// Registers the loader `L` and saver `S`, which are generated for `#[class(resource_format)]`.
+    pub fn with_resource_format<L, S>(mut self) -> Self
+    where
+        L: Inherits<classes::ResourceFormatLoader> + NewGd,
+        S: Inherits<classes::ResourceFormatSaver> + NewGd,
+    {
+        set(
+            &mut self.create_resource_format_fn,
+            resource_format::create_resource_format::<L, S>,
+        );
+        self
+    }
+
+    /// Registers the language `Lang`, which is generated for `#[class(script_language)]` on `L`.
+    #[cfg(since_api = "4.2")]
+    pub fn with_script_language<L, Lang>(mut self) -> Self
+    where
+        L: crate::obj::script::RustScriptLanguage,
+        Lang: Inherits<classes::ScriptLanguage> + crate::obj::NewAlloc,
+    {
+        set(
+            &mut self.create_script_language_fn,
+            script_language::create_script_language::<L, Lang>,
+        );
+        self
+    }
+
+    pub fn with_icon(mut self, icon: &'static str) -> Self {
+        self.icon = Some(icon);
+        self
+    }
 }
 
 /// Stores registration functions for methods, constants, and documentation from inherent `#[godot_api]` impl blocks.
@@ -620,3 +695,28 @@ impl DynTraitImpl {
         Ok(dyn_gd_t)
     }
 }
+
+/// Representation of an `#[export_enum]` declaration, which attaches a Rust enum or bitfield to a class.
+///
+/// The constants themselves are registered together with the other constants of the `#[godot_api]` block; this item only links the Rust
+/// type to its class, see [`class_enum_info()`][crate::registry::constant::class_enum_info].
+#[derive(Clone, Debug)]
+pub struct ClassEnum {
+    /// Name of the enum inside the class, e.g. `State` for `Player.State`.
+    pub(crate) enum_name: &'static str,
+
+    /// TypeId of the Rust enum or bitfield.
+    pub(crate) enum_typeid: any::TypeId,
+
+    pub(crate) is_bitfield: bool,
+}
+
+impl ClassEnum {
+    pub fn new<E: constant::ExportEnum>(enum_name: &'static str) -> Self {
+        Self {
+            enum_name,
+            enum_typeid: any::TypeId::of::<E>(),
+            is_bitfield: E::IS_BITFIELD,
+        }
+    }
+}
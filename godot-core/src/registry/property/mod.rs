@@ -504,6 +504,26 @@ pub mod export_info_functions {
         }
     }
 
+    /// Inspector button for a `Callable` property, see `#[export_tool_button]`.
+    ///
+    /// Formats: `"Bake"`, `"Bake,Bake"` (text and editor icon name).
+    #[cfg(since_api = "4.4")]
+    pub fn export_tool_button<S: AsRef<str>, I: AsRef<str>>(text: S, icon: I) -> PropertyHintInfo {
+        let text = text.as_ref();
+        let icon = icon.as_ref();
+
+        let hint_string = if icon.is_empty() {
+            GString::from(text)
+        } else {
+            GString::from(format!("{text},{icon}"))
+        };
+
+        PropertyHintInfo {
+            hint: PropertyHint::TOOL_BUTTON,
+            hint_string,
+        }
+    }
+
     macro_rules! default_export_funcs {
         (
             $( $function_name:ident => $property_hint:ident, )*
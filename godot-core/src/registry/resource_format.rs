@@ -0,0 +1,210 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Internal machinery behind `#[class(resource_format)]`, used by the loader and saver classes that the proc-macro generates.
+
+use std::io::{Read, Write};
+
+use crate::builtin::{GString, PackedStringArray, StringName, Variant};
+use crate::classes::file_access::ModeFlags;
+use crate::classes::{
+    ClassDb, Resource, ResourceFormatLoader, ResourceFormatSaver, ResourceLoader, ResourceSaver,
+};
+use crate::global::Error as GodotError;
+use crate::meta::ToGodot;
+use crate::obj::{Gd, GodotClass, Inherits, InstanceId, NewGd};
+use crate::private::{ClassPlugin, PluginItem};
+use crate::registry::class::lock_or_panic;
+use crate::sys::{Global, GlobalGuard};
+use crate::tools::{GFile, ResourceFormat};
+
+/// Loader and saver of one `#[class(resource_format)]`, while added to `ResourceLoader`/`ResourceSaver`.
+///
+/// Only instance IDs are stored, as `Gd` pointers cannot be sent across threads. The engine keeps the objects alive.
+struct LoadedResourceFormat {
+    loader_id: InstanceId,
+    saver_id: InstanceId,
+}
+
+fn global_resource_formats() -> GlobalGuard<'static, Vec<LoadedResourceFormat>> {
+    static RESOURCE_FORMATS: Global<Vec<LoadedResourceFormat>> = Global::default();
+
+    lock_or_panic(&RESOURCE_FORMATS, "resource formats")
+}
+
+/// Creates loader and saver of a `#[class(resource_format)]`; stored in the plugin of the annotated class.
+pub type CreateResourceFormatFn = fn() -> (Gd<ResourceFormatLoader>, Gd<ResourceFormatSaver>);
+
+/// Instantiates the loader and saver classes generated for a `#[class(resource_format)]`.
+pub fn create_resource_format<L, S>() -> (Gd<ResourceFormatLoader>, Gd<ResourceFormatSaver>)
+where
+    L: Inherits<ResourceFormatLoader> + NewGd,
+    S: Inherits<ResourceFormatSaver> + NewGd,
+{
+    (L::new_gd().upcast(), S::new_gd().upcast())
+}
+
+/// Adds all `#[class(resource_format)]` loaders and savers to the engine.
+///
+/// Called once the `Scene` level is loaded, as `ResourceLoader` and `ResourceSaver` are not guaranteed to be available earlier.
+pub(crate) fn add_resource_formats() {
+    let mut create_fns = Vec::new();
+    crate::private::iterate_plugins(|elem: &ClassPlugin| {
+        if let PluginItem::Struct(s) = &elem.item {
+            if let Some(create_fn) = s.create_resource_format_fn {
+                create_fns.push(create_fn);
+            }
+        }
+    });
+
+    if create_fns.is_empty() {
+        return;
+    }
+
+    let mut resource_loader = ResourceLoader::singleton();
+    let mut resource_saver = ResourceSaver::singleton();
+    let mut loaded = Vec::with_capacity(create_fns.len());
+
+    for create_fn in create_fns {
+        let (loader, saver) = create_fn();
+        resource_loader.add_resource_format_loader(&loader);
+        resource_saver.add_resource_format_saver(&saver);
+
+        loaded.push(LoadedResourceFormat {
+            loader_id: loader.instance_id(),
+            saver_id: saver.instance_id(),
+        });
+    }
+
+    global_resource_formats().extend(loaded);
+}
+
+/// Removes all loaders and savers added by [`add_resource_formats()`]. Called when the `Scene` level is unloaded.
+pub(crate) fn remove_resource_formats() {
+    let loaded = std::mem::take(&mut *global_resource_formats());
+    if loaded.is_empty() {
+        return;
+    }
+
+    let mut resource_loader = ResourceLoader::singleton();
+    let mut resource_saver = ResourceSaver::singleton();
+
+    for format in loaded.into_iter().rev() {
+        if let Ok(loader) = Gd::<ResourceFormatLoader>::try_from_instance_id(format.loader_id) {
+            resource_loader.remove_resource_format_loader(&loader);
+        }
+        if let Ok(saver) = Gd::<ResourceFormatSaver>::try_from_instance_id(format.saver_id) {
+            resource_saver.remove_resource_format_saver(&saver);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementations of IResourceFormatLoader and IResourceFormatSaver methods.
+
+pub fn recognized_extensions(extensions: &[&str]) -> PackedStringArray {
+    extensions.iter().map(|&ext| GString::from(ext)).collect()
+}
+
+/// Whether `type_` is `R` or one of its base classes.
+pub fn handles_type<R: GodotClass>(type_: &StringName) -> bool {
+    ClassDb::singleton().is_parent_class(&R::class_name().to_string_name(), type_)
+}
+
+/// Returns the class name of `R` if `path` has one of the given extensions, otherwise an empty string.
+pub fn resource_type<R: GodotClass>(path: &GString, extensions: &[&str]) -> GString {
+    if has_extension(path, extensions) {
+        R::class_name().to_gstring()
+    } else {
+        GString::new()
+    }
+}
+
+/// Reads the file at `path` and converts it with [`ResourceFormat::load()`].
+///
+/// Returns the resource on success, or an error code as `Variant`; both are understood by Godot.
+pub fn load<F, R>(path: &GString) -> Variant
+where
+    F: ResourceFormat<R>,
+    R: Inherits<Resource>,
+{
+    let result = read_file(path).and_then(|bytes| F::load(&bytes));
+
+    match result {
+        Ok(resource) => resource.to_variant(),
+        Err(error) => error.to_variant(),
+    }
+}
+
+/// Whether `resource` can be saved by the format for `R`.
+pub fn recognize<R: Inherits<Resource>>(resource: &Option<Gd<Resource>>) -> bool {
+    resource
+        .as_ref()
+        .is_some_and(|resource| resource.clone().try_cast::<R>().is_ok())
+}
+
+/// Returns `extensions` if `resource` can be saved by the format for `R`, otherwise an empty array.
+pub fn recognized_extensions_for<R: Inherits<Resource>>(
+    resource: &Option<Gd<Resource>>,
+    extensions: &[&str],
+) -> PackedStringArray {
+    if recognize::<R>(resource) {
+        recognized_extensions(extensions)
+    } else {
+        PackedStringArray::new()
+    }
+}
+
+/// Converts `resource` with [`ResourceFormat::save()`] and writes the result to `path`.
+pub fn save<F, R>(resource: Option<Gd<Resource>>, path: &GString) -> GodotError
+where
+    F: ResourceFormat<R>,
+    R: Inherits<Resource>,
+{
+    let Some(resource) = resource else {
+        return GodotError::ERR_INVALID_PARAMETER;
+    };
+
+    let Ok(resource) = resource.try_cast::<R>() else {
+        return GodotError::ERR_INVALID_PARAMETER;
+    };
+
+    match F::save(&resource).and_then(|bytes| write_file(path, &bytes)) {
+        Ok(()) => GodotError::OK,
+        Err(error) => error,
+    }
+}
+
+fn has_extension(path: &GString, extensions: &[&str]) -> bool {
+    let path = path.to_string();
+    let Some((_, ext)) = path.rsplit_once('.') else {
+        return false;
+    };
+
+    extensions
+        .iter()
+        .any(|candidate| candidate.eq_ignore_ascii_case(ext))
+}
+
+fn read_file(path: &GString) -> Result<Vec<u8>, GodotError> {
+    let mut file =
+        GFile::open(path, ModeFlags::READ).map_err(|_| GodotError::ERR_FILE_CANT_OPEN)?;
+
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)
+        .map_err(|_| GodotError::ERR_FILE_CANT_READ)?;
+
+    Ok(bytes)
+}
+
+fn write_file(path: &GString, bytes: &[u8]) -> Result<(), GodotError> {
+    let mut file =
+        GFile::open(path, ModeFlags::WRITE).map_err(|_| GodotError::ERR_FILE_CANT_OPEN)?;
+
+    file.write_all(bytes)
+        .map_err(|_| GodotError::ERR_FILE_CANT_WRITE)
+}
@@ -0,0 +1,566 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Internal machinery behind `#[class(script_language)]`, used by the language and script classes that the proc-macro generates.
+
+use std::ffi::c_void;
+use std::rc::Rc;
+
+use crate::builtin::{
+    Array, Dictionary, GString, PackedStringArray, StringName, Variant, VariantType,
+};
+use crate::classes::{Engine, Object, Script, ScriptLanguage};
+use crate::global::Error as GodotError;
+use crate::meta::{ClassName, MethodInfo, PropertyInfo};
+use crate::obj::script::{
+    create_script_instance, script_instance_exists, PlaceholderInstances, RustScriptLanguage,
+    ScriptError, ScriptInstance, SiMut,
+};
+use crate::obj::{bounds, Bounds, Gd, GodotClass, Inherits, InstanceId, NewAlloc, NewGd};
+use crate::private::{ClassPlugin, PluginItem};
+use crate::sys::{self, Global, GlobalGuard};
+
+/// Language of one `#[class(script_language)]`, while registered with the engine.
+struct LoadedScriptLanguage {
+    /// Name of the class implementing `RustScriptLanguage`.
+    class_name: ClassName,
+    language_id: InstanceId,
+}
+
+fn global_script_languages() -> GlobalGuard<'static, Vec<LoadedScriptLanguage>> {
+    static SCRIPT_LANGUAGES: Global<Vec<LoadedScriptLanguage>> = Global::default();
+
+    SCRIPT_LANGUAGES.lock()
+}
+
+/// Creates the language object of a `#[class(script_language)]`; stored in the plugin of the annotated class.
+pub type CreateScriptLanguageFn = fn() -> (ClassName, Gd<ScriptLanguage>);
+
+/// Instantiates the language class generated for the `RustScriptLanguage` implementation `L`.
+pub fn create_script_language<L, Lang>() -> (ClassName, Gd<ScriptLanguage>)
+where
+    L: RustScriptLanguage,
+    Lang: Inherits<ScriptLanguage> + NewAlloc,
+{
+    (L::class_name(), Lang::new_alloc().upcast())
+}
+
+/// Registers all `#[class(script_language)]` languages with the engine.
+///
+/// Called once the `Scene` level is loaded, before resource formats are added (which may already load scripts).
+pub(crate) fn add_script_languages() {
+    let mut create_fns = Vec::new();
+    crate::private::iterate_plugins(|elem: &ClassPlugin| {
+        if let PluginItem::Struct(s) = &elem.item {
+            if let Some(create_fn) = s.create_script_language_fn {
+                create_fns.push(create_fn);
+            }
+        }
+    });
+
+    if create_fns.is_empty() {
+        return;
+    }
+
+    let mut engine = Engine::singleton();
+    let mut loaded = Vec::with_capacity(create_fns.len());
+
+    for create_fn in create_fns {
+        let (class_name, language) = create_fn();
+
+        let error = engine.register_script_language(&language);
+        if error != GodotError::OK {
+            crate::godot_error!(
+                "Failed to register script language of class `{class_name}`: {error:?}"
+            );
+            language.free();
+            continue;
+        }
+
+        loaded.push(LoadedScriptLanguage {
+            class_name,
+            language_id: language.instance_id(),
+        });
+    }
+
+    global_script_languages().extend(loaded);
+}
+
+/// Unregisters and frees all languages added by [`add_script_languages()`]. Called when the `Scene` level is unloaded.
+pub(crate) fn remove_script_languages() {
+    let loaded = std::mem::take(&mut *global_script_languages());
+    if loaded.is_empty() {
+        return;
+    }
+
+    let mut engine = Engine::singleton();
+    for language in loaded.into_iter().rev() {
+        if let Ok(language) = Gd::<ScriptLanguage>::try_from_instance_id(language.language_id) {
+            engine.unregister_script_language(&language);
+            language.free();
+        }
+    }
+}
+
+/// Returns the registered language object for `L`.
+///
+/// Returns `None` if the language has not been registered (yet), e.g. because [`Engine::register_script_language()`] failed, or if it
+/// has already been unregistered.
+pub fn language<L: RustScriptLanguage>() -> Option<Gd<ScriptLanguage>> {
+    let class_name = L::class_name();
+    let language_id = global_script_languages()
+        .iter()
+        .find(|language| language.class_name == class_name)
+        .map(|language| language.language_id)?;
+
+    Gd::try_from_instance_id(language_id).ok()
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Script class
+
+/// Gives access to the [`ScriptData`] field of a generated script class.
+pub trait HasScriptData<L: RustScriptLanguage>:
+    Inherits<Script> + NewGd + Bounds<Declarer = bounds::DeclUser>
+{
+    fn script_data(&self) -> &ScriptData<L>;
+    fn script_data_mut(&mut self) -> &mut ScriptData<L>;
+}
+
+/// State of a script resource: source code and compiled program.
+pub struct ScriptData<L: RustScriptLanguage> {
+    source: GString,
+    program: Option<Rc<L::Program>>,
+    placeholders: PlaceholderInstances,
+}
+
+impl<L: RustScriptLanguage> Default for ScriptData<L> {
+    fn default() -> Self {
+        Self {
+            source: GString::new(),
+            program: None,
+            placeholders: PlaceholderInstances::new(),
+        }
+    }
+}
+
+impl<L: RustScriptLanguage> ScriptData<L> {
+    pub fn source_code(&self) -> GString {
+        self.source.clone()
+    }
+
+    /// Replaces the source code. Takes effect on the next [`reload()`][Self::reload].
+    pub fn set_source_code(&mut self, source: GString) {
+        self.source = source;
+    }
+
+    /// Compiles the source code. On failure, the previous program is discarded.
+    ///
+    /// Placeholders are only updated on success. After a failed compilation, they keep the properties of the last valid program.
+    pub fn reload(&mut self) -> GodotError {
+        let error = match L::compile(&self.source.to_string()) {
+            Ok(program) => {
+                self.program = Some(Rc::new(program));
+                GodotError::OK
+            }
+            Err(error) => {
+                self.program = None;
+                crate::godot_error!("{} script error: {error}", L::NAME);
+                GodotError::ERR_PARSE_ERROR
+            }
+        };
+
+        if let Some(program) = self.program.as_deref() {
+            self.placeholders
+                .update(L::property_list(program), default_values::<L>(program));
+        }
+
+        error
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.program.is_some()
+    }
+
+    pub fn is_tool(&self) -> bool {
+        self.program.as_deref().is_some_and(L::is_tool)
+    }
+
+    /// Like GDScript, scripts only run in the editor if they are tool scripts.
+    pub fn can_instantiate(&self) -> bool {
+        self.is_valid() && (self.is_tool() || !Engine::singleton().is_editor_hint())
+    }
+
+    pub fn global_name(&self) -> StringName {
+        self.program
+            .as_deref()
+            .map(L::global_name)
+            .unwrap_or_default()
+    }
+
+    pub fn instance_base_type(&self) -> StringName {
+        match self.program.as_deref() {
+            Some(program) => L::instance_base_type(program),
+            None => StringName::default(),
+        }
+    }
+
+    pub fn has_method(&self, method: &StringName) -> bool {
+        self.method_list()
+            .iter()
+            .any(|info| info.method_name == *method)
+    }
+
+    pub fn method_info(&self, method: &StringName) -> Dictionary {
+        self.method_list()
+            .iter()
+            .find(|info| info.method_name == *method)
+            .map(MethodInfo::to_dictionary)
+            .unwrap_or_default()
+    }
+
+    pub fn script_method_list(&self) -> Array<Dictionary> {
+        self.method_list()
+            .iter()
+            .map(MethodInfo::to_dictionary)
+            .collect()
+    }
+
+    pub fn script_property_list(&self) -> Array<Dictionary> {
+        self.property_list()
+            .iter()
+            .map(PropertyInfo::to_dictionary)
+            .collect()
+    }
+
+    pub fn members(&self) -> Array<StringName> {
+        self.property_list()
+            .into_iter()
+            .map(|info| info.property_name)
+            .collect()
+    }
+
+    /// Creates a script instance for `for_object`, or returns null if the script did not compile.
+    ///
+    /// # Safety
+    /// See [`create_script_instance()`].
+    pub unsafe fn instance_create(
+        &self,
+        script: Gd<Script>,
+        for_object: Gd<Object>,
+    ) -> *mut c_void {
+        let Some(program) = self.program.clone() else {
+            return std::ptr::null_mut();
+        };
+        let Some(language) = language::<L>() else {
+            crate::godot_error!(
+                "Cannot create script instance: script language `{}` is not registered",
+                L::class_name()
+            );
+            return std::ptr::null_mut();
+        };
+
+        let instance = RustScriptInstance::<L> {
+            state: L::create_state(&program),
+            program,
+            owner_id: for_object.instance_id(),
+            script,
+            language,
+        };
+
+        // SAFETY: forwarded to caller.
+        unsafe { create_script_instance(instance, for_object) }
+    }
+
+    /// Creates a placeholder instance, which Godot uses if the script cannot run (see [`can_instantiate()`][Self::can_instantiate]).
+    ///
+    /// # Safety
+    /// [`placeholder_erased()`][Self::placeholder_erased] must be called once Godot frees the placeholder.
+    pub unsafe fn placeholder_instance_create(
+        &self,
+        script: Gd<Script>,
+        for_object: Gd<Object>,
+    ) -> *mut c_void {
+        let Some(language) = language::<L>() else {
+            crate::godot_error!(
+                "Cannot create placeholder instance: script language `{}` is not registered",
+                L::class_name()
+            );
+            return std::ptr::null_mut();
+        };
+
+        // SAFETY: forwarded to caller; the language of generated scripts is always `L`.
+        unsafe { self.placeholders.create(&language, &script, &for_object) }
+    }
+
+    /// Implements `IScriptExtension::placeholder_erased_rawptr()`.
+    pub fn placeholder_erased(&self, placeholder: *mut c_void) {
+        self.placeholders.erase(placeholder);
+    }
+
+    fn method_list(&self) -> Vec<MethodInfo> {
+        self.program
+            .as_deref()
+            .map(L::method_list)
+            .unwrap_or_default()
+    }
+
+    fn property_list(&self) -> Vec<PropertyInfo> {
+        self.program
+            .as_deref()
+            .map(L::property_list)
+            .unwrap_or_default()
+    }
+}
+
+/// Values of the script's properties in a freshly created state.
+fn default_values<L: RustScriptLanguage>(program: &L::Program) -> Vec<(StringName, Variant)> {
+    let state = L::create_state(program);
+
+    L::property_list(program)
+        .into_iter()
+        .filter_map(|info| {
+            let value = L::get_property(program, &state, &info.property_name)?;
+            Some((info.property_name, value))
+        })
+        .collect()
+}
+
+/// Implements `IScriptExtension::instance_has()`.
+pub fn instance_has<S>(script: Gd<S>, object: &Gd<Object>) -> bool
+where
+    S: Inherits<Script> + crate::classes::IScriptExtension + Bounds<Declarer = bounds::DeclUser>,
+{
+    script_instance_exists(object, &script)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Resource format
+
+/// Creates a script from file contents; used by the generated `ResourceFormat` implementation.
+pub fn load_script<L, S>(bytes: &[u8]) -> Result<Gd<Script>, GodotError>
+where
+    L: RustScriptLanguage,
+    S: HasScriptData<L>,
+{
+    let source = std::str::from_utf8(bytes).map_err(|_| GodotError::ERR_FILE_CORRUPT)?;
+
+    let mut script = S::new_gd();
+    let error = {
+        let mut guard = script.bind_mut();
+        let data = guard.script_data_mut();
+        data.set_source_code(GString::from(source));
+        data.reload()
+    };
+
+    // Like GDScript, the editor still loads broken scripts, so they can be fixed.
+    if error != GodotError::OK && !Engine::singleton().is_editor_hint() {
+        return Err(error);
+    }
+
+    Ok(script.upcast())
+}
+
+/// Returns the source code of `script` as file contents.
+pub fn save_script<L, S>(script: &Gd<Script>) -> Result<Vec<u8>, GodotError>
+where
+    L: RustScriptLanguage,
+    S: HasScriptData<L>,
+{
+    let script = script
+        .clone()
+        .try_cast::<S>()
+        .map_err(|_| GodotError::ERR_INVALID_PARAMETER)?;
+
+    let source = script.bind().script_data().source_code();
+    Ok(source.to_string().into_bytes())
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Language class
+
+pub fn string_list(items: &[&str]) -> PackedStringArray {
+    items.iter().map(|&item| GString::from(item)).collect()
+}
+
+pub fn recognized_extensions<L: RustScriptLanguage>() -> PackedStringArray {
+    string_list(&[L::EXTENSION])
+}
+
+/// Implements `IScriptLanguageExtension::make_template()`.
+pub fn make_template<L, S>(class_name: &GString, base_class_name: &GString) -> Option<Gd<Script>>
+where
+    L: RustScriptLanguage,
+    S: HasScriptData<L>,
+{
+    let source = L::template(&class_name.to_string(), &base_class_name.to_string());
+
+    let mut script = S::new_gd();
+    script
+        .bind_mut()
+        .script_data_mut()
+        .set_source_code(GString::from(source));
+
+    Some(script.upcast())
+}
+
+/// Implements `IScriptLanguageExtension::create_script()`.
+pub fn create_script<L, S>() -> Option<Gd<Object>>
+where
+    L: RustScriptLanguage,
+    S: HasScriptData<L>,
+{
+    Some(S::new_gd().upcast::<Script>().upcast())
+}
+
+/// Implements `IScriptLanguageExtension::validate()`.
+pub fn validate<L: RustScriptLanguage>(source: &GString, path: &GString) -> Dictionary {
+    let errors = L::validate(&source.to_string());
+
+    let error_dicts: Array<Dictionary> = errors
+        .iter()
+        .map(|error: &ScriptError| {
+            let mut dict = Dictionary::new();
+            dict.set("path", path.clone());
+            dict.set("line", error.line() as i64);
+            dict.set("column", error.column() as i64);
+            dict.set("message", error.message());
+            dict
+        })
+        .collect();
+
+    let mut result = Dictionary::new();
+    result.set("valid", errors.is_empty());
+    result.set("errors", error_dicts);
+    result
+}
+
+/// Result of code completion and lookup, which are not supported.
+pub fn code_unavailable() -> Dictionary {
+    let mut result = Dictionary::new();
+    result.set("result", GodotError::ERR_UNAVAILABLE);
+    result.set("force", false);
+    result.set("call_hint", GString::new());
+    result.set("type", 0);
+    result
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Script instance
+
+/// Script instance, delegating to the `RustScriptLanguage` implementation.
+struct RustScriptInstance<L: RustScriptLanguage> {
+    program: Rc<L::Program>,
+    state: L::State,
+
+    // Not Gd, which would keep ref-counted owners alive.
+    owner_id: InstanceId,
+    script: Gd<Script>,
+    language: Gd<ScriptLanguage>,
+}
+
+impl<L: RustScriptLanguage> ScriptInstance for RustScriptInstance<L> {
+    type Base = Object;
+
+    fn class_name(&self) -> GString {
+        let global_name = L::global_name(&self.program);
+        if global_name.is_empty() {
+            GString::from(L::NAME)
+        } else {
+            GString::from(&global_name)
+        }
+    }
+
+    fn set_property(mut this: SiMut<Self>, name: StringName, value: &Variant) -> bool {
+        let Self { program, state, .. } = &mut *this;
+        L::set_property(program.as_ref(), state, &name, value)
+    }
+
+    fn get_property(&self, name: StringName) -> Option<Variant> {
+        L::get_property(&self.program, &self.state, &name)
+    }
+
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        L::property_list(&self.program)
+    }
+
+    fn get_method_list(&self) -> Vec<MethodInfo> {
+        L::method_list(&self.program)
+    }
+
+    fn call(
+        mut this: SiMut<Self>,
+        method: StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType> {
+        let owner = Gd::from_instance_id(this.owner_id);
+        let Self { program, state, .. } = &mut *this;
+
+        L::call(program.as_ref(), state, &owner, &method, args)
+    }
+
+    fn is_placeholder(&self) -> bool {
+        false
+    }
+
+    fn has_method(&self, method: StringName) -> bool {
+        L::method_list(&self.program)
+            .iter()
+            .any(|info| info.method_name == method)
+    }
+
+    fn get_script(&self) -> &Gd<Script> {
+        &self.script
+    }
+
+    fn get_property_type(&self, name: StringName) -> VariantType {
+        L::property_list(&self.program)
+            .iter()
+            .find(|info| info.property_name == name)
+            .map_or(VariantType::NIL, |info| info.variant_type)
+    }
+
+    fn to_string(&self) -> GString {
+        GString::new()
+    }
+
+    fn get_property_state(&self) -> Vec<(StringName, Variant)> {
+        L::property_list(&self.program)
+            .into_iter()
+            .filter_map(|info| {
+                let value = L::get_property(&self.program, &self.state, &info.property_name)?;
+                Some((info.property_name, value))
+            })
+            .collect()
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        self.language.clone()
+    }
+
+    fn on_refcount_decremented(&self) -> bool {
+        true
+    }
+
+    fn on_refcount_incremented(&self) {}
+
+    fn property_get_fallback(&self, _name: StringName) -> Option<Variant> {
+        None
+    }
+
+    fn property_set_fallback(_this: SiMut<Self>, _name: StringName, _value: &Variant) -> bool {
+        false
+    }
+
+    #[cfg(since_api = "4.3")]
+    fn get_method_argument_count(&self, method: StringName) -> Option<u32> {
+        L::method_list(&self.program)
+            .iter()
+            .find(|info| info.method_name == method)
+            .map(|info| info.arguments.len() as u32)
+    }
+}
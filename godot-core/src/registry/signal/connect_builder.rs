@@ -5,14 +5,18 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
 use super::{make_callable_name, make_godot_fn};
-#[cfg(feature = "experimental-threads")]
-use crate::builtin::Callable;
-use crate::builtin::{GString, Variant};
+use crate::builtin::{Callable, GString, Variant};
 use crate::classes::object::ConnectFlags;
+use crate::classes::{Engine, Object, SceneTree};
 use crate::meta;
 use crate::meta::InParamTuple;
-use crate::obj::{bounds, Bounds, Gd, GodotClass, WithSignals};
+use crate::obj::{bounds, Bounds, Gd, GodotClass, InstanceId, WithSignals};
 use crate::registry::signal::signal_receiver::{IndirectSignalReceiver, SignalReceiver};
 use crate::registry::signal::{ConnectHandle, ToSignalObj, TypedSignal};
 
@@ -31,6 +35,49 @@ use crate::registry::signal::{ConnectHandle, ToSignalObj, TypedSignal};
 /// - [`name()`][Self::name]: Name of the `Callable` (for debug purposes).  \
 ///   If not specified, the Rust function name is used. This is typically a good default, but not very readable for closures.
 /// - [`flags()`][Self::flags]: Provide one or multiple [`ConnectFlags`][crate::classes::object::ConnectFlags], possibly combined with bitwise OR.
+/// - [`owned_by()`][Self::owned_by]: Tie the connection's lifetime to another object, disconnecting when that object is freed.
+///
+/// # Combinators
+/// Emissions can be preprocessed before they reach the connected function, without keeping state in the receiving class.
+/// Combinators are applied in the order they are called, and can be chained arbitrarily:
+/// - [`filter()`][Self::filter]: Only forward emissions that satisfy a predicate.
+/// - [`map()`][Self::map]: Transform the parameter tuple; the connected function then receives the new tuple.
+/// - [`debounce()`][Self::debounce]: Forward only the last emission of a burst, once no emission happened for a given time.
+/// - [`throttle()`][Self::throttle]: Forward at most one emission per time interval.
+/// - [`take()`][Self::take], [`take_while()`][Self::take_while]: Stop forwarding after a number of emissions, or once a condition fails.
+///
+/// The whole chain is one connection, so the returned [`ConnectHandle`] disconnects it at once.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use std::time::Duration;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct SearchBox {
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl SearchBox {
+///     #[signal]
+///     fn text_changed(text: GString);
+///
+///     fn search(&mut self, query: String) { /* ... */ }
+/// }
+///
+/// fn setup(search_box: &mut SearchBox) {
+///     search_box
+///         .signals()
+///         .text_changed()
+///         .builder()
+///         .filter(|(text,)| text.len() >= 3)
+///         .map(|(text,)| (text.to_string(),))
+///         .debounce(Duration::from_millis(300))
+///         .connect_self_mut(|this, query| this.search(query));
+/// }
+/// ```
+///
+/// Combinators are not available for [`connect_sync()`](#method.connect_sync).
 ///
 /// # Finalizing
 /// After customizing your builder, you can register the connection with various `connect_*` functions.
@@ -67,9 +114,77 @@ use crate::registry::signal::{ConnectHandle, ToSignalObj, TypedSignal};
 // - Pack multiple types together into "type lists", i.e. custom structs carrying the type state. For a user, this would appear as one type,
 // - which could also be #[doc(hidden)]. However, this may make the trait resolution more complex and worsen error messages, so not done now.
 #[must_use]
-pub struct ConnectBuilder<'ts, 'c, C: WithSignals, Ps> {
+pub struct ConnectBuilder<'ts, 'c, C: WithSignals, Ps, Out = Ps, Comb = NoCombinators> {
     parent_sig: &'ts TypedSignal<'c, C, Ps>,
     data: BuilderData,
+
+    /// Combinators, turning a receiver of `Out` into a receiver of the signal parameters `Ps`. `None` without combinators.
+    pipeline: Option<Pipeline<Ps, Out>>,
+
+    /// Whether combinators have been added: [`NoCombinators`] or [`WithCombinators`].
+    _combinators: PhantomData<Comb>,
+}
+
+/// Type state of a [`ConnectBuilder`] without combinators.
+#[doc(hidden)]
+pub enum NoCombinators {}
+
+/// Type state of a [`ConnectBuilder`] with at least one combinator.
+#[doc(hidden)]
+pub enum WithCombinators {}
+
+type Pipeline<Ps, Out> = Box<dyn FnOnce(Box<dyn FnMut(Out)>) -> Box<dyn FnMut(Ps)>>;
+
+/// Builds the receiver of the signal parameters from the combinator pipeline, depending on the type state of a [`ConnectBuilder`].
+///
+/// Without combinators, there is no pipeline: the receiver is connected directly, without boxing or dynamic calls.
+#[doc(hidden)]
+pub trait CombinatorState<Ps: 'static, Out: 'static> {
+    /// Appends a combinator stage, which turns a receiver of `Out2` into a receiver of `Out`.
+    fn append<Out2: 'static>(
+        pipeline: Option<Pipeline<Ps, Out>>,
+        stage: impl FnOnce(Box<dyn FnMut(Out2)>) -> Box<dyn FnMut(Out)> + 'static,
+    ) -> Pipeline<Ps, Out2>;
+
+    /// Turns a receiver of `Out` into a receiver of the signal parameters `Ps`.
+    fn into_sink(
+        pipeline: Option<Pipeline<Ps, Out>>,
+        receiver: impl FnMut(Out) + 'static,
+    ) -> impl FnMut(Ps) + 'static;
+}
+
+impl<Ps: 'static> CombinatorState<Ps, Ps> for NoCombinators {
+    fn append<Out2: 'static>(
+        _pipeline: Option<Pipeline<Ps, Ps>>,
+        stage: impl FnOnce(Box<dyn FnMut(Out2)>) -> Box<dyn FnMut(Ps)> + 'static,
+    ) -> Pipeline<Ps, Out2> {
+        Box::new(stage)
+    }
+
+    fn into_sink(
+        _pipeline: Option<Pipeline<Ps, Ps>>,
+        receiver: impl FnMut(Ps) + 'static,
+    ) -> impl FnMut(Ps) + 'static {
+        receiver
+    }
+}
+
+impl<Ps: 'static, Out: 'static> CombinatorState<Ps, Out> for WithCombinators {
+    fn append<Out2: 'static>(
+        pipeline: Option<Pipeline<Ps, Out>>,
+        stage: impl FnOnce(Box<dyn FnMut(Out2)>) -> Box<dyn FnMut(Out)> + 'static,
+    ) -> Pipeline<Ps, Out2> {
+        let pipeline = pipeline.expect("builder with combinators has a pipeline");
+        Box::new(move |receiver| pipeline(stage(receiver)))
+    }
+
+    fn into_sink(
+        pipeline: Option<Pipeline<Ps, Out>>,
+        receiver: impl FnMut(Out) + 'static,
+    ) -> impl FnMut(Ps) + 'static {
+        let pipeline = pipeline.expect("builder with combinators has a pipeline");
+        pipeline(Box::new(receiver))
+    }
 }
 
 /// Gathers all the non-typestate data, so that the builder can easily transfer it without manually moving each field.
@@ -80,9 +195,14 @@ struct BuilderData {
 
     /// Godot connection flags.
     connect_flags: Option<ConnectFlags>,
+
+    /// Whether any combinator has been added to the pipeline. Mirrors the `Comb` type state, for use in generic code.
+    has_combinators: bool,
+
+    /// Object whose lifetime bounds the connection, if set by `owned_by()`.
+    owner: Option<InstanceId>,
 }
 
-#[allow(clippy::needless_lifetimes)] // 'ts + 'c are used conditionally.
 impl<'ts, 'c, C, Ps> ConnectBuilder<'ts, 'c, C, Ps>
 where
     C: WithSignals,
@@ -92,9 +212,18 @@ where
         ConnectBuilder {
             parent_sig,
             data: BuilderData::default(),
+            pipeline: None,
+            _combinators: PhantomData,
         }
     }
+}
 
+#[allow(clippy::needless_lifetimes)] // 'ts + 'c are used conditionally.
+impl<'ts, 'c, C, Ps, Out, Comb> ConnectBuilder<'ts, 'c, C, Ps, Out, Comb>
+where
+    C: WithSignals,
+    Ps: meta::ParamTuple,
+{
     /// Name of the `Callable`, mostly used for debugging.
     ///
     /// If not provided, the Rust type name of the function/method is used.
@@ -120,27 +249,307 @@ where
         self
     }
 
-    /// Directly connect a Rust callable `godot_fn`, with a name based on `F`.
+    /// Ties the connection's lifetime to the object with the given instance ID.
+    ///
+    /// When that object is freed, Godot removes the connection automatically. This is mostly useful for closures passed to
+    /// [`connect()`][Self::connect], which would otherwise stay connected as long as the signal object lives -- for example, a closure
+    /// updating a UI node, which should stop once that node is freed.
+    ///
+    /// For `connect_self_*` and `connect_other_*`, the connection is additionally bound to its receiver object: emissions after the
+    /// receiver has been freed are ignored.
+    ///
+    /// Not supported by [`connect_sync()`](#method.connect_sync), which panics if an owner is set.
+    ///
+    /// # Panics
+    /// When connecting, if no object with the given instance ID exists.
+    pub fn owned_by(mut self, owner: InstanceId) -> Self {
+        assert!(
+            self.data.owner.is_none(),
+            "owned_by() called twice on the same builder."
+        );
+
+        self.data.owner = Some(owner);
+        self
+    }
+
+    /// Connect `receiver` behind all combinators, with a name based on `F`.
     ///
     /// This exists as a shorthand for the connect methods and avoids the generic instantiation of the full-blown
     /// type state builder for simple + common connections, thus hopefully being a tiny bit lighter on compile times.
-    fn inner_connect_godot_fn<F>(
+    fn inner_connect_receiver<F>(
         self,
-        godot_fn: impl FnMut(&[&Variant]) -> Result<Variant, ()> + 'static,
+        receiver: impl FnMut(Out) + 'static,
         bound: &Gd<impl GodotClass>,
-    ) -> ConnectHandle {
-        let callable_name = match &self.data.callable_name {
+    ) -> ConnectHandle
+    where
+        Ps: InParamTuple + 'static,
+        Out: 'static,
+        Comb: CombinatorState<Ps, Out>,
+    {
+        let ConnectBuilder {
+            parent_sig,
+            data,
+            pipeline,
+            ..
+        } = self;
+
+        // Godot disconnects the callable once the object it is linked to is freed.
+        let linked_object: Gd<Object> = match data.owner {
+            Some(owner_id) => Gd::try_from_instance_id(owner_id).unwrap_or_else(|_| {
+                panic!("owned_by(): no object with instance ID {owner_id} exists")
+            }),
+            None => bound.clone().upcast_object(),
+        };
+
+        // Set when disconnected through the handle. Combinators like debounce() may call the receiver later, from a callback that is
+        // independent of the connection, so they must not forward emissions afterward.
+        let disconnected = Rc::new(Cell::new(false));
+
+        // Such a later call may also happen once the owner or bound object is freed, and an owner may outlive the bound object.
+        let check_alive = data.has_combinators || data.owner.is_some();
+        let bound_id = bound.instance_id();
+        let owner_id = data.owner;
+        let receiver = {
+            let disconnected = disconnected.clone();
+            let mut receiver = receiver;
+            move |args: Out| {
+                let is_alive = || {
+                    !disconnected.get()
+                        && bound_id.lookup_validity()
+                        && owner_id.is_none_or(|id| id.lookup_validity())
+                };
+
+                if !check_alive || is_alive() {
+                    receiver(args)
+                }
+            }
+        };
+
+        let mut sink = Comb::into_sink(pipeline, receiver);
+        let godot_fn = make_godot_fn(move |args: Ps| sink(args));
+
+        let callable_name = match &data.callable_name {
             Some(user_provided_name) => user_provided_name,
             None => &make_callable_name::<F>(),
         };
 
-        let callable = bound.linked_callable(callable_name, godot_fn);
-        self.parent_sig
-            .inner_connect_untyped(callable, self.data.connect_flags)
+        let callable = linked_object.linked_callable(callable_name, godot_fn);
+        parent_sig
+            .inner_connect_untyped(callable, data.connect_flags)
+            .with_disconnect_flag(disconnected)
+    }
+
+    /// Appends a combinator stage, which turns a receiver of `Out2` into a receiver of `Out`.
+    fn then<Out2>(
+        self,
+        stage: impl FnOnce(Box<dyn FnMut(Out2)>) -> Box<dyn FnMut(Out)> + 'static,
+    ) -> ConnectBuilder<'ts, 'c, C, Ps, Out2, WithCombinators>
+    where
+        Out: 'static,
+        Out2: 'static,
+        Ps: 'static,
+        Comb: CombinatorState<Ps, Out>,
+    {
+        let ConnectBuilder {
+            parent_sig,
+            mut data,
+            pipeline,
+            ..
+        } = self;
+
+        data.has_combinators = true;
+
+        ConnectBuilder {
+            parent_sig,
+            data,
+            pipeline: Some(Comb::append(pipeline, stage)),
+            _combinators: PhantomData,
+        }
     }
 }
 
-impl<C: WithSignals, Ps: InParamTuple + 'static> ConnectBuilder<'_, '_, C, Ps> {
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Combinators
+
+impl<'ts, 'c, C, Ps, Out, Comb> ConnectBuilder<'ts, 'c, C, Ps, Out, Comb>
+where
+    C: WithSignals,
+    Ps: meta::ParamTuple + 'static,
+    Out: 'static,
+    Comb: CombinatorState<Ps, Out>,
+{
+    /// Only forwards emissions for which `predicate` returns `true`.
+    ///
+    /// The predicate receives the parameter tuple, as produced by previous combinators.
+    pub fn filter(
+        self,
+        mut predicate: impl FnMut(&Out) -> bool + 'static,
+    ) -> ConnectBuilder<'ts, 'c, C, Ps, Out, WithCombinators> {
+        self.then(move |mut receiver| {
+            Box::new(move |args| {
+                if predicate(&args) {
+                    receiver(args)
+                }
+            })
+        })
+    }
+
+    /// Transforms the parameter tuple before forwarding it.
+    ///
+    /// The connected function then receives the elements of `Out2`, which must be a tuple (possibly with a single element, like `(i32,)`)
+    /// of types implementing [`FromGodot`][crate::meta::FromGodot].
+    pub fn map<Out2: 'static>(
+        self,
+        mut transform: impl FnMut(Out) -> Out2 + 'static,
+    ) -> ConnectBuilder<'ts, 'c, C, Ps, Out2, WithCombinators> {
+        self.then(move |mut receiver| Box::new(move |args| receiver(transform(args))))
+    }
+
+    /// Forwards an emission only after no further emission happened for `delay`. Only the last emission of a burst is forwarded.
+    ///
+    /// The delay is measured in real time (ignoring pause and time scale), using a `SceneTreeTimer`. A pending emission is discarded if the
+    /// connection is disconnected through its [`ConnectHandle`], or if the receiver or [owner][Self::owned_by] is freed in the meantime.
+    ///
+    /// # Panics
+    /// On emission, if the main loop is not a `SceneTree`.
+    pub fn debounce(self, delay: Duration) -> ConnectBuilder<'ts, 'c, C, Ps, Out, WithCombinators> {
+        self.then(move |receiver| {
+            let receiver = Rc::new(RefCell::new(receiver));
+
+            // Last emission, and a counter to recognize whether a timer is outdated.
+            let pending = Rc::new(RefCell::new((None::<Out>, 0u64)));
+
+            Box::new(move |args| {
+                let generation = {
+                    let mut pending = pending.borrow_mut();
+                    pending.0 = Some(args);
+                    pending.1 += 1;
+                    pending.1
+                };
+
+                let pending = pending.clone();
+                let receiver = receiver.clone();
+                call_after(delay, move || {
+                    let args = {
+                        let mut pending = pending.borrow_mut();
+                        if pending.1 != generation {
+                            return;
+                        }
+                        pending.0.take()
+                    };
+
+                    // Borrow of `pending` is released, so the receiver may emit the signal again.
+                    if let Some(args) = args {
+                        (receiver.borrow_mut())(args);
+                    }
+                });
+            })
+        })
+    }
+
+    /// Forwards at most one emission per `interval`. The first emission is forwarded immediately, following ones are discarded until
+    /// `interval` has passed.
+    ///
+    /// The interval is measured in real time.
+    pub fn throttle(
+        self,
+        interval: Duration,
+    ) -> ConnectBuilder<'ts, 'c, C, Ps, Out, WithCombinators> {
+        self.then(move |mut receiver| {
+            let mut last_forwarded: Option<Instant> = None;
+
+            Box::new(move |args| {
+                let now = Instant::now();
+                if last_forwarded.is_some_and(|last| now.duration_since(last) < interval) {
+                    return;
+                }
+
+                last_forwarded = Some(now);
+                receiver(args);
+            })
+        })
+    }
+
+    /// Forwards only the first `count` emissions.
+    ///
+    /// Afterward, the connected function is dropped, and further emissions are ignored. The connection itself stays in place until it is
+    /// disconnected through the [`ConnectHandle`], or the object is freed.
+    pub fn take(self, count: usize) -> ConnectBuilder<'ts, 'c, C, Ps, Out, WithCombinators> {
+        self.then(move |receiver| {
+            let mut remaining = count;
+            let mut receiver = (count > 0).then_some(receiver);
+
+            Box::new(move |args| {
+                let Some(rcv) = receiver.as_mut() else {
+                    return;
+                };
+
+                rcv(args);
+                remaining -= 1;
+                if remaining == 0 {
+                    receiver = None;
+                }
+            })
+        })
+    }
+
+    /// Forwards emissions as long as `predicate` returns `true`.
+    ///
+    /// On the first emission for which the predicate returns `false`, the connected function is dropped, and this and all further
+    /// emissions are ignored. See [`take()`][Self::take] regarding the connection.
+    pub fn take_while(
+        self,
+        mut predicate: impl FnMut(&Out) -> bool + 'static,
+    ) -> ConnectBuilder<'ts, 'c, C, Ps, Out, WithCombinators> {
+        self.then(move |receiver| {
+            let mut receiver = Some(receiver);
+
+            Box::new(move |args| {
+                let Some(rcv) = receiver.as_mut() else {
+                    return;
+                };
+
+                if predicate(&args) {
+                    rcv(args);
+                } else {
+                    receiver = None;
+                }
+            })
+        })
+    }
+}
+
+/// Calls `f` once after `delay` of real time.
+fn call_after(delay: Duration, f: impl FnOnce() + 'static) {
+    let tree = Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .expect("ConnectBuilder::debounce() requires the main loop to be a SceneTree");
+
+    let mut timer = tree
+        .create_timer_ex(delay.as_secs_f64())
+        .process_always(true)
+        .ignore_time_scale(true)
+        .done()
+        .expect("SceneTree::create_timer() returned null");
+
+    let callable = Callable::from_once_fn("ConnectBuilder::debounce", move |_args| {
+        f();
+        Ok(Variant::nil())
+    });
+    timer.connect("timeout", &callable);
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Finalizing
+
+impl<C, Ps, Out, Comb> ConnectBuilder<'_, '_, C, Ps, Out, Comb>
+where
+    C: WithSignals,
+    Ps: InParamTuple + 'static,
+    Out: InParamTuple + 'static,
+    Comb: CombinatorState<Ps, Out>,
+{
     /// Connect a non-member function (global function, associated function or closure).
     ///
     /// Example usages:
@@ -159,17 +568,17 @@ impl<C: WithSignals, Ps: InParamTuple + 'static> ConnectBuilder<'_, '_, C, Ps> {
     /// - If you need cross-thread signals, use [`connect_sync()`](#method.connect_sync) instead (requires feature "experimental-threads").
     pub fn connect<F>(self, mut function: F) -> ConnectHandle
     where
-        for<'c_rcv> F: SignalReceiver<(), Ps>,
-        for<'c_rcv> IndirectSignalReceiver<'c_rcv, (), Ps, F>: From<&'c_rcv mut F>,
+        for<'c_rcv> F: SignalReceiver<(), Out>,
+        for<'c_rcv> IndirectSignalReceiver<'c_rcv, (), Out, F>: From<&'c_rcv mut F>,
     {
-        let godot_fn = make_godot_fn(move |args| {
+        let receiver = move |args: Out| {
             IndirectSignalReceiver::from(&mut function)
                 .function()
                 .call((), args);
-        });
+        };
 
         let bound = self.parent_sig.receiver_object();
-        self.inner_connect_godot_fn::<F>(godot_fn, &bound)
+        self.inner_connect_receiver::<F>(receiver, &bound)
     }
 
     /// Connect a method with `&mut self` as the first parameter (user classes only).
@@ -183,20 +592,20 @@ impl<C: WithSignals, Ps: InParamTuple + 'static> ConnectBuilder<'_, '_, C, Ps> {
     pub fn connect_self_mut<F>(self, mut function: F) -> ConnectHandle
     where
         C: Bounds<Declarer = bounds::DeclUser>,
-        for<'c_rcv> F: SignalReceiver<&'c_rcv mut C, Ps>,
-        for<'c_rcv> IndirectSignalReceiver<'c_rcv, &'c_rcv mut C, Ps, F>: From<&'c_rcv mut F>,
+        for<'c_rcv> F: SignalReceiver<&'c_rcv mut C, Out>,
+        for<'c_rcv> IndirectSignalReceiver<'c_rcv, &'c_rcv mut C, Out, F>: From<&'c_rcv mut F>,
     {
         let mut gd = self.parent_sig.receiver_object();
 
-        let godot_fn = make_godot_fn(move |args| {
+        let receiver = move |args: Out| {
             let mut guard = Gd::bind_mut(&mut gd);
             IndirectSignalReceiver::from(&mut function)
                 .function()
                 .call(&mut *guard, args);
-        });
+        };
 
         let bound = self.parent_sig.receiver_object();
-        self.inner_connect_godot_fn::<F>(godot_fn, &bound)
+        self.inner_connect_receiver::<F>(receiver, &bound)
     }
 
     /// Connect a method with `&mut Gd<Self>` as the first parameter (user + engine classes).
@@ -209,19 +618,19 @@ impl<C: WithSignals, Ps: InParamTuple + 'static> ConnectBuilder<'_, '_, C, Ps> {
     /// - If you need cross-thread signals, use [`connect_sync()`](#method.connect_sync) instead (requires feature `experimental-threads`).
     pub fn connect_self_gd<F>(self, mut function: F) -> ConnectHandle
     where
-        F: SignalReceiver<Gd<C>, Ps>,
-        for<'c_rcv> IndirectSignalReceiver<'c_rcv, Gd<C>, Ps, F>: From<&'c_rcv mut F>,
+        F: SignalReceiver<Gd<C>, Out>,
+        for<'c_rcv> IndirectSignalReceiver<'c_rcv, Gd<C>, Out, F>: From<&'c_rcv mut F>,
     {
         let gd = self.parent_sig.receiver_object();
         let bound = gd.clone();
 
-        let godot_fn = make_godot_fn(move |args| {
+        let receiver = move |args: Out| {
             IndirectSignalReceiver::from(&mut function)
                 .function()
                 .call(gd.clone(), args);
-        });
+        };
 
-        self.inner_connect_godot_fn::<F>(godot_fn, &bound)
+        self.inner_connect_receiver::<F>(receiver, &bound)
     }
 
     /// Connect a method with any `&mut OtherC` as the first parameter (user classes only).
@@ -244,19 +653,19 @@ impl<C: WithSignals, Ps: InParamTuple + 'static> ConnectBuilder<'_, '_, C, Ps> {
     ) -> ConnectHandle
     where
         OtherC: GodotClass + Bounds<Declarer = bounds::DeclUser>,
-        for<'c_rcv> F: SignalReceiver<&'c_rcv mut OtherC, Ps>,
-        for<'c_rcv> IndirectSignalReceiver<'c_rcv, &'c_rcv mut OtherC, Ps, F>: From<&'c_rcv mut F>,
+        for<'c_rcv> F: SignalReceiver<&'c_rcv mut OtherC, Out>,
+        for<'c_rcv> IndirectSignalReceiver<'c_rcv, &'c_rcv mut OtherC, Out, F>: From<&'c_rcv mut F>,
     {
         let mut gd = object.to_signal_obj();
 
-        let godot_fn = make_godot_fn(move |args| {
+        let receiver = move |args: Out| {
             let mut guard = Gd::bind_mut(&mut gd);
             IndirectSignalReceiver::from(&mut method)
                 .function()
                 .call(&mut *guard, args);
-        });
+        };
 
-        self.inner_connect_godot_fn::<F>(godot_fn, &object.to_signal_obj())
+        self.inner_connect_receiver::<F>(receiver, &object.to_signal_obj())
     }
 
     /// Connect a method with any `&mut Gd<OtherC>` as the first parameter (user + engine classes).
@@ -277,26 +686,32 @@ impl<C: WithSignals, Ps: InParamTuple + 'static> ConnectBuilder<'_, '_, C, Ps> {
     ) -> ConnectHandle
     where
         OtherC: GodotClass,
-        F: SignalReceiver<Gd<OtherC>, Ps>,
-        for<'c_rcv> IndirectSignalReceiver<'c_rcv, Gd<OtherC>, Ps, F>: From<&'c_rcv mut F>,
+        F: SignalReceiver<Gd<OtherC>, Out>,
+        for<'c_rcv> IndirectSignalReceiver<'c_rcv, Gd<OtherC>, Out, F>: From<&'c_rcv mut F>,
     {
         let gd = object.to_signal_obj();
 
-        let godot_fn = make_godot_fn(move |args| {
+        let receiver = move |args: Out| {
             IndirectSignalReceiver::from(&mut method)
                 .function()
                 .call(gd.clone(), args);
-        });
+        };
 
-        self.inner_connect_godot_fn::<F>(godot_fn, &object.to_signal_obj())
+        self.inner_connect_receiver::<F>(receiver, &object.to_signal_obj())
     }
+}
 
+// Only without combinators, as these are not thread-safe.
+impl<C: WithSignals, Ps: InParamTuple + 'static> ConnectBuilder<'_, '_, C, Ps, Ps, NoCombinators> {
     /// Connect to this signal using a thread-safe function, allows the signal to be called across threads.
     ///
     /// Requires `Send` + `Sync` bounds on the provided function `F`, and is only available for the `experimental-threads`
     /// Cargo feature.
     ///
     /// If you need [connect flags](ConnectFlags), call [`flags()`](Self::flags) before this.
+    ///
+    /// # Panics
+    /// If [`owned_by()`](Self::owned_by) has been called on this builder, since the owner cannot be checked from other threads.
     #[cfg(feature = "experimental-threads")]
     pub fn connect_sync<F>(self, mut function: F)
     where
@@ -305,6 +720,11 @@ impl<C: WithSignals, Ps: InParamTuple + 'static> ConnectBuilder<'_, '_, C, Ps> {
         for<'c_rcv> F: SignalReceiver<(), Ps> + Send + Sync,
         for<'c_rcv> IndirectSignalReceiver<'c_rcv, (), Ps, F>: From<&'c_rcv mut F>,
     {
+        assert!(
+            self.data.owner.is_none(),
+            "connect_sync() does not support owned_by()."
+        );
+
         let godot_fn = make_godot_fn(move |args| {
             IndirectSignalReceiver::from(&mut function)
                 .function()
@@ -6,6 +6,8 @@
  */
 
 use std::borrow::Cow;
+use std::cell::Cell;
+use std::rc::Rc;
 
 use crate::builtin::Callable;
 use crate::classes::Object;
@@ -16,11 +18,15 @@ use crate::obj::Gd;
 /// Returned by connections made by the `connect_*` methods of
 /// [`TypedSignal`][crate::registry::signal::TypedSignal] and [`ConnectBuilder`][crate::registry::signal::ConnectBuilder].
 ///
-/// Connections managed by a handle can be disconnected using [`disconnect()`][Self::disconnect].
+/// Connections managed by a handle can be disconnected using [`disconnect()`][Self::disconnect]. Dropping the handle leaves the connection
+/// in place; to disconnect automatically, convert it into a [`ConnectGuard`] or add it to a [`ConnectionGroup`].
 pub struct ConnectHandle {
     receiver_object: Gd<Object>,
     signal_name: Cow<'static, str>,
     callable: Callable,
+
+    /// Set on disconnect, so that receivers called later by combinators (e.g. `debounce()`) can ignore the call.
+    disconnected: Option<Rc<Cell<bool>>>,
 }
 
 impl ConnectHandle {
@@ -34,9 +40,16 @@ impl ConnectHandle {
             receiver_object,
             signal_name,
             callable,
+            disconnected: None,
         }
     }
 
+    /// Sets `flag` to `true` once the connection is disconnected through this handle.
+    pub(super) fn with_disconnect_flag(mut self, flag: Rc<Cell<bool>>) -> Self {
+        self.disconnected = Some(flag);
+        self
+    }
+
     /// Disconnects the signal from the connected callable.
     ///
     /// Panics (Debug)
@@ -44,6 +57,10 @@ impl ConnectHandle {
     pub fn disconnect(mut self) {
         debug_assert!(self.is_connected());
 
+        if let Some(disconnected) = &self.disconnected {
+            disconnected.set(true);
+        }
+
         self.receiver_object
             .disconnect(&*self.signal_name, &self.callable);
     }
@@ -61,4 +78,172 @@ impl ConnectHandle {
                 .receiver_object
                 .is_connected(&*self.signal_name, &self.callable)
     }
+
+    /// Converts the handle into a guard, which disconnects the signal when dropped.
+    pub fn into_guard(self) -> ConnectGuard {
+        ConnectGuard { handle: Some(self) }
+    }
+
+    /// Disconnects only if the connection still exists; used by guards and groups.
+    fn disconnect_if_connected(self) {
+        if self.is_connected() {
+            self.disconnect();
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// ConnectGuard
+
+/// RAII guard for a signal connection, which disconnects when dropped.
+///
+/// Created by [`ConnectHandle::into_guard()`]. Store the guard alongside the state that the connection depends on, e.g. in the fields of
+/// a UI class, so the connection is torn down together with it.
+///
+/// If the connection has already been removed in another way (e.g. because the signal object was freed), dropping the guard does nothing.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// use godot::register::ConnectGuard;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct HealthBar {
+///     player_guard: Option<ConnectGuard>,
+///     base: Base<Node>,
+/// }
+///
+/// # #[derive(GodotClass)] #[class(init, base=Node)] struct Player { base: Base<Node> }
+/// # #[godot_api] impl Player { #[signal] fn health_changed(health: i32); }
+/// impl HealthBar {
+///     fn watch(&mut self, player: &Gd<Player>) {
+///         let handle = player.signals().health_changed().connect(|health| {
+///             println!("Health: {health}");
+///         });
+///
+///         // Replacing the guard disconnects the previous player's signal.
+///         self.player_guard = Some(handle.into_guard());
+///     }
+/// }
+/// ```
+#[must_use = "dropping a ConnectGuard immediately disconnects the signal"]
+pub struct ConnectGuard {
+    // Only None after release().
+    handle: Option<ConnectHandle>,
+}
+
+impl ConnectGuard {
+    /// Whether the guarded connection still exists. See [`ConnectHandle::is_connected()`].
+    pub fn is_connected(&self) -> bool {
+        self.handle
+            .as_ref()
+            .is_some_and(ConnectHandle::is_connected)
+    }
+
+    /// Disconnects the signal now, instead of on drop.
+    ///
+    /// Does nothing if the connection no longer exists.
+    pub fn disconnect(mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.disconnect_if_connected();
+        }
+    }
+
+    /// Releases the guard without disconnecting, returning the handle. The connection then stays in place when the handle is dropped.
+    pub fn release(mut self) -> ConnectHandle {
+        self.handle.take().expect("ConnectGuard already released")
+    }
+}
+
+impl Drop for ConnectGuard {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.take() {
+            handle.disconnect_if_connected();
+        }
+    }
+}
+
+impl From<ConnectHandle> for ConnectGuard {
+    fn from(handle: ConnectHandle) -> Self {
+        handle.into_guard()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// ConnectionGroup
+
+/// Owns multiple signal connections, and disconnects all of them together.
+///
+/// All connections are disconnected when the group is dropped, or explicitly using [`disconnect_all()`][Self::disconnect_all]. Connections
+/// that no longer exist at that point (e.g. because their signal object was freed) are skipped.
+///
+/// This is useful for screens or dialogs, which connect many signals when opened and must disconnect them when closed.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::classes::{Button, LineEdit};
+/// use godot::register::ConnectionGroup;
+///
+/// fn open_dialog(ok: &Gd<Button>, cancel: &Gd<Button>, name: &Gd<LineEdit>) -> ConnectionGroup {
+///     let mut connections = ConnectionGroup::new();
+///     connections.add(ok.signals().pressed().connect(|| println!("OK")));
+///     connections.add(cancel.signals().pressed().connect(|| println!("Cancel")));
+///     connections.add(name.signals().text_changed().connect(|text| println!("Name: {text}")));
+///
+///     connections // Keep alive while the dialog is open.
+/// }
+/// ```
+#[derive(Default)]
+#[must_use = "dropping a ConnectionGroup immediately disconnects all its signals"]
+pub struct ConnectionGroup {
+    handles: Vec<ConnectHandle>,
+}
+
+impl ConnectionGroup {
+    /// Creates an empty group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a connection to the group.
+    pub fn add(&mut self, handle: ConnectHandle) {
+        self.handles.push(handle);
+    }
+
+    /// Disconnects all connections in the group, leaving it empty. The group can be reused afterward.
+    pub fn disconnect_all(&mut self) {
+        for handle in self.handles.drain(..) {
+            handle.disconnect_if_connected();
+        }
+    }
+
+    /// Number of connections in the group, including those that have been disconnected in another way.
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    /// Returns `true` if the group contains no connections.
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+impl Drop for ConnectionGroup {
+    fn drop(&mut self) {
+        self.disconnect_all();
+    }
+}
+
+impl Extend<ConnectHandle> for ConnectionGroup {
+    fn extend<I: IntoIterator<Item = ConnectHandle>>(&mut self, iter: I) {
+        self.handles.extend(iter);
+    }
+}
+
+impl FromIterator<ConnectHandle> for ConnectionGroup {
+    fn from_iter<I: IntoIterator<Item = ConnectHandle>>(iter: I) -> Self {
+        Self {
+            handles: iter.into_iter().collect(),
+        }
+    }
 }
@@ -0,0 +1,132 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Typed access to signals that are only known at runtime (declared in scripts, or via `Object::add_user_signal()`).
+
+use godot_ffi as sys;
+
+use crate::builtin::{Dictionary, VariantArray, VariantType};
+use crate::classes::{ClassDb, Object};
+use crate::meta::error::DynSignalError;
+use crate::meta::{ParamTuple, PropertyInfo};
+use crate::obj::Gd;
+use crate::registry::signal::TypedSignal;
+
+impl<Ps: ParamTuple> TypedSignal<'static, Object, Ps> {
+    /// Creates a typed signal after checking `Ps` against the signal's declaration on `object`.
+    pub(crate) fn try_from_dynamic(object: Gd<Object>, name: &str) -> Result<Self, DynSignalError> {
+        check_signature::<Ps>(&object, name)?;
+
+        Ok(Self::new_dynamic(object, name.to_string()))
+    }
+}
+
+/// Compares the declaration from `Object::get_signal_list()` with the parameter types of `Ps`.
+fn check_signature<Ps: ParamTuple>(object: &Gd<Object>, name: &str) -> Result<(), DynSignalError> {
+    let class_name = || object.get_class().to_string();
+
+    let Some(declared_args) = find_signal_args(object, name) else {
+        return Err(DynSignalError::not_found(class_name(), name.to_string()));
+    };
+
+    if declared_args.len() != Ps::LEN {
+        return Err(DynSignalError::arity(
+            class_name(),
+            name.to_string(),
+            declared_args.len(),
+            Ps::LEN,
+        ));
+    }
+
+    for (index, declared) in declared_args.iter().enumerate() {
+        let requested = Ps::property_info(index, "")
+            .expect("ParamTuple::property_info() must be available for all indices < LEN");
+
+        if !is_compatible(declared, &requested) {
+            return Err(DynSignalError::param_type(
+                class_name(),
+                name.to_string(),
+                index,
+                describe(declared.variant_type, &declared.class_name),
+                describe(requested.variant_type, &requested.class_name.to_string()),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Type of a declared signal parameter, as reported by Godot.
+struct DeclaredParam {
+    variant_type: VariantType,
+    class_name: String,
+}
+
+fn find_signal_args(object: &Gd<Object>, name: &str) -> Option<Vec<DeclaredParam>> {
+    let signal = object
+        .get_signal_list()
+        .iter_shared()
+        .find(|signal| signal.get_or_nil("name").to_string() == name)?;
+
+    let args = signal
+        .get_or_nil("args")
+        .try_to::<VariantArray>()
+        .unwrap_or_default();
+
+    let params = args
+        .iter_shared()
+        .map(|arg| {
+            let arg = arg.try_to::<Dictionary>().unwrap_or_default();
+            let type_ord = arg.get_or_nil("type").try_to::<i64>().unwrap_or(0);
+
+            DeclaredParam {
+                variant_type: VariantType::from_sys(type_ord as sys::GDExtensionVariantType),
+                class_name: arg.get_or_nil("class_name").to_string(),
+            }
+        })
+        .collect();
+
+    Some(params)
+}
+
+fn is_compatible(declared: &DeclaredParam, requested: &PropertyInfo) -> bool {
+    // NIL stands for untyped parameters (e.g. in GDScript), or for `Variant` on the Rust side.
+    if declared.variant_type == VariantType::NIL || requested.variant_type == VariantType::NIL {
+        return true;
+    }
+
+    if declared.variant_type != requested.variant_type {
+        return false;
+    }
+
+    if declared.variant_type != VariantType::OBJECT
+        || declared.class_name.is_empty()
+        || requested.class_name.is_none()
+    {
+        return true;
+    }
+
+    // Only classes known to ClassDB can be compared; script classes (`class_name` in GDScript) are accepted as-is.
+    let declared_class = crate::builtin::StringName::from(declared.class_name.as_str());
+    let requested_class = requested.class_name.to_string_name();
+
+    let class_db = ClassDb::singleton();
+    if !class_db.class_exists(&declared_class) || !class_db.class_exists(&requested_class) {
+        return true;
+    }
+
+    // Objects emitted with the declared class must be convertible to the requested one.
+    class_db.is_parent_class(&declared_class, &requested_class)
+}
+
+fn describe(variant_type: VariantType, class_name: &str) -> String {
+    if variant_type == VariantType::OBJECT && !class_name.is_empty() {
+        class_name.to_string()
+    } else {
+        format!("{variant_type:?}")
+    }
+}
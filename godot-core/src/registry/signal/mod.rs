@@ -9,6 +9,7 @@
 
 mod connect_builder;
 mod connect_handle;
+mod dyn_signal;
 mod signal_object;
 mod signal_receiver;
 mod typed_signal;
@@ -24,7 +25,7 @@ use crate::meta;
 // Used in `godot` crate.
 pub mod re_export {
     pub use super::connect_builder::ConnectBuilder;
-    pub use super::connect_handle::ConnectHandle;
+    pub use super::connect_handle::{ConnectGuard, ConnectHandle, ConnectionGroup};
     pub use super::signal_receiver::{IndirectSignalReceiver, SignalReceiver};
     pub use super::typed_signal::TypedSignal;
 }
@@ -103,6 +103,15 @@ impl<'c, C: WithSignals, Ps: meta::ParamTuple> TypedSignal<'c, C, Ps> {
         }
     }
 
+    // Used for signals that are only known at runtime, see dyn_signal.rs.
+    pub(super) fn new_dynamic(object: C::__SignalObj<'c>, name: String) -> Self {
+        Self {
+            object,
+            name: Cow::Owned(name),
+            _signature: PhantomData,
+        }
+    }
+
     pub(crate) fn receiver_object(&self) -> Gd<C> {
         let object = self.object.to_owned_object();
 
@@ -5,6 +5,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::any::{type_name, TypeId};
 use std::cell::Cell;
 use std::ptr;
 
@@ -15,6 +16,7 @@ use godot_cell::panicking::{InaccessibleGuard, MutGuard, RefGuard};
 use godot_ffi as sys;
 
 use crate::godot_error;
+use crate::meta::error::BindError;
 use crate::obj::{Base, Gd, GodotClass, Inherits};
 use crate::storage::log_pre_drop;
 
@@ -94,6 +96,12 @@ pub unsafe trait Storage {
     /// they are violated.
     fn get_mut(&self) -> MutGuard<'_, Self::Instance>;
 
+    /// Like [`get()`][Self::get], but returns an error instead of panicking if the instance is already bound.
+    fn try_get(&self) -> Result<RefGuard<'_, Self::Instance>, BindError>;
+
+    /// Like [`get_mut()`][Self::get_mut], but returns an error instead of panicking if the instance is already bound.
+    fn try_get_mut(&self) -> Result<MutGuard<'_, Self::Instance>, BindError>;
+
     /// Returns a guard that allows calling methods on `Gd<Base>` that take `&mut self`.
     ///
     /// This can use the provided `instance` to provide extra safety guarantees such as allowing reentrant
@@ -169,8 +177,80 @@ const fn _assert_implements_storage<T: Storage + StorageRefCounted>() {}
 const _INSTANCE_STORAGE_IMPLEMENTS_STORAGE: () =
     _assert_implements_storage::<InstanceStorage<crate::classes::Object>>();
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Rust base classes
+
+/// First field of every `InstanceStorage<T>`, identifying the stored class.
+///
+/// Godot only knows one extension instance per object: the storage of the most-derived class. This pointer is also passed to callbacks
+/// registered by Rust base classes, which then follow `base_storage` to find their own part of the object.
+#[repr(C)]
+pub(crate) struct StorageHeader {
+    type_id: TypeId,
+
+    /// Header of the storage of the Rust base class, or null if the direct base is an engine class.
+    base_storage: *const StorageHeader,
+}
+
+impl StorageHeader {
+    pub(crate) fn new<T: GodotClass>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            base_storage: ptr::null(),
+        }
+    }
+
+    pub(crate) fn set_base_storage(&mut self, base_storage: &ErasedBaseStorage) {
+        self.base_storage = base_storage.storage.header();
+    }
+}
+
+/// Type-erased access to the storage of a Rust base class.
+pub(crate) trait BaseStorage {
+    fn header(&self) -> *const StorageHeader;
+
+    fn is_bound(&self) -> bool;
+
+    fn set_lifecycle(&self, lifecycle: Lifecycle);
+}
+
+impl<T: GodotClass> BaseStorage for InstanceStorage<T> {
+    fn header(&self) -> *const StorageHeader {
+        // Storages are #[repr(C)] with the header as first field.
+        ptr::from_ref(self).cast::<StorageHeader>()
+    }
+
+    fn is_bound(&self) -> bool {
+        Storage::is_bound(self)
+    }
+
+    fn set_lifecycle(&self, lifecycle: Lifecycle) {
+        Storage::set_lifecycle(self, lifecycle)
+    }
+}
+
+/// Rust part of an object that belongs to a Rust base class.
+///
+/// Owned by the storage of the derived class, so it lives exactly as long as the object.
+#[doc(hidden)]
+pub struct ErasedBaseStorage {
+    pub(crate) storage: Box<dyn BaseStorage>,
+}
+
+impl ErasedBaseStorage {
+    pub(crate) fn new<T: GodotClass>(storage: InstanceStorage<T>) -> Self {
+        Self {
+            storage: Box::new(storage),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
 /// Interprets the opaque pointer as pointing to `InstanceStorage<T>`.
 ///
+/// If the object's class derives from `T` on the Rust side, the storage of `T` is found through the chain of base storages.
+///
 /// Note: returns reference with unbounded lifetime; intended for local usage
 ///
 /// # Safety
@@ -180,7 +260,19 @@ const _INSTANCE_STORAGE_IMPLEMENTS_STORAGE: () =
 pub unsafe fn as_storage<'u, T: GodotClass>(
     instance_ptr: sys::GDExtensionClassInstancePtr,
 ) -> &'u InstanceStorage<T> {
-    &*(instance_ptr as *mut InstanceStorage<T>)
+    let mut header = instance_ptr as *const StorageHeader;
+
+    while (*header).type_id != TypeId::of::<T>() {
+        header = (*header).base_storage;
+
+        assert!(
+            !header.is_null(),
+            "instance storage does not contain Rust class `{}`",
+            type_name::<T>()
+        );
+    }
+
+    &*(header as *const InstanceStorage<T>)
 }
 
 /// # Safety
@@ -13,32 +13,32 @@ mod single_threaded;
 
 use std::any::type_name;
 
+use crate::meta::error::BindError;
+
 pub use instance_storage::*;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Shared code for submodules
 
-fn bind_failed<T>(err: Box<dyn std::error::Error>, tracker: &DebugBorrowTracker) -> ! {
-    let ty = type_name::<T>();
-
-    eprint!("{tracker}");
-
-    panic!(
-        "Gd<T>::bind() failed, already bound; T = {ty}.\n  \
-        Make sure to use `self.base_mut()` or `self.base()` instead of `self.to_gd()` when possible.\n  \
-        Details: {err}."
-    )
-}
+fn bind_failed(err: BindError) -> ! {
+    if let Some(previous_borrow) = err.previous_borrow() {
+        eprint!("{previous_borrow}");
+    }
 
-fn bind_mut_failed<T>(err: Box<dyn std::error::Error>, tracker: &DebugBorrowTracker) -> ! {
-    let ty = type_name::<T>();
+    let method = err.method_name();
+    let ty = err.type_name();
+    let details = err.reason();
 
-    eprint!("{tracker}");
+    let hint = if err.is_mut() {
+        "Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible."
+    } else {
+        "Make sure to use `self.base_mut()` or `self.base()` instead of `self.to_gd()` when possible."
+    };
 
     panic!(
-        "Gd<T>::bind_mut() failed, already bound; T = {ty}.\n  \
-        Make sure to use `self.base_mut()` instead of `self.to_gd()` when possible.\n  \
-        Details: {err}."
+        "Gd<T>::{method}() failed, already bound; T = {ty}.\n  \
+        {hint}\n  \
+        Details: {details}."
     )
 }
 
@@ -10,10 +10,18 @@ use godot_cell::blocking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 #[cfg(not(feature = "experimental-threads"))]
 use godot_cell::panicking::{GdCell, InaccessibleGuard, MutGuard, RefGuard};
 
+use crate::meta::error::BindError;
 use crate::obj::{Base, GodotClass};
-use crate::storage::{AtomicLifecycle, DebugBorrowTracker, Lifecycle, Storage, StorageRefCounted};
+use crate::storage::{
+    AtomicLifecycle, DebugBorrowTracker, ErasedBaseStorage, Lifecycle, Storage, StorageHeader,
+    StorageRefCounted,
+};
 
+#[repr(C)]
 pub struct InstanceStorage<T: GodotClass> {
+    // Must stay the first field, see StorageHeader.
+    header: StorageHeader,
+
     user_instance: GdCell<T>,
     pub(super) base: Base<T::Base>,
 
@@ -22,6 +30,9 @@ pub struct InstanceStorage<T: GodotClass> {
 
     // No-op in Release mode.
     borrow_tracker: DebugBorrowTracker,
+
+    // Part of the object belonging to a Rust base class, if any. Declared last, so it outlives the user instance.
+    base_storage: Option<ErasedBaseStorage>,
 }
 
 // SAFETY:
@@ -42,15 +53,21 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
         super::log_construct::<T>(&base);
 
         Self {
+            header: StorageHeader::new::<T>(),
             user_instance: GdCell::new(user_instance),
             base,
             lifecycle: AtomicLifecycle::new(Lifecycle::Alive),
             borrow_tracker: DebugBorrowTracker::new(),
+            base_storage: None,
         }
     }
 
     fn is_bound(&self) -> bool {
         self.user_instance.is_currently_bound()
+            || self
+                .base_storage
+                .as_ref()
+                .is_some_and(|base| base.storage.is_bound())
     }
 
     fn base(&self) -> &Base<<Self::Instance as GodotClass>::Base> {
@@ -61,23 +78,31 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     // of capturing the backtrace. This may be changed as the threading model (#18) evolves.
 
     fn get(&self) -> RefGuard<'_, T> {
+        self.try_get().unwrap_or_else(|e| super::bind_failed(e))
+    }
+
+    fn get_mut(&self) -> MutGuard<'_, T> {
+        self.try_get_mut().unwrap_or_else(|e| super::bind_failed(e))
+    }
+
+    fn try_get(&self) -> Result<RefGuard<'_, T>, BindError> {
         let guard = self
             .user_instance
             .borrow()
-            .unwrap_or_else(|e| super::bind_failed::<T>(e, &self.borrow_tracker));
+            .map_err(|e| BindError::new::<T>(false, e, &self.borrow_tracker))?;
 
         self.borrow_tracker.track_ref_borrow();
-        guard
+        Ok(guard)
     }
 
-    fn get_mut(&self) -> MutGuard<'_, T> {
+    fn try_get_mut(&self) -> Result<MutGuard<'_, T>, BindError> {
         let guard = self
             .user_instance
             .borrow_mut()
-            .unwrap_or_else(|e| super::bind_mut_failed::<T>(e, &self.borrow_tracker));
+            .map_err(|e| BindError::new::<T>(true, e, &self.borrow_tracker))?;
 
         self.borrow_tracker.track_mut_borrow();
-        guard
+        Ok(guard)
     }
 
     fn get_inaccessible<'a: 'b, 'b>(
@@ -94,7 +119,19 @@ unsafe impl<T: GodotClass> Storage for InstanceStorage<T> {
     }
 
     fn set_lifecycle(&self, lifecycle: Lifecycle) {
-        self.lifecycle.set(lifecycle)
+        self.lifecycle.set(lifecycle);
+
+        if let Some(base) = &self.base_storage {
+            base.storage.set_lifecycle(lifecycle);
+        }
+    }
+}
+
+impl<T: GodotClass> InstanceStorage<T> {
+    /// Attaches the part of the object that belongs to the Rust base class of `T`.
+    pub(crate) fn set_base_storage(&mut self, base_storage: ErasedBaseStorage) {
+        self.header.set_base_storage(&base_storage);
+        self.base_storage = Some(base_storage);
     }
 }
 
@@ -6,16 +6,19 @@
  */
 
 use std::cell::RefCell;
+use std::fmt;
 use std::future::Future;
 use std::marker::PhantomData;
 use std::panic::AssertUnwindSafe;
 use std::pin::Pin;
+use std::rc::Rc;
 use std::sync::Arc;
 use std::task::{Context, Poll, Wake, Waker};
 use std::thread::{self, LocalKey, ThreadId};
 
 use crate::builtin::{Callable, Variant};
 use crate::private::handle_panic;
+use crate::task::CancellationToken;
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Public interface
@@ -23,7 +26,8 @@ use crate::private::handle_panic;
 /// Create a new async background task.
 ///
 /// This function allows creating a new async task in which Godot signals can be awaited, like it is possible in GDScript. The
-/// [`TaskHandle`] that is returned provides synchronous introspection into the current state of the task.
+/// [`JoinHandle`] that is returned provides synchronous introspection into the current state of the task. It can also be awaited from
+/// another task, to obtain the value that the future returned.
 ///
 /// Signals can be converted to futures in the following ways:
 ///
@@ -86,8 +90,29 @@ use crate::private::handle_panic;
 ///     println!("Node has changed: {}", node.get_name());
 /// });
 /// ```
+///
+/// Awaiting the result of another task:
+/// ```no_run
+/// # use godot::builtin::Signal;
+/// # let signal: Signal = unimplemented!();
+/// let loader = godot::task::spawn(async move {
+///     signal.to_future::<()>().await;
+///     42
+/// });
+///
+/// godot::task::spawn(async move {
+///     match loader.await {
+///         Ok(value) => println!("Loaded: {value}"),
+///         Err(err) => println!("Loader did not finish: {err}"),
+///     }
+/// });
+/// ```
 #[doc(alias = "async")]
-pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+pub fn spawn<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + 'static,
+    F::Output: 'static,
+{
     // Spawning new tasks is only allowed on the main thread for now.
     // We can not accept Sync + Send futures since all object references (i.e. Gd<T>) are not thread-safe. So a future has to remain on the
     // same thread it was created on. Godots signals on the other hand can be emitted on any thread, so it can't be guaranteed on which thread
@@ -100,43 +125,71 @@ pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
         "godot_task() can only be used on the main thread"
     );
 
-    let (task_handle, godot_waker) = ASYNC_RUNTIME.with_runtime_mut(move |rt| {
-        let task_handle = rt.add_task(Box::pin(future));
-        let godot_waker = Arc::new(GodotWaker::new(
-            task_handle.index,
-            task_handle.id,
-            thread::current().id(),
-        ));
+    let state = Rc::new(RefCell::new(JoinState::Pending { waker: None }));
+    let token = CancellationToken::new();
+    let task = JoinTask {
+        future: Box::pin(future),
+        state: state.clone(),
+        token: token.clone(),
+    };
 
-        (task_handle, godot_waker)
-    });
+    let (index, id) = ASYNC_RUNTIME.with_runtime_mut(move |rt| rt.add_task(task));
+    let godot_waker = Arc::new(GodotWaker::new(index, id, thread::current().id()));
 
     poll_future(godot_waker);
-    task_handle
+    JoinHandle::new(index, id, state, token)
 }
 
-/// Handle for an active background task.
+/// Handle for an active background task, returned by [`spawn()`].
 ///
 /// This handle provides introspection into the current state of the task, as well as providing a way to cancel it.
 ///
+/// A `JoinHandle<T>` is itself a future, which resolves once the task has finished: to `Ok(T)` with the value returned by the task, or to
+/// [`Err(JoinError)`][JoinError] if the task panicked, was canceled, or the engine shut down. Awaiting is only possible from within another
+/// task.
+///
 /// The associated task will **not** be canceled if this handle is dropped.
-pub struct TaskHandle {
+pub struct JoinHandle<T> {
     index: usize,
     id: u64,
+    state: Rc<RefCell<JoinState<T>>>,
+    token: CancellationToken,
     _no_send_sync: PhantomData<*const ()>,
 }
 
-impl TaskHandle {
-    fn new(index: usize, id: u64) -> Self {
+/// Handle for an active background task, which does not return a value.
+pub type TaskHandle = JoinHandle<()>;
+
+impl<T> JoinHandle<T> {
+    fn new(
+        index: usize,
+        id: u64,
+        state: Rc<RefCell<JoinState<T>>>,
+        token: CancellationToken,
+    ) -> Self {
         Self {
             index,
             id,
+            state,
+            token,
             _no_send_sync: PhantomData,
         }
     }
 
+    /// Returns the task's token, which the task obtains with [`CancellationToken::current()`].
+    ///
+    /// Canceling the token lets the task stop at a point of its choosing, while [`cancel()`][Self::cancel] drops it wherever it is suspended.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.token.clone()
+    }
+
     /// Cancels the task if it is still pending and does nothing if it is already completed.
+    ///
+    /// The task's [cancellation token][Self::cancellation_token] is canceled as well, so that clones of it held elsewhere observe the
+    /// cancellation.
     pub fn cancel(self) {
+        self.token.cancel();
+
         ASYNC_RUNTIME.with_runtime_mut(|rt| {
             let Some(task) = rt.tasks.get(self.index) else {
                 // Getting the task from the runtime might return None if the runtime has already been deinitialized. In this case, we just
@@ -181,6 +234,171 @@ impl TaskHandle {
     }
 }
 
+impl<T> Future for JoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+
+        match std::mem::replace(&mut *state, JoinState::Taken) {
+            JoinState::Pending { .. } => {
+                *state = JoinState::Pending {
+                    waker: Some(cx.waker().clone()),
+                };
+                Poll::Pending
+            }
+            JoinState::Finished(result) => Poll::Ready(result),
+            JoinState::Taken => panic!("JoinHandle polled after completion"),
+        }
+    }
+}
+
+/// Error returned when awaiting a [`JoinHandle`] of a task that did not run to completion.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinError {
+    kind: JoinErrorKind,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum JoinErrorKind {
+    Panicked(String),
+    Cancelled,
+    Shutdown,
+}
+
+impl JoinError {
+    pub(super) fn panicked(panic_message: String) -> Self {
+        Self {
+            kind: JoinErrorKind::Panicked(panic_message),
+        }
+    }
+
+    pub(super) fn cancelled() -> Self {
+        Self {
+            kind: JoinErrorKind::Cancelled,
+        }
+    }
+
+    pub(super) fn shutdown() -> Self {
+        Self {
+            kind: JoinErrorKind::Shutdown,
+        }
+    }
+
+    /// Returns `true` if the task panicked.
+    pub fn is_panic(&self) -> bool {
+        matches!(self.kind, JoinErrorKind::Panicked(_))
+    }
+
+    /// Returns `true` if the task was canceled explicitly, through [`JoinHandle::cancel()`].
+    pub fn is_cancelled(&self) -> bool {
+        self.kind == JoinErrorKind::Cancelled
+    }
+
+    /// Returns `true` if the task could not complete because the engine shut down.
+    pub fn is_shutdown(&self) -> bool {
+        self.kind == JoinErrorKind::Shutdown
+    }
+
+    /// The message of the panic, if the task panicked.
+    pub fn panic_message(&self) -> Option<&str> {
+        match &self.kind {
+            JoinErrorKind::Panicked(message) => Some(message),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            JoinErrorKind::Panicked(message) => write!(f, "async task panicked: {message}"),
+            JoinErrorKind::Cancelled => write!(f, "async task was canceled"),
+            JoinErrorKind::Shutdown => {
+                write!(f, "async task did not complete before engine shutdown")
+            }
+        }
+    }
+}
+
+impl std::error::Error for JoinError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Task result
+
+/// Result of a task, shared between the task and its [`JoinHandle`].
+enum JoinState<T> {
+    /// Task is running; the waker belongs to a task awaiting the `JoinHandle`.
+    Pending { waker: Option<Waker> },
+    /// Task has finished, result not yet retrieved.
+    Finished(Result<T, JoinError>),
+    /// Result has been retrieved by awaiting the `JoinHandle`.
+    Taken,
+}
+
+impl<T> JoinState<T> {
+    fn finish(&mut self, result: Result<T, JoinError>, wake: bool) {
+        if let JoinState::Pending { waker: Some(waker) } =
+            std::mem::replace(self, JoinState::Finished(result))
+        {
+            if wake {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// Future stored in the runtime, which forwards the output of the user's future to the [`JoinHandle`].
+struct JoinTask<F: Future> {
+    future: Pin<Box<F>>,
+    state: Rc<RefCell<JoinState<F::Output>>>,
+    token: CancellationToken,
+}
+
+impl<F: Future> Future for JoinTask<F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = &mut *self;
+        let poll_result = {
+            let _guard = this.token.enter();
+            std::panic::catch_unwind(AssertUnwindSafe(|| this.future.as_mut().poll(cx)))
+        };
+
+        match poll_result {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(value)) => {
+                this.state.borrow_mut().finish(Ok(value), true);
+                Poll::Ready(())
+            }
+            Err(payload) => {
+                let panic_message = crate::private::extract_panic_message(payload.as_ref());
+                this.state
+                    .borrow_mut()
+                    .finish(Err(JoinError::panicked(panic_message)), true);
+
+                // Propagate, so that the runtime reports the panic as usual.
+                std::panic::resume_unwind(payload)
+            }
+        }
+    }
+}
+
+impl<F: Future> Drop for JoinTask<F> {
+    fn drop(&mut self) {
+        // Dropped before completion: the task was canceled or the runtime shut down.
+        // In the latter case, awaiting tasks are dropped as well, and the engine must not be called anymore, so nobody is woken.
+        let mut state = self.state.borrow_mut();
+        if matches!(*state, JoinState::Pending { .. }) {
+            if is_runtime_shut_down() {
+                state.finish(Err(JoinError::shutdown()), false);
+            } else {
+                state.finish(Err(JoinError::cancelled()), true);
+            }
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Async Runtime
 
@@ -197,7 +415,16 @@ thread_local! {
 /// We have to drop all the remaining Futures during engine shutdown. This avoids them being dropped at process termination where they would
 /// try to access engine resources, which leads to SEGFAULTs.
 pub(crate) fn cleanup() {
-    ASYNC_RUNTIME.set(None);
+    // Take the runtime out before dropping it, so that futures being dropped can detect the shutdown (see `is_runtime_shut_down`).
+    let runtime = ASYNC_RUNTIME.take();
+    drop(runtime);
+}
+
+/// Whether the runtime has been deinitialized.
+///
+/// Returns `false` if the runtime is currently borrowed, e.g. because a task is being canceled.
+fn is_runtime_shut_down() -> bool {
+    ASYNC_RUNTIME.with(|rt| rt.try_borrow().is_ok_and(|rt| rt.is_none()))
 }
 
 #[cfg(feature = "trace")]
@@ -317,7 +544,9 @@ impl AsyncRuntime {
     /// free slot, a new slot is added which may grow the underlying [`Vec`].
     ///
     /// The future storage always starts out with a capacity of 10 tasks.
-    fn add_task<F: Future<Output = ()> + 'static>(&mut self, future: F) -> TaskHandle {
+    ///
+    /// Returns the index and ID of the task.
+    fn add_task<F: Future<Output = ()> + 'static>(&mut self, future: F) -> (usize, u64) {
         let id = self.next_id();
         let index_slot = self
             .tasks
@@ -339,7 +568,7 @@ impl AsyncRuntime {
             }
         };
 
-        TaskHandle::new(index, id)
+        (index, id)
     }
 
     /// Extract a pending task from the storage.
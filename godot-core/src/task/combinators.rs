@@ -0,0 +1,321 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// join_all()
+
+/// Waits for all futures to complete, and returns their outputs in the original order.
+///
+/// The futures run concurrently within the current task. To await spawned tasks, pass their [`JoinHandle`][super::JoinHandle]s.
+///
+/// ```no_run
+/// # use godot::builtin::Signal;
+/// # let (first, second): (Signal, Signal) = unimplemented!();
+/// use godot::task;
+///
+/// task::spawn(async move {
+///     let futures = [first.to_future::<()>(), second.to_future::<()>()];
+///     task::join_all(futures).await;
+///     println!("Both signals have been emitted.");
+/// });
+/// ```
+pub fn join_all<I>(futures: I) -> JoinAll<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    let futures = futures
+        .into_iter()
+        .map(|future| JoinSlot::Pending(Box::pin(future)))
+        .collect();
+
+    JoinAll { futures }
+}
+
+/// Future returned by [`join_all()`].
+pub struct JoinAll<F: Future> {
+    futures: Vec<JoinSlot<F>>,
+}
+
+enum JoinSlot<F: Future> {
+    Pending(Pin<Box<F>>),
+    Done(Option<F::Output>),
+}
+
+// Futures are boxed, so moving JoinAll doesn't move them.
+impl<F: Future> Unpin for JoinAll<F> {}
+
+impl<F: Future> Future for JoinAll<F> {
+    type Output = Vec<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut all_done = true;
+
+        for slot in self.futures.iter_mut() {
+            if let JoinSlot::Pending(future) = slot {
+                match future.as_mut().poll(cx) {
+                    Poll::Ready(output) => *slot = JoinSlot::Done(Some(output)),
+                    Poll::Pending => all_done = false,
+                }
+            }
+        }
+
+        if !all_done {
+            return Poll::Pending;
+        }
+
+        let outputs = self
+            .futures
+            .iter_mut()
+            .map(|slot| match slot {
+                JoinSlot::Done(output) => output.take().expect("JoinAll polled after completion"),
+                JoinSlot::Pending(_) => unreachable!("all futures are done"),
+            })
+            .collect();
+
+        Poll::Ready(outputs)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// select()
+
+/// Waits for the first of several futures to complete, and returns its output together with its index.
+///
+/// The remaining futures are dropped. To wait for futures with different output types, map them to a common type, e.g. an enum.
+///
+/// ```no_run
+/// # use godot::builtin::Signal;
+/// # let (accepted, canceled): (Signal, Signal) = unimplemented!();
+/// use godot::task;
+///
+/// task::spawn(async move {
+///     let futures = [accepted.to_future::<()>(), canceled.to_future::<()>()];
+///     let ((), index) = task::select(futures).await;
+///     println!("Dialog was {}.", if index == 0 { "accepted" } else { "canceled" });
+/// });
+/// ```
+///
+/// # Panics
+/// When awaited, if `futures` is empty.
+pub fn select<I>(futures: I) -> Select<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Future,
+{
+    let futures = futures.into_iter().map(Box::pin).collect();
+
+    Select {
+        futures,
+        is_done: false,
+    }
+}
+
+/// Future returned by [`select()`].
+pub struct Select<F: Future> {
+    futures: Vec<Pin<Box<F>>>,
+    is_done: bool,
+}
+
+impl<F: Future> Unpin for Select<F> {}
+
+impl<F: Future> Future for Select<F> {
+    type Output = (F::Output, usize);
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        assert!(!self.is_done, "Select polled after completion");
+        assert!(
+            !self.futures.is_empty(),
+            "select() needs at least one future"
+        );
+
+        for (index, future) in self.futures.iter_mut().enumerate() {
+            if let Poll::Ready(output) = future.as_mut().poll(cx) {
+                self.futures.clear();
+                self.is_done = true;
+                return Poll::Ready((output, index));
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// CancellationToken
+
+/// Cooperative cancellation for async tasks.
+///
+/// Clones of a token share their state: once [`cancel()`][Self::cancel] is called on any of them, all of them are canceled. Tasks can
+/// check [`is_cancelled()`][Self::is_cancelled] or await [`cancelled()`][Self::cancelled], for example in [`select()`], to stop their work
+/// at a point of their choosing. In contrast, [`JoinHandle::cancel()`][super::JoinHandle::cancel] drops a task wherever it is suspended.
+///
+/// Every task started with [`spawn()`][super::spawn] has its own token, available inside the task through [`current()`][Self::current]
+/// and outside through [`JoinHandle::cancellation_token()`][super::JoinHandle::cancellation_token]. Tokens can also be created and shared
+/// explicitly. Like tasks, tokens are bound to the main thread.
+///
+/// ```no_run
+/// # use godot::builtin::Signal;
+/// # let signal: Signal = unimplemented!();
+/// use godot::task::{self, CancellationToken};
+///
+/// let token = CancellationToken::new();
+/// let task_token = token.clone();
+///
+/// task::spawn(async move {
+///     while !task_token.is_cancelled() {
+///         signal.to_future::<()>().await;
+///         println!("Signal received.");
+///     }
+/// });
+///
+/// // Later:
+/// token.cancel();
+/// ```
+///
+/// Using the token of a task:
+/// ```no_run
+/// # use godot::builtin::Signal;
+/// # let signal: Signal = unimplemented!();
+/// use godot::task::{self, CancellationToken};
+///
+/// let handle = task::spawn(async move {
+///     let token = CancellationToken::current().expect("inside a task");
+///     while !token.is_cancelled() {
+///         signal.to_future::<()>().await;
+///     }
+/// });
+///
+/// // Later:
+/// handle.cancellation_token().cancel();
+/// ```
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    state: Rc<RefCell<CancellationState>>,
+}
+
+#[derive(Default)]
+struct CancellationState {
+    is_cancelled: bool,
+    /// Wakers of pending [`Cancelled`] futures, by their key.
+    wakers: HashMap<u64, Waker>,
+    next_key: u64,
+}
+
+thread_local! {
+    /// Token of the task that is currently being polled.
+    static CURRENT_TOKEN: RefCell<Option<CancellationToken>> = const { RefCell::new(None) };
+}
+
+impl CancellationToken {
+    /// Creates a token that is not canceled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the token of the task that is currently running, or `None` if not called from within a task spawned by
+    /// [`spawn()`][super::spawn].
+    pub fn current() -> Option<Self> {
+        CURRENT_TOKEN.with_borrow(Clone::clone)
+    }
+
+    /// Makes this token the one returned by [`current()`][Self::current], until the returned guard is dropped.
+    pub(super) fn enter(&self) -> CurrentTokenGuard {
+        let previous = CURRENT_TOKEN.replace(Some(self.clone()));
+        CurrentTokenGuard { previous }
+    }
+
+    /// Cancels this token and all its clones. Tasks awaiting [`cancelled()`][Self::cancelled] are woken up.
+    ///
+    /// Has no effect if the token is already canceled.
+    pub fn cancel(&self) {
+        let wakers = {
+            let mut state = self.state.borrow_mut();
+            state.is_cancelled = true;
+            std::mem::take(&mut state.wakers)
+        };
+
+        for waker in wakers.into_values() {
+            waker.wake();
+        }
+    }
+
+    /// Returns `true` if [`cancel()`][Self::cancel] has been called on this token or one of its clones.
+    pub fn is_cancelled(&self) -> bool {
+        self.state.borrow().is_cancelled
+    }
+
+    /// Returns a future that resolves once the token is canceled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled {
+            token: self.clone(),
+            key: None,
+        }
+    }
+}
+
+/// Restores the previous [`CancellationToken::current()`] when dropped.
+pub(super) struct CurrentTokenGuard {
+    previous: Option<CancellationToken>,
+}
+
+impl Drop for CurrentTokenGuard {
+    fn drop(&mut self) {
+        CURRENT_TOKEN.set(self.previous.take());
+    }
+}
+
+/// Future returned by [`CancellationToken::cancelled()`].
+pub struct Cancelled {
+    token: CancellationToken,
+    /// Key of the waker registered in the token, once polled.
+    key: Option<u64>,
+}
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let this = self.get_mut();
+        let mut state = this.token.state.borrow_mut();
+
+        if state.is_cancelled {
+            return Poll::Ready(());
+        }
+
+        let key = *this.key.get_or_insert_with(|| {
+            let key = state.next_key;
+            state.next_key += 1;
+            key
+        });
+
+        match state.wakers.get_mut(&key) {
+            Some(waker) if waker.will_wake(cx.waker()) => {}
+            Some(waker) => waker.clone_from(cx.waker()),
+            None => {
+                state.wakers.insert(key, cx.waker().clone());
+            }
+        }
+
+        Poll::Pending
+    }
+}
+
+impl Drop for Cancelled {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token.state.borrow_mut().wakers.remove(&key);
+        }
+    }
+}
@@ -8,8 +8,9 @@
 use core::panic;
 use std::fmt::Display;
 use std::future::{Future, IntoFuture};
+use std::marker::PhantomData;
 use std::pin::Pin;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 use std::task::{Context, Poll, Waker};
 use std::thread::ThreadId;
 
@@ -60,7 +61,8 @@ impl<R: InParamTuple + IntoDynamicSend> Future for SignalFuture<R> {
 }
 
 // Not derived, otherwise an extra bound `Output: Default` is required.
-struct SignalFutureData<T> {
+// Public, but unnameable outside the crate; only needed for the itest alias `SignalFutureResolver`.
+pub struct SignalFutureData<T> {
     state: SignalFutureState<T>,
     waker: Option<Waker>,
 }
@@ -74,89 +76,36 @@ impl<T> Default for SignalFutureData<T> {
     }
 }
 
-// Only public for itest.
-pub struct SignalFutureResolver<R: IntoDynamicSend> {
-    data: Arc<Mutex<SignalFutureData<R::Target>>>,
-}
-
-impl<R: IntoDynamicSend> Clone for SignalFutureResolver<R> {
-    fn clone(&self) -> Self {
-        Self {
-            data: self.data.clone(),
-        }
-    }
-}
-
-/// For itest to construct and test a resolver.
-#[cfg(feature = "trace")]
-pub fn create_test_signal_future_resolver<R: IntoDynamicSend>() -> SignalFutureResolver<R> {
-    SignalFutureResolver {
-        data: Arc::new(Mutex::new(SignalFutureData::default())),
-    }
-}
+impl<T: Send + 'static> ResolverState<T> for SignalFutureData<T> {
+    fn on_emit(&mut self, value: T) -> Option<Waker> {
+        self.state = SignalFutureState::Ready(value);
 
-impl<R: IntoDynamicSend> SignalFutureResolver<R> {
-    fn new(data: Arc<Mutex<SignalFutureData<R::Target>>>) -> Self {
-        Self { data }
-    }
-}
-
-impl<R: IntoDynamicSend> std::hash::Hash for SignalFutureResolver<R> {
-    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        state.write_usize(Arc::as_ptr(&self.data) as usize);
+        // We no longer need the waker after we resolved. If the future is polled again, we'll also get a new waker.
+        self.waker.take()
     }
-}
-
-impl<R: IntoDynamicSend> PartialEq for SignalFutureResolver<R> {
-    fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.data, &other.data)
-    }
-}
 
-impl<R: InParamTuple + IntoDynamicSend> RustCallable for SignalFutureResolver<R> {
-    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
-        let waker = {
-            let mut data = self.data.lock().unwrap();
-            data.state = SignalFutureState::Ready(R::from_variant_array(args).into_dynamic_send());
-
-            // We no longer need the waker after we resolved. If the future is polled again, we'll also get a new waker.
-            data.waker.take()
-        };
-
-        if let Some(waker) = waker {
-            waker.wake();
+    // Marking the future as dead lets it resolve to an error value the next time it gets polled.
+    fn on_resolver_dropped(&mut self) -> Option<Waker> {
+        if !matches!(self.state, SignalFutureState::Pending) {
+            // The future is no longer pending, so no clean up is required.
+            return None;
         }
 
-        Ok(Variant::nil())
-    }
-}
+        self.state = SignalFutureState::Dead;
 
-impl<R: IntoDynamicSend> Display for SignalFutureResolver<R> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "SignalFutureResolver::<{}>", std::any::type_name::<R>())
+        // If there is no waker, then the future has not been polled yet and we simply wait for the runtime to perform the first poll.
+        self.waker.clone()
     }
 }
 
-// This resolver will change the futures state when it's being dropped (i.e. the engine removes all connected signal callables). By marking
-// the future as dead we can resolve it to an error value the next time it gets polled.
-impl<R: IntoDynamicSend> Drop for SignalFutureResolver<R> {
-    fn drop(&mut self) {
-        let mut data = self.data.lock().unwrap();
-
-        if !matches!(data.state, SignalFutureState::Pending) {
-            // The future is no longer pending, so no clean up is required.
-            return;
-        }
-
-        // We mark the future as dead, so the next time it gets polled we can react to it's inability to resolve.
-        data.state = SignalFutureState::Dead;
+// Only public for itest.
+pub type SignalFutureResolver<R> =
+    SignalResolver<R, SignalFutureData<<R as IntoDynamicSend>::Target>>;
 
-        // If we got a waker we trigger it to get the future polled. If there is no waker, then the future has not been polled yet and we
-        // simply wait for the runtime to perform the first poll.
-        if let Some(ref waker) = data.waker {
-            waker.wake_by_ref();
-        }
-    }
+/// For itest to construct and test a resolver.
+#[cfg(feature = "trace")]
+pub fn create_test_signal_future_resolver<R: IntoDynamicSend>() -> SignalFutureResolver<R> {
+    SignalResolver::new(Arc::default())
 }
 
 #[derive(Default)]
@@ -414,6 +363,96 @@ impl<T: GodotClass> IntoDynamicSend for Gd<T> {
     }
 }
 
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Resolver
+
+/// State shared between a [`SignalResolver`] and the future or stream consuming the emissions of a signal.
+pub trait ResolverState<T>: Send + 'static {
+    /// Stores an emission. Returns the waker of the consumer, if it needs to be woken.
+    fn on_emit(&mut self, value: T) -> Option<Waker>;
+
+    /// Called whenever a copy of the resolver is dropped. Returns the waker of the consumer, if it needs to be woken.
+    ///
+    /// The engine drops its copy once the callable is disconnected, in particular when the signal object is freed. The consumer drops its
+    /// own copy as well as temporary ones (created to disconnect); it marks the state as dropped beforehand, so these can be told apart.
+    fn on_resolver_dropped(&mut self) -> Option<Waker>;
+}
+
+/// Custom callable connected to a signal, which forwards emissions to a [`FallibleSignalFuture`] or [`SignalStream`][super::SignalStream].
+///
+/// Clones share the state, and are equal with the same hash. This allows creating a new Godot `Callable` from a clone, which is equal to
+/// the connected one but has independent reference counting.
+pub struct SignalResolver<R: IntoDynamicSend, S: ResolverState<R::Target>> {
+    data: Arc<Mutex<S>>,
+    _signal_args: PhantomData<fn() -> R>,
+}
+
+impl<R: IntoDynamicSend, S: ResolverState<R::Target>> SignalResolver<R, S> {
+    pub(super) fn new(data: Arc<Mutex<S>>) -> Self {
+        Self {
+            data,
+            _signal_args: PhantomData,
+        }
+    }
+
+    /// Locks the state, ignoring poisoning: the resolver is also locked in `Drop`, which runs inside engine callbacks and must not panic.
+    fn lock(&self) -> MutexGuard<'_, S> {
+        self.data.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<R: IntoDynamicSend, S: ResolverState<R::Target>> Clone for SignalResolver<R, S> {
+    fn clone(&self) -> Self {
+        Self::new(self.data.clone())
+    }
+}
+
+impl<R: IntoDynamicSend, S: ResolverState<R::Target>> std::hash::Hash for SignalResolver<R, S> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.data) as usize);
+    }
+}
+
+impl<R: IntoDynamicSend, S: ResolverState<R::Target>> PartialEq for SignalResolver<R, S> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.data, &other.data)
+    }
+}
+
+impl<R, S> RustCallable for SignalResolver<R, S>
+where
+    R: InParamTuple + IntoDynamicSend,
+    S: ResolverState<R::Target>,
+{
+    fn invoke(&mut self, args: &[&Variant]) -> Result<Variant, ()> {
+        // Convert before locking, so that a panic in the conversion cannot poison the mutex.
+        let value = R::from_variant_array(args).into_dynamic_send();
+        let waker = self.lock().on_emit(value);
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+
+        Ok(Variant::nil())
+    }
+}
+
+impl<R: IntoDynamicSend, S: ResolverState<R::Target>> Display for SignalResolver<R, S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SignalResolver::<{}>", std::any::type_name::<R>())
+    }
+}
+
+impl<R: IntoDynamicSend, S: ResolverState<R::Target>> Drop for SignalResolver<R, S> {
+    fn drop(&mut self) {
+        let waker = self.lock().on_resolver_dropped();
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Generated impls
 
@@ -0,0 +1,172 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::obj::call_deferred_on_main;
+use crate::task::JoinError;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Runs a closure on the main thread, and returns a future resolving to its result.
+///
+/// Can be called from any thread. The closure is deferred to the main thread's idle time, like
+/// [`Callable::call_deferred()`][crate::builtin::Callable::call_deferred], so it runs at the end of the current frame at the earliest --
+/// also if called from the main thread itself. Inside the closure, objects can be accessed as usual.
+///
+/// The returned [`MainThreadFuture`] can be awaited from any executor, e.g. inside [`spawn_worker()`][super::spawn_worker]. To wait
+/// synchronously, use [`run_on_main_blocking()`].
+///
+/// ```no_run
+/// use godot::classes::Engine;
+/// use godot::task;
+///
+/// task::spawn_worker(async {
+///     let frames = task::run_on_main(|| Engine::singleton().get_process_frames()).await;
+///     println!("Main thread is at frame {frames:?}");
+/// });
+/// ```
+pub fn run_on_main<F, T>(f: F) -> MainThreadFuture<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let state = Arc::new(Mutex::new(MainThreadState {
+        result: None,
+        waker: None,
+    }));
+
+    let mut job = MainThreadJob {
+        function: Some(f),
+        state: state.clone(),
+    };
+    call_deferred_on_main("run_on_main", move || job.run());
+
+    MainThreadFuture {
+        state,
+        is_done: false,
+    }
+}
+
+/// Runs a closure on the main thread, and blocks the current thread until its result is available.
+///
+/// Same as [`run_on_main()`], but waits synchronously. Returns `Err` if the closure panicked, or if it could not run because the engine
+/// shut down in the meantime (see [`JoinError::is_shutdown()`]).
+///
+/// # Panics
+/// If called from the main thread, as this would deadlock: the closure could only run after the current frame, which cannot finish while
+/// the main thread is blocked.
+///
+/// Note that this cannot detect indirect deadlocks, e.g. when the main thread itself waits for the calling thread.
+pub fn run_on_main_blocking<F, T>(f: F) -> Result<T, JoinError>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    assert!(
+        !crate::init::is_main_thread(),
+        "run_on_main_blocking() called on the main thread, which would deadlock; call the closure directly instead"
+    );
+
+    super::worker::block_on(run_on_main(f))
+}
+
+/// Future returned by [`run_on_main()`].
+///
+/// Resolves to `Ok(T)` with the closure's result, or to [`Err(JoinError)`][JoinError] if the closure panicked or was never run (because
+/// the engine shut down). Unlike most futures in this module, it is `Send` and can be awaited on any thread.
+pub struct MainThreadFuture<T> {
+    state: Arc<Mutex<MainThreadState<T>>>,
+    is_done: bool,
+}
+
+impl<T> Future for MainThreadFuture<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        assert!(!this.is_done, "MainThreadFuture polled after completion");
+
+        let mut state = this.state.lock().unwrap();
+        match state.result.take() {
+            Some(result) => {
+                this.is_done = true;
+                Poll::Ready(result)
+            }
+            None => {
+                state.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct MainThreadState<T> {
+    result: Option<Result<T, JoinError>>,
+    waker: Option<Waker>,
+}
+
+/// Closure held by the deferred call. If it is dropped before being called (on engine shutdown), the future resolves to an error.
+struct MainThreadJob<F, T> {
+    function: Option<F>,
+    state: Arc<Mutex<MainThreadState<T>>>,
+}
+
+impl<F, T> MainThreadJob<F, T>
+where
+    F: FnOnce() -> T,
+{
+    fn run(&mut self) {
+        let Some(function) = self.function.take() else {
+            return;
+        };
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(function)).map_err(|payload| {
+            JoinError::panicked(crate::private::extract_panic_message(payload.as_ref()))
+        });
+
+        self.finish(result);
+    }
+
+    fn finish(&self, result: Result<T, JoinError>) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            state.result = Some(result);
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<F, T> Drop for MainThreadJob<F, T> {
+    fn drop(&mut self) {
+        if self.function.is_none() {
+            return;
+        }
+
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            state.result = Some(Err(JoinError::shutdown()));
+            state.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
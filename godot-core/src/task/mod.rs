@@ -9,16 +9,35 @@
 //!
 //! This module contains:
 //! - Implementations of [`Future`](std::future::Future) for [`Signal`](crate::builtin::Signal) and [`TypedSignal`](crate::registry::signal::TypedSignal).
+//! - Streams of all emissions of a signal: [`SignalStream`].
 //! - A way to [`spawn`] new async tasks by using the engine as the async runtime.
+//! - Combinators to compose futures: [`join_all`], [`select`] and [`CancellationToken`].
+//! - Off-main-thread work on Godot's `WorkerThreadPool`: [`spawn_blocking`] and [`spawn_worker`].
+//! - Dispatching work back to the main thread from any thread: [`run_on_main`] (requires `experimental-threads`).
+//! - Futures for frames and timers: [`next_frame`], [`next_physics_frame`], [`sleep`] and [`timeout`].
 
 mod async_runtime;
+mod combinators;
 mod futures;
+#[cfg(feature = "experimental-threads")]
+mod main_thread;
+mod stream;
+mod timers;
+mod worker;
 
 // Public re-exports
-pub use async_runtime::{spawn, TaskHandle};
+pub use async_runtime::{spawn, JoinError, JoinHandle, TaskHandle};
+pub use combinators::{join_all, select, CancellationToken, Cancelled, JoinAll, Select};
 pub use futures::{
     DynamicSend, FallibleSignalFuture, FallibleSignalFutureError, IntoDynamicSend, SignalFuture,
 };
+#[cfg(feature = "experimental-threads")]
+pub use main_thread::{run_on_main, run_on_main_blocking, MainThreadFuture};
+pub use stream::{OverflowPolicy, SignalStream, SignalStreamNext};
+pub use timers::{
+    next_frame, next_physics_frame, sleep, timeout, Timeout, TimeoutError, TimerOptions,
+};
+pub use worker::{spawn_blocking, spawn_worker, WorkerHandle};
 
 // For use in integration tests.
 #[cfg(feature = "trace")]
@@ -0,0 +1,282 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{Callable, Signal};
+use crate::meta::InParamTuple;
+use crate::obj::WithSignals;
+use crate::registry::signal::TypedSignal;
+use crate::task::futures::{ResolverState, SignalResolver};
+use crate::task::{DynamicSend, IntoDynamicSend};
+
+/// Default number of emissions buffered by [`TypedSignal::to_stream()`].
+const DEFAULT_CAPACITY: usize = 64;
+
+/// Stream of all emissions of a Godot signal.
+///
+/// Created by [`TypedSignal::to_stream()`] or [`Signal::to_stream()`]. Unlike a [`SignalFuture`][super::SignalFuture], which resolves on
+/// a single emission, the stream stays connected and buffers emissions until they are consumed. Await them one by one with
+/// [`next()`][Self::next]. The stream ends (yields `None`) once the signal object is freed and all buffered emissions have been consumed.
+///
+/// The buffer is bounded. If it is full, emissions are discarded according to the [`OverflowPolicy`]; see
+/// [`overflow_count()`][Self::overflow_count].
+///
+/// With the `futures` crate feature, `SignalStream` implements [`futures_core::Stream`](https://docs.rs/futures-core/latest/futures_core/stream/trait.Stream.html).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Player {
+///     base: Base<RefCounted>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn damaged(amount: u32);
+/// }
+///
+/// let player = Player::new_gd();
+/// let mut damage = player.signals().damaged().to_stream();
+///
+/// godot::task::spawn(async move {
+///     while let Some((amount,)) = damage.next().await {
+///         println!("Took {amount} damage.");
+///     }
+///     println!("Player freed.");
+/// });
+/// ```
+///
+/// # Panics
+/// - If one of the signal arguments is `!Send`, but the signal was emitted on a different thread.
+/// - The stream's `Drop` implementation can cause a non-unwinding panic in rare cases, should the signal object be freed at the same time
+///   as the stream is dropped. Make sure to keep signal objects alive until the stream is dropped, or has ended.
+pub struct SignalStream<R: InParamTuple + IntoDynamicSend> {
+    data: Arc<Mutex<SignalStreamData<R::Target>>>,
+    callable: SignalStreamResolver<R>,
+    signal: Signal,
+}
+
+/// Determines which emissions a [`SignalStream`] discards when its buffer is full.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum OverflowPolicy {
+    /// Discard the oldest buffered emission, to make room for the new one.
+    #[default]
+    DropOldest,
+
+    /// Discard the new emission, keeping the buffer as is.
+    DropNewest,
+}
+
+impl<R: InParamTuple + IntoDynamicSend> SignalStream<R> {
+    fn new(signal: Signal, capacity: usize, overflow: OverflowPolicy) -> Self {
+        assert!(capacity > 0, "SignalStream capacity must be at least 1");
+        debug_assert!(
+            !signal.is_null(),
+            "Failed to create a stream for an invalid Signal!\nEither the signal object was already freed or the signal was not registered in the object before using it.",
+        );
+
+        let data = Arc::new(Mutex::new(SignalStreamData {
+            buffer: VecDeque::new(),
+            capacity,
+            overflow,
+            overflow_count: 0,
+            is_ended: false,
+            is_dropped: false,
+            waker: None,
+        }));
+
+        let callable = SignalStreamResolver::new(data.clone());
+
+        // Not one-shot: the resolver stays connected until the stream is dropped or the signal object is freed.
+        signal.connect(&Callable::from_custom(callable.clone()), 0);
+
+        Self {
+            data,
+            callable,
+            signal,
+        }
+    }
+
+    /// Returns a future resolving to the next emission, or `None` if the stream has ended.
+    pub fn next(&mut self) -> SignalStreamNext<'_, R> {
+        SignalStreamNext { stream: self }
+    }
+
+    /// Polls for the next emission. Prefer [`next()`][Self::next] inside async code.
+    pub fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        let mut data = self.data.lock().unwrap();
+
+        let value = match data.buffer.pop_front() {
+            Some(value) => value,
+            None if data.is_ended => return Poll::Ready(None),
+            None => {
+                data.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+        };
+
+        // Drop the lock before the potential panic, so the mutex isn't poisoned.
+        drop(data);
+
+        let Some(value) = DynamicSend::extract_if_safe(value) else {
+            panic!("the streamed signal was not emitted on the main-thread, but contained a non Send argument");
+        };
+
+        Poll::Ready(Some(value))
+    }
+
+    /// Number of emissions that have been discarded so far, because the buffer was full.
+    pub fn overflow_count(&self) -> usize {
+        self.data.lock().unwrap().overflow_count
+    }
+
+    /// Returns `true` if the signal object has been freed. Buffered emissions may still be available.
+    pub fn is_ended(&self) -> bool {
+        self.data.lock().unwrap().is_ended
+    }
+}
+
+impl<R: InParamTuple + IntoDynamicSend> Drop for SignalStream<R> {
+    fn drop(&mut self) {
+        // The callable might already be destroyed, this occurs during engine shutdown.
+        if self.signal.is_null() {
+            return;
+        }
+
+        // Disconnecting drops the engine's resolver, which must not be mistaken for the object being freed.
+        self.data.lock().unwrap().is_dropped = true;
+
+        // Same approach (and TOCTOU caveat) as in FallibleSignalFuture.
+        let gd_callable = Callable::from_custom(self.callable.clone());
+        if !self.signal.is_null() && self.signal.is_connected(&gd_callable) {
+            self.signal.disconnect(&gd_callable);
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<R: InParamTuple + IntoDynamicSend> futures_core::Stream for SignalStream<R> {
+    type Item = R;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<R>> {
+        self.get_mut().poll_next(cx)
+    }
+}
+
+/// Future returned by [`SignalStream::next()`].
+pub struct SignalStreamNext<'s, R: InParamTuple + IntoDynamicSend> {
+    stream: &'s mut SignalStream<R>,
+}
+
+impl<R: InParamTuple + IntoDynamicSend> Future for SignalStreamNext<'_, R> {
+    type Output = Option<R>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().stream.poll_next(cx)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Resolver
+
+struct SignalStreamData<T> {
+    buffer: VecDeque<T>,
+    capacity: usize,
+    overflow: OverflowPolicy,
+    overflow_count: usize,
+    is_ended: bool,
+    is_dropped: bool,
+    waker: Option<Waker>,
+}
+
+impl<T: Send + 'static> ResolverState<T> for SignalStreamData<T> {
+    fn on_emit(&mut self, value: T) -> Option<Waker> {
+        if self.buffer.len() >= self.capacity {
+            self.overflow_count += 1;
+
+            match self.overflow {
+                OverflowPolicy::DropOldest => {
+                    self.buffer.pop_front();
+                }
+                OverflowPolicy::DropNewest => return None,
+            }
+        }
+
+        self.buffer.push_back(value);
+        self.waker.take()
+    }
+
+    // When the engine drops its copy of the resolver (the signal object was freed), the stream ends.
+    fn on_resolver_dropped(&mut self) -> Option<Waker> {
+        if self.is_dropped || self.is_ended {
+            return None;
+        }
+
+        self.is_ended = true;
+        self.waker.take()
+    }
+}
+
+/// Resolver that buffers every emission, instead of resolving once like the one of `FallibleSignalFuture`.
+type SignalStreamResolver<R> = SignalResolver<R, SignalStreamData<<R as IntoDynamicSend>::Target>>;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Constructors
+
+impl Signal {
+    /// Creates a stream of all emissions of this signal.
+    ///
+    /// Buffers up to 64 emissions, discarding the oldest ones on overflow. See [`SignalStream`] for details, and
+    /// [`to_stream_with()`][Self::to_stream_with] to configure buffering.
+    ///
+    /// Since the `Signal` type does not contain information on the signal argument types, the stream item type has to be inferred from
+    /// the call to this function.
+    pub fn to_stream<R: InParamTuple + IntoDynamicSend>(&self) -> SignalStream<R> {
+        SignalStream::new(self.clone(), DEFAULT_CAPACITY, OverflowPolicy::default())
+    }
+
+    /// Creates a stream of all emissions of this signal, buffering up to `capacity` emissions.
+    ///
+    /// # Panics
+    /// If `capacity` is 0.
+    pub fn to_stream_with<R: InParamTuple + IntoDynamicSend>(
+        &self,
+        capacity: usize,
+        overflow: OverflowPolicy,
+    ) -> SignalStream<R> {
+        SignalStream::new(self.clone(), capacity, overflow)
+    }
+}
+
+impl<C: WithSignals, R: InParamTuple + IntoDynamicSend> TypedSignal<'_, C, R> {
+    /// Creates a stream of all emissions of this signal.
+    ///
+    /// Buffers up to 64 emissions, discarding the oldest ones on overflow. See [`SignalStream`] for details, and
+    /// [`to_stream_with()`][Self::to_stream_with] to configure buffering.
+    pub fn to_stream(&self) -> SignalStream<R> {
+        SignalStream::new(
+            self.to_untyped(),
+            DEFAULT_CAPACITY,
+            OverflowPolicy::default(),
+        )
+    }
+
+    /// Creates a stream of all emissions of this signal, buffering up to `capacity` emissions.
+    ///
+    /// # Panics
+    /// If `capacity` is 0.
+    pub fn to_stream_with(&self, capacity: usize, overflow: OverflowPolicy) -> SignalStream<R> {
+        SignalStream::new(self.to_untyped(), capacity, overflow)
+    }
+}
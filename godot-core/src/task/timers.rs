@@ -0,0 +1,166 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use crate::builtin::Signal;
+use crate::classes::{Engine, SceneTree};
+use crate::obj::Gd;
+use crate::task::SignalFuture;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Future that resolves at the next idle frame (`SceneTree.process_frame` signal).
+///
+/// Equivalent to GDScript `await get_tree().process_frame`.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+pub fn next_frame() -> SignalFuture<()> {
+    Signal::from_object_signal(&scene_tree(), "process_frame").to_future()
+}
+
+/// Future that resolves at the next physics frame (`SceneTree.physics_frame` signal).
+///
+/// Equivalent to GDScript `await get_tree().physics_frame`.
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+pub fn next_physics_frame() -> SignalFuture<()> {
+    Signal::from_object_signal(&scene_tree(), "physics_frame").to_future()
+}
+
+/// Future that resolves after `duration` of game time has passed.
+///
+/// Uses a `SceneTreeTimer`, so the waiting time is measured in frames of the scene tree and behaves according to `options`. With default
+/// options, the timer respects pause and `Engine.time_scale`.
+///
+/// Equivalent to GDScript `await get_tree().create_timer(seconds, ...).timeout`.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use godot::task::{self, TimerOptions};
+///
+/// godot::task::spawn(async {
+///     task::sleep(Duration::from_millis(500), TimerOptions::default()).await;
+///     println!("Half a second later.");
+/// });
+/// ```
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+pub fn sleep(duration: Duration, options: TimerOptions) -> SignalFuture<()> {
+    let TimerOptions {
+        process_always,
+        process_in_physics,
+        ignore_time_scale,
+    } = options;
+
+    let timer = scene_tree()
+        .create_timer_ex(duration.as_secs_f64())
+        .process_always(process_always)
+        .process_in_physics(process_in_physics)
+        .ignore_time_scale(ignore_time_scale)
+        .done()
+        .expect("SceneTree::create_timer() returned null");
+
+    Signal::from_object_signal(&timer, "timeout").to_future()
+}
+
+/// Wraps a future, so that it resolves to `Err(TimeoutError)` if it does not complete within `duration` of game time.
+///
+/// The duration is measured like in [`sleep()`] with default options.
+///
+/// ```no_run
+/// use std::time::Duration;
+/// # use godot::builtin::Signal;
+/// # let signal: Signal = unimplemented!();
+///
+/// godot::task::spawn(async move {
+///     let result = godot::task::timeout(Duration::from_secs(2), signal.to_future::<()>()).await;
+///     if result.is_err() {
+///         println!("Signal not emitted within 2 seconds.");
+///     }
+/// });
+/// ```
+///
+/// # Panics
+/// If the main loop is not a `SceneTree`.
+pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    Timeout {
+        future: Box::pin(future),
+        timer: sleep(duration, TimerOptions::default()),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Types
+
+/// Configures how the timer of [`sleep()`] measures time.
+///
+/// Note that the default differs from Godot's `SceneTree.create_timer()`, which processes timers also while the tree is paused.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TimerOptions {
+    /// If `true`, the timer also advances while the scene tree is paused.
+    pub process_always: bool,
+
+    /// If `true`, the timer is updated at the end of physics frames instead of idle frames.
+    pub process_in_physics: bool,
+
+    /// If `true`, the timer ignores `Engine.time_scale` and advances in real time.
+    pub ignore_time_scale: bool,
+}
+
+/// Future returned by [`timeout()`].
+pub struct Timeout<F: Future> {
+    future: Pin<Box<F>>,
+    timer: SignalFuture<()>,
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, TimeoutError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if let Poll::Ready(value) = this.future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(value));
+        }
+
+        match Pin::new(&mut this.timer).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(TimeoutError)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Error returned by a [`timeout()`] future, if the inner future did not complete in time.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct TimeoutError;
+
+impl Display for TimeoutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "future did not complete before the timeout")
+    }
+}
+
+impl std::error::Error for TimeoutError {}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+fn scene_tree() -> Gd<SceneTree> {
+    Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>().ok())
+        .expect("task timers require the main loop to be a SceneTree")
+}
@@ -0,0 +1,278 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use crate::builtin::{Callable, RustCallable, Variant};
+use crate::classes::WorkerThreadPool;
+use crate::task::JoinError;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Public interface
+
+/// Runs a blocking closure on Godot's [`WorkerThreadPool`], and returns a handle to await its result on the main thread.
+///
+/// Use this for CPU-heavy work such as pathfinding or procedural generation, which would otherwise stall the frame. The closure must be
+/// `Send`, and so must its result. Objects (`Gd<T>`) should generally not be accessed from the closure; pass plain data in and out instead.
+///
+/// The returned [`WorkerHandle`] can be awaited inside an async task (see [`spawn()`][super::spawn]). It resolves on the main thread,
+/// at the earliest at the end of the frame in which the closure finished.
+///
+/// ```no_run
+/// use godot::task;
+///
+/// fn expensive_path(from: i32, to: i32) -> Vec<i32> {
+///     (from..=to).collect()
+/// }
+///
+/// task::spawn(async {
+///     let path = task::spawn_blocking(|| expensive_path(0, 100)).await;
+///     println!("Path found: {:?}", path.expect("path finding panicked"));
+/// });
+/// ```
+///
+/// # Panics
+/// If called from any other thread than the main thread.
+pub fn spawn_blocking<F, T>(work: F) -> WorkerHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    start_worker("spawn_blocking", work)
+}
+
+/// Runs a `Send` future to completion on Godot's [`WorkerThreadPool`], and returns a handle to await its output on the main thread.
+///
+/// The future occupies one worker thread until it completes, blocking the thread while it waits. This is meant for futures that mostly
+/// compute and only occasionally wait, e.g. for other worker results. Godot signals cannot be awaited on worker threads.
+///
+/// See [`spawn_blocking()`] for details on the returned handle.
+///
+/// # Panics
+/// If called from any other thread than the main thread.
+pub fn spawn_worker<F>(future: F) -> WorkerHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    start_worker("spawn_worker", move || block_on(future))
+}
+
+/// Handle to work running on the [`WorkerThreadPool`], returned by [`spawn_blocking()`] and [`spawn_worker()`].
+///
+/// Awaiting the handle yields `Ok(T)` with the result of the work, or [`Err(JoinError)`][JoinError] if the work panicked.
+///
+/// Work that has started cannot be canceled. If the handle is dropped, the work still runs to completion, but its result is discarded.
+pub struct WorkerHandle<T> {
+    state: Arc<Mutex<WorkerState<T>>>,
+}
+
+impl<T> WorkerHandle<T> {
+    /// Returns `true` if the result has been delivered to the main thread, i.e. awaiting the handle would complete immediately.
+    pub fn is_finished(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.is_delivered && state.result.is_some()
+    }
+}
+
+impl<T> Future for WorkerHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        // Only resolve once delivered, so that the result is observed on the main thread.
+        if state.is_delivered {
+            if let Some(result) = state.result.take() {
+                return Poll::Ready(result);
+            }
+
+            panic!("WorkerHandle polled after completion");
+        }
+
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct WorkerState<T> {
+    result: Option<Result<T, JoinError>>,
+    waker: Option<Waker>,
+    task_id: Option<i64>,
+
+    // Set on the main thread, after the worker has stored the result.
+    is_delivered: bool,
+}
+
+fn start_worker<F, T>(name: &'static str, work: F) -> WorkerHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    assert!(
+        crate::init::is_main_thread(),
+        "task::{name}() can only be used on the main thread"
+    );
+
+    let state = Arc::new(Mutex::new(WorkerState {
+        result: None,
+        waker: None,
+        task_id: None,
+        is_delivered: false,
+    }));
+
+    let job_state = state.clone();
+    let job = move || {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(work)).map_err(|payload| {
+            JoinError::panicked(crate::private::extract_panic_message(payload.as_ref()))
+        });
+
+        job_state.lock().unwrap().result = Some(result);
+
+        // Hand over to the main thread through its message queue, like GodotWaker does.
+        Callable::from_custom(WorkerDelivery {
+            name,
+            state: job_state,
+        })
+        .call_deferred(&[]);
+    };
+
+    let job: Box<dyn FnOnce() + Send> = Box::new(job);
+    let action = Callable::from_custom(WorkerJob {
+        name,
+        job: Arc::new(Mutex::new(Some(job))),
+    });
+
+    // Lock while adding the task, so that `WorkerDelivery` cannot observe the state before the ID is stored.
+    let mut locked = state.lock().unwrap();
+    let task_id = WorkerThreadPool::singleton()
+        .add_task_ex(&action)
+        .description(name)
+        .done();
+    locked.task_id = Some(task_id);
+    drop(locked);
+
+    WorkerHandle { state }
+}
+
+/// Runs on the main thread once the work has finished: releases the pool task and wakes the awaiting task.
+///
+/// Created on the worker thread and deferred to the main thread. Only holds the shared state, so it's `Send` without further guarantees.
+struct WorkerDelivery<T> {
+    name: &'static str,
+    state: Arc<Mutex<WorkerState<T>>>,
+}
+
+impl<T: Send + 'static> RustCallable for WorkerDelivery<T> {
+    fn invoke(&mut self, _args: &[&Variant]) -> Result<Variant, ()> {
+        let (task_id, waker) = {
+            let mut state = self.state.lock().unwrap();
+            state.is_delivered = true;
+            (state.task_id.take(), state.waker.take())
+        };
+
+        // Every pool task must be waited for, to release its resources. The work is done, so this returns immediately.
+        if let Some(task_id) = task_id {
+            WorkerThreadPool::singleton().wait_for_task_completion(task_id);
+        }
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+
+        Ok(Variant::nil())
+    }
+}
+
+impl<T> PartialEq for WorkerDelivery<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl<T> Hash for WorkerDelivery<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.state) as *const () as usize);
+    }
+}
+
+impl<T> fmt::Display for WorkerDelivery<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "task::{}::deliver", self.name)
+    }
+}
+
+/// Work executed by Godot on a pool thread.
+struct WorkerJob {
+    name: &'static str,
+
+    // Arc for identity in PartialEq/Hash; Mutex makes the FnOnce callable through `&mut self` on any thread.
+    #[allow(clippy::type_complexity)]
+    job: Arc<Mutex<Option<Box<dyn FnOnce() + Send>>>>,
+}
+
+impl RustCallable for WorkerJob {
+    fn invoke(&mut self, _args: &[&Variant]) -> Result<Variant, ()> {
+        let job = self.job.lock().unwrap().take();
+
+        if let Some(job) = job {
+            job();
+        }
+
+        Ok(Variant::nil())
+    }
+}
+
+impl PartialEq for WorkerJob {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.job, &other.job)
+    }
+}
+
+impl Hash for WorkerJob {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.job) as *const () as usize);
+    }
+}
+
+impl fmt::Display for WorkerJob {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "task::{}", self.name)
+    }
+}
+
+/// Minimal executor for running a future on the current (worker) thread.
+pub(super) fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut ctx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut ctx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
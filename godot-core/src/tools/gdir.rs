@@ -0,0 +1,478 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::ErrorKind;
+
+use crate::builtin::GString;
+use crate::classes::{DirAccess, FileAccess};
+use crate::global::Error;
+use crate::meta::{arg_into_ref, AsArg};
+use crate::obj::Gd;
+
+/// Open a directory for listing its contents.
+///
+/// This is a wrapper around a [`DirAccess`] pointer, in the spirit of [`GFile`][super::GFile]:
+///
+/// - Listing a directory returns an iterator of [`DirEntry`] values, instead of driving `list_dir_begin()` and `get_next()` by hand.
+///   The listing is ended automatically once the iterator is dropped.
+/// - [`walk()`](Self::walk) recursively walks all subdirectories, optionally limited in depth or filtered.
+///   [`glob()`](Self::glob) finds entries whose path matches a pattern.
+/// - Associated functions like [`create_dir_all()`](Self::create_dir_all), [`remove_dir_all()`](Self::remove_dir_all),
+///   [`copy()`](Self::copy) and [`rename()`](Self::rename) mirror their `std::fs` counterparts.
+/// - All fallible operations return [`std::io::Result`]. Godot errors are mapped to the closest [`ErrorKind`], e.g.
+///   `ERR_FILE_NOT_FOUND` to [`ErrorKind::NotFound`].
+///
+/// Like `DirAccess`, paths can be absolute (`res://`, `user://` or file system paths), or relative to the opened directory.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use godot::tools::GDir;
+///
+/// fn list_levels() -> std::io::Result<()> {
+///     let mut dir = GDir::open("res://levels")?;
+///
+///     // Direct children.
+///     for entry in dir.read_dir()? {
+///         println!("{} (directory: {})", entry.name(), entry.is_dir());
+///     }
+///
+///     // All scenes in the directory and its subdirectories.
+///     for entry in dir.glob("*.tscn") {
+///         println!("Scene: {}", entry?.path());
+///     }
+///
+///     Ok(())
+/// }
+///
+/// fn reset_saves() -> std::io::Result<()> {
+///     GDir::remove_dir_all("user://saves")?;
+///     GDir::create_dir_all("user://saves/slot1")
+/// }
+/// ```
+///
+/// ## See also
+///
+/// - [`DirAccess`] class in Rust.
+/// - [Godot documentation](https://docs.godotengine.org/en/stable/classes/class_diraccess.html) for `DirAccess`.
+pub struct GDir {
+    da: Gd<DirAccess>,
+}
+
+impl GDir {
+    /// Open a directory.
+    pub fn open(path: impl AsArg<GString>) -> std::io::Result<Self> {
+        arg_into_ref!(path);
+
+        let da = DirAccess::open(path).ok_or_else(|| {
+            io_error(
+                DirAccess::get_open_error(),
+                format_args!("can't open directory {path}"),
+            )
+        })?;
+
+        Ok(Self { da })
+    }
+
+    /// Path of the opened directory.
+    #[doc(alias = "get_current_dir")]
+    pub fn path(&self) -> GString {
+        self.da.get_current_dir()
+    }
+
+    /// Whether hidden entries are included when listing. Defaults to `false`.
+    #[doc(alias = "get_include_hidden")]
+    pub fn include_hidden(&self) -> bool {
+        self.da.get_include_hidden()
+    }
+
+    /// Sets whether hidden entries are included when listing, see [`include_hidden()`](Self::include_hidden).
+    pub fn set_include_hidden(&mut self, include: bool) {
+        self.da.set_include_hidden(include);
+    }
+
+    /// Lists the direct children of the directory, excluding `.` and `..`.
+    ///
+    /// Entries are returned in the order provided by the file system. Use [`walk()`](Self::walk) for sorted, recursive listing.
+    #[doc(alias = "list_dir_begin")]
+    pub fn read_dir(&mut self) -> std::io::Result<ReadDir<'_>> {
+        let base = self.path();
+
+        self.da.set_include_navigational(false);
+        check_error(
+            self.da.list_dir_begin(),
+            format_args!("can't list directory {base}"),
+        )?;
+
+        Ok(ReadDir {
+            da: &mut self.da,
+            base,
+            is_finished: false,
+        })
+    }
+
+    /// Recursively walks the directory and all its subdirectories.
+    ///
+    /// Entries of each directory are sorted by name, and yielded before the entries of their subdirectories. The directory itself is
+    /// not included. Configure the walk with [`Walk::max_depth()`] and [`Walk::filter_entry()`].
+    pub fn walk(&self) -> Walk {
+        Walk::new(self.path(), self.include_hidden())
+    }
+
+    /// Recursively finds all entries whose path, relative to this directory, matches `pattern`.
+    ///
+    /// Patterns follow Godot's [`String.match()`](https://docs.godotengine.org/en/stable/classes/class_string.html#class-string-method-match)
+    /// rules: `*` matches any sequence of characters (including `/`), `?` matches a single character. For example, `*.tscn` matches all
+    /// scenes in all subdirectories, while `enemies/*.tres` only matches resources below the `enemies` subdirectory.
+    pub fn glob(&self, pattern: impl AsArg<GString>) -> Glob {
+        arg_into_ref!(pattern);
+
+        Glob {
+            walk: self.walk(),
+            pattern: pattern.clone(),
+        }
+    }
+
+    /// Retrieve inner pointer to the [`DirAccess`].
+    pub fn into_inner(self) -> Gd<DirAccess> {
+        self.da
+    }
+
+    // ----------------------------------------------------------------------------------------------------------------------------------------------
+    // Associated functions, operating on paths.
+
+    /// Returns `true` if a directory exists at `path`.
+    #[doc(alias = "dir_exists_absolute")]
+    pub fn exists(path: impl AsArg<GString>) -> bool {
+        DirAccess::dir_exists_absolute(path)
+    }
+
+    /// Creates a new, empty directory. The parent directory must exist.
+    #[doc(alias = "make_dir_absolute")]
+    pub fn create_dir(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        check_error(
+            DirAccess::make_dir_absolute(path),
+            format_args!("can't create directory {path}"),
+        )
+    }
+
+    /// Creates a directory, including all missing parent directories. Succeeds if the directory already exists.
+    #[doc(alias = "make_dir_recursive_absolute")]
+    pub fn create_dir_all(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        check_error(
+            DirAccess::make_dir_recursive_absolute(path),
+            format_args!("can't create directory {path}"),
+        )
+    }
+
+    /// Removes a file or an empty directory.
+    #[doc(alias = "remove_absolute")]
+    pub fn remove(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        check_error(
+            DirAccess::remove_absolute(path),
+            format_args!("can't remove {path}"),
+        )
+    }
+
+    /// Removes a directory with all its contents, including hidden entries.
+    pub fn remove_dir_all(path: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(path);
+
+        for entry in read_dir_sorted(path, true, 1)? {
+            if entry.is_dir() {
+                Self::remove_dir_all(entry.path())?;
+            } else {
+                Self::remove(entry.path())?;
+            }
+        }
+
+        Self::remove(path)
+    }
+
+    /// Copies a file. If `to` exists, it is overwritten.
+    #[doc(alias = "copy_absolute")]
+    pub fn copy(from: impl AsArg<GString>, to: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(from);
+        arg_into_ref!(to);
+
+        if !FileAccess::file_exists(from) {
+            return Err(io_error(
+                Error::ERR_FILE_NOT_FOUND,
+                format_args!("can't copy {from} to {to}: not a file"),
+            ));
+        }
+
+        check_error(
+            DirAccess::copy_absolute(from, to),
+            format_args!("can't copy {from} to {to}"),
+        )
+    }
+
+    /// Renames or moves a file or directory. If `to` is an existing file, it is overwritten.
+    #[doc(alias = "rename_absolute")]
+    pub fn rename(from: impl AsArg<GString>, to: impl AsArg<GString>) -> std::io::Result<()> {
+        arg_into_ref!(from);
+        arg_into_ref!(to);
+
+        check_error(
+            DirAccess::rename_absolute(from, to),
+            format_args!("can't rename {from} to {to}"),
+        )
+    }
+}
+
+impl fmt::Debug for GDir {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GDir").field("path", &self.path()).finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Entries and iterators
+
+/// Entry of a directory, returned by [`GDir`] iterators.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DirEntry {
+    name: GString,
+    path: GString,
+    is_dir: bool,
+    depth: usize,
+}
+
+impl DirEntry {
+    /// File or directory name, without path.
+    pub fn name(&self) -> &GString {
+        &self.name
+    }
+
+    /// Full path of the entry.
+    pub fn path(&self) -> &GString {
+        &self.path
+    }
+
+    /// Returns `true` if the entry is a directory.
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+
+    /// Returns `true` if the entry is a file (i.e. not a directory).
+    pub fn is_file(&self) -> bool {
+        !self.is_dir
+    }
+
+    /// Nesting level relative to the listed directory: 1 for direct children, 2 for their children, and so on.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+/// Iterator over the direct children of a directory, returned by [`GDir::read_dir()`].
+pub struct ReadDir<'a> {
+    da: &'a mut Gd<DirAccess>,
+    base: GString,
+    is_finished: bool,
+}
+
+impl Iterator for ReadDir<'_> {
+    type Item = DirEntry;
+
+    fn next(&mut self) -> Option<DirEntry> {
+        if self.is_finished {
+            return None;
+        }
+
+        let name = self.da.get_next();
+        if name.is_empty() {
+            self.is_finished = true;
+            self.da.list_dir_end();
+            return None;
+        }
+
+        Some(DirEntry {
+            path: join_path(&self.base, &name),
+            name,
+            is_dir: self.da.current_is_dir(),
+            depth: 1,
+        })
+    }
+}
+
+impl Drop for ReadDir<'_> {
+    fn drop(&mut self) {
+        if !self.is_finished {
+            self.da.list_dir_end();
+        }
+    }
+}
+
+/// Recursive directory iterator, returned by [`GDir::walk()`].
+///
+/// Yields `Err` for subdirectories that cannot be listed, and continues with the remaining ones.
+pub struct Walk {
+    pending_dirs: Vec<(GString, usize)>,
+    entries: VecDeque<DirEntry>,
+    include_hidden: bool,
+    max_depth: usize,
+    filter: Option<Box<dyn FnMut(&DirEntry) -> bool>>,
+}
+
+impl Walk {
+    fn new(root: GString, include_hidden: bool) -> Self {
+        Self {
+            pending_dirs: vec![(root, 1)],
+            entries: VecDeque::new(),
+            include_hidden,
+            max_depth: usize::MAX,
+            filter: None,
+        }
+    }
+
+    /// Only yields entries up to the given [depth](DirEntry::depth); 1 only lists the direct children.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = depth;
+        self
+    }
+
+    /// Skips entries for which `predicate` returns `false`. Skipped directories are not descended into.
+    pub fn filter_entry(mut self, predicate: impl FnMut(&DirEntry) -> bool + 'static) -> Self {
+        self.filter = Some(Box::new(predicate));
+        self
+    }
+}
+
+impl Iterator for Walk {
+    type Item = std::io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(entry) = self.entries.pop_front() {
+                return Some(Ok(entry));
+            }
+
+            // Depth-first: continue with the most recently found directory.
+            let (dir, depth) = self.pending_dirs.pop()?;
+            if depth > self.max_depth {
+                continue;
+            }
+
+            let mut entries = match read_dir_sorted(&dir, self.include_hidden, depth) {
+                Ok(entries) => entries,
+                Err(err) => return Some(Err(err)),
+            };
+
+            if let Some(filter) = self.filter.as_mut() {
+                entries.retain(|entry| filter(entry));
+            }
+
+            // Push in reverse, so that subdirectories are popped in name order.
+            let subdirs = entries.iter().rev().filter(|entry| entry.is_dir);
+            self.pending_dirs
+                .extend(subdirs.map(|entry| (entry.path.clone(), depth + 1)));
+
+            self.entries = entries.into();
+        }
+    }
+}
+
+/// Iterator over entries matching a pattern, returned by [`GDir::glob()`].
+pub struct Glob {
+    walk: Walk,
+    pattern: GString,
+}
+
+impl Iterator for Glob {
+    type Item = std::io::Result<DirEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entry = match self.walk.next()? {
+                Ok(entry) => entry,
+                Err(err) => return Some(Err(err)),
+            };
+
+            if relative_path(&entry).match_glob(&self.pattern) {
+                return Some(Ok(entry));
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+/// Lists a directory, sorted by name.
+fn read_dir_sorted(
+    path: &GString,
+    include_hidden: bool,
+    depth: usize,
+) -> std::io::Result<Vec<DirEntry>> {
+    let mut dir = GDir::open(path)?;
+    dir.set_include_hidden(include_hidden);
+
+    let mut entries: Vec<DirEntry> = dir
+        .read_dir()?
+        .map(|entry| DirEntry { depth, ..entry })
+        .collect();
+
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(entries)
+}
+
+fn join_path(base: &GString, name: &GString) -> GString {
+    let base = base.to_string();
+
+    if base.ends_with('/') {
+        GString::from(format!("{base}{name}"))
+    } else {
+        GString::from(format!("{base}/{name}"))
+    }
+}
+
+/// Path relative to the walked directory, reconstructed from the entry's last `depth` path components.
+fn relative_path(entry: &DirEntry) -> GString {
+    let path = entry.path.to_string();
+    let components: Vec<&str> = path.rsplitn(entry.depth + 1, '/').collect();
+
+    let relative = components[..entry.depth]
+        .iter()
+        .rev()
+        .copied()
+        .collect::<Vec<_>>()
+        .join("/");
+
+    GString::from(relative)
+}
+
+fn check_error(error: Error, context: fmt::Arguments) -> std::io::Result<()> {
+    if error == Error::OK {
+        Ok(())
+    } else {
+        Err(io_error(error, context))
+    }
+}
+
+fn io_error(error: Error, context: fmt::Arguments) -> std::io::Error {
+    let kind = match error {
+        Error::ERR_FILE_NOT_FOUND | Error::ERR_FILE_BAD_PATH | Error::ERR_DOES_NOT_EXIST => {
+            ErrorKind::NotFound
+        }
+        Error::ERR_FILE_NO_PERMISSION | Error::ERR_UNAUTHORIZED => ErrorKind::PermissionDenied,
+        Error::ERR_ALREADY_EXISTS | Error::ERR_FILE_ALREADY_IN_USE => ErrorKind::AlreadyExists,
+        Error::ERR_INVALID_PARAMETER => ErrorKind::InvalidInput,
+        _ => ErrorKind::Other,
+    };
+
+    std::io::Error::new(kind, format!("{context}; GodotError: {error:?}"))
+}
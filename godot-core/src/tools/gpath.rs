@@ -0,0 +1,446 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+
+use crate::builtin::GString;
+use crate::classes::ProjectSettings;
+use crate::meta::{AsArg, CowArg};
+
+/// Borrowed path in Godot's virtual file system, such as `res://levels/intro.tscn`.
+///
+/// `GPath` is to [`GPathBuf`] what [`std::path::Path`] is to [`std::path::PathBuf`]: an unsized slice of a path, typically used behind a
+/// reference. Unlike `std::path`, it understands Godot's path schemes:
+/// - `res://` for project resources,
+/// - `user://` for user data,
+/// - `uid://` for resource UIDs, which are opaque: they have no parent, file name or extension.
+///
+/// Absolute file system paths (`/home/...`, `C:/...`) and paths relative to a directory are supported as well. Godot always uses `/` as
+/// separator, also on Windows.
+///
+/// Path manipulation like [`join()`](Self::join), [`parent()`](Self::parent) or [`extension()`](Self::extension) is implemented in pure Rust
+/// and does not access the engine or file system. Conversion between `res://`/`user://` and file system paths happens through
+/// [`globalize()`](Self::globalize) and [`localize()`](Self::localize).
+///
+/// `&GPath` and `&GPathBuf` can be passed to all APIs taking `impl AsArg<GString>`, for example [`GFile::open()`][super::GFile::open],
+/// [`load()`][super::load] or [`save()`][super::save].
+///
+/// # Example
+/// ```no_run
+/// use godot::classes::PackedScene;
+/// use godot::tools::{load, GPath};
+///
+/// let levels = GPath::new("res://levels");
+/// let intro = levels.join("intro.tscn");
+/// assert_eq!(intro.as_str(), "res://levels/intro.tscn");
+/// assert_eq!(intro.file_stem(), Some("intro"));
+/// assert_eq!(intro.extension(), Some("tscn"));
+/// assert_eq!(intro.parent(), Some(levels));
+///
+/// let scene = load::<PackedScene>(&intro);
+/// ```
+#[derive(Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct GPath {
+    inner: str,
+}
+
+impl GPath {
+    /// Borrows a string slice as a path. Does not allocate.
+    pub fn new<S: AsRef<str> + ?Sized>(path: &S) -> &GPath {
+        let path: &str = path.as_ref();
+
+        // SAFETY: GPath is a repr(transparent) wrapper around str, so the pointer casts preserve layout and metadata (length).
+        unsafe { &*(path as *const str as *const GPath) }
+    }
+
+    /// The path as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    /// Converts to an owned [`GPathBuf`].
+    pub fn to_gpath_buf(&self) -> GPathBuf {
+        GPathBuf::from(self.as_str())
+    }
+
+    /// Converts to a Godot string.
+    pub fn to_gstring(&self) -> GString {
+        GString::from(self.as_str())
+    }
+
+    /// Scheme of the path without `://`, e.g. `"res"` for `res://icon.svg`. `None` for file system and relative paths.
+    pub fn scheme(&self) -> Option<&str> {
+        self.inner
+            .split_once("://")
+            .map(|(scheme, _)| scheme)
+            .filter(|scheme| !scheme.is_empty() && !scheme.contains('/'))
+    }
+
+    /// Returns `true` for `res://` paths.
+    pub fn is_resource_path(&self) -> bool {
+        self.scheme() == Some("res")
+    }
+
+    /// Returns `true` for `user://` paths.
+    pub fn is_user_path(&self) -> bool {
+        self.scheme() == Some("user")
+    }
+
+    /// Returns `true` for `uid://` paths.
+    pub fn is_uid(&self) -> bool {
+        self.scheme() == Some("uid")
+    }
+
+    /// Returns `true` if the path has a scheme or is an absolute file system path.
+    pub fn is_absolute(&self) -> bool {
+        !self.root().is_empty()
+    }
+
+    /// Returns `true` if the path is relative to some directory; the opposite of [`is_absolute()`](Self::is_absolute).
+    pub fn is_relative(&self) -> bool {
+        !self.is_absolute()
+    }
+
+    /// Appends `path`, separated by `/`. If `path` is absolute, it replaces the current path.
+    ///
+    /// `res://` joined with `icon.svg` gives `res://icon.svg` -- no separator is added after a trailing `/`.
+    pub fn join(&self, path: impl AsRef<GPath>) -> GPathBuf {
+        let mut buf = self.to_gpath_buf();
+        buf.push(path);
+        buf
+    }
+
+    /// Path without its last component, or `None` if the path is a root (like `res://` or `/`), empty, or a UID.
+    ///
+    /// The parent of a single-component relative path is the empty path, like in `std::path`.
+    pub fn parent(&self) -> Option<&GPath> {
+        if self.is_uid() {
+            return None;
+        }
+
+        let root_len = self.root().len();
+        let rest = self.inner[root_len..].trim_end_matches('/');
+        if rest.is_empty() {
+            return None;
+        }
+
+        let parent_len = match rest.rfind('/') {
+            Some(pos) => root_len + rest[..pos].trim_end_matches('/').len(),
+            None => root_len,
+        };
+
+        Some(GPath::new(&self.inner[..parent_len]))
+    }
+
+    /// Last component of the path, or `None` if the path is a root, ends in `.` or `..`, or is a UID.
+    pub fn file_name(&self) -> Option<&str> {
+        if self.is_uid() {
+            return None;
+        }
+
+        let rest = self.inner[self.root().len()..].trim_end_matches('/');
+        let name = match rest.rfind('/') {
+            Some(pos) => &rest[pos + 1..],
+            None => rest,
+        };
+
+        match name {
+            "" | "." | ".." => None,
+            name => Some(name),
+        }
+    }
+
+    /// File name without its extension. Like in `std::path`, a leading `.` does not start an extension, so the stem of `.import` is `.import`.
+    pub fn file_stem(&self) -> Option<&str> {
+        self.file_name()
+            .map(|name| split_extension(name).map_or(name, |(stem, _)| stem))
+    }
+
+    /// Extension of the file name without `.`, e.g. `"tscn"` for `res://main.tscn`. Only the last extension is returned for `.tar.gz`.
+    pub fn extension(&self) -> Option<&str> {
+        self.file_name()
+            .and_then(split_extension)
+            .map(|(_, extension)| extension)
+    }
+
+    /// Path with the extension replaced by `extension`, or added if there was none. An empty `extension` removes the existing one.
+    ///
+    /// Paths without file name are returned unchanged.
+    pub fn with_extension(&self, extension: &str) -> GPathBuf {
+        let mut buf = self.to_gpath_buf();
+        buf.set_extension(extension);
+        buf
+    }
+
+    /// Converts a `res://` or `user://` path to an absolute file system path, using `ProjectSettings.globalize_path()`.
+    ///
+    /// Other paths are returned unchanged. Note that `res://` paths cannot be globalized in exported projects, as resources are stored
+    /// in a pack file.
+    pub fn globalize(&self) -> GPathBuf {
+        let path = ProjectSettings::singleton().globalize_path(self);
+        GPathBuf::from(path)
+    }
+
+    /// Converts an absolute file system path inside the project or user directory to a `res://` or `user://` path, using
+    /// `ProjectSettings.localize_path()`.
+    ///
+    /// Paths outside of these directories are returned unchanged.
+    pub fn localize(&self) -> GPathBuf {
+        let path = ProjectSettings::singleton().localize_path(self);
+        GPathBuf::from(path)
+    }
+
+    // ----------------------------------------------------------------------------------------------------------------------------------------------
+    // Private methods.
+
+    /// Prefix that makes the path absolute: `res://`, `/`, `C:/`, or empty for relative paths.
+    fn root(&self) -> &str {
+        let path = &self.inner;
+
+        if self.scheme().is_some() {
+            let end = path.find("://").unwrap() + 3;
+            &path[..end]
+        } else if path.starts_with('/') {
+            &path[..1]
+        } else if is_windows_drive(path) {
+            &path[..3]
+        } else {
+            ""
+        }
+    }
+}
+
+impl fmt::Display for GPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl fmt::Debug for GPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl ToOwned for GPath {
+    type Owned = GPathBuf;
+
+    fn to_owned(&self) -> GPathBuf {
+        self.to_gpath_buf()
+    }
+}
+
+impl AsRef<GPath> for GPath {
+    fn as_ref(&self) -> &GPath {
+        self
+    }
+}
+
+impl AsRef<GPath> for str {
+    fn as_ref(&self) -> &GPath {
+        GPath::new(self)
+    }
+}
+
+impl AsRef<GPath> for String {
+    fn as_ref(&self) -> &GPath {
+        GPath::new(self)
+    }
+}
+
+impl AsRef<str> for GPath {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&GPath> for GString {
+    fn from(path: &GPath) -> Self {
+        path.to_gstring()
+    }
+}
+
+impl AsArg<GString> for &GPath {
+    fn into_arg<'r>(self) -> CowArg<'r, GString> {
+        CowArg::Owned(self.to_gstring())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Owned path in Godot's virtual file system.
+///
+/// The owned counterpart of [`GPath`], which it dereferences to. Modify it in place with [`push()`](Self::push), [`pop()`](Self::pop) and
+/// [`set_extension()`](Self::set_extension).
+#[derive(Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct GPathBuf {
+    inner: String,
+}
+
+impl GPathBuf {
+    /// Creates an empty path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Borrows as [`GPath`].
+    pub fn as_path(&self) -> &GPath {
+        GPath::new(&self.inner)
+    }
+
+    /// Converts into the underlying string.
+    pub fn into_string(self) -> String {
+        self.inner
+    }
+
+    /// Appends `path`, separated by `/`. If `path` is absolute, it replaces the current path. See [`GPath::join()`].
+    pub fn push(&mut self, path: impl AsRef<GPath>) {
+        let path = path.as_ref();
+
+        if path.is_absolute() || self.inner.is_empty() {
+            self.inner.clear();
+        } else if !self.inner.ends_with('/') {
+            self.inner.push('/');
+        }
+
+        self.inner.push_str(path.as_str());
+    }
+
+    /// Removes the last component. Returns `false` and leaves the path unchanged if there is no [parent](GPath::parent).
+    pub fn pop(&mut self) -> bool {
+        match self.parent().map(|parent| parent.as_str().len()) {
+            Some(len) => {
+                self.inner.truncate(len);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replaces the extension, or adds one if there was none. See [`GPath::with_extension()`].
+    ///
+    /// Returns `false` and leaves the path unchanged if there is no file name.
+    pub fn set_extension(&mut self, extension: &str) -> bool {
+        let Some(stem) = self.file_stem() else {
+            return false;
+        };
+
+        // The stem is a subslice of the path; everything after it (extension and trailing slashes) is replaced.
+        let stem_end = stem.as_ptr() as usize - self.inner.as_ptr() as usize + stem.len();
+        self.inner.truncate(stem_end);
+
+        if !extension.is_empty() {
+            self.inner.push('.');
+            self.inner.push_str(extension);
+        }
+
+        true
+    }
+}
+
+impl Deref for GPathBuf {
+    type Target = GPath;
+
+    fn deref(&self) -> &GPath {
+        self.as_path()
+    }
+}
+
+impl Borrow<GPath> for GPathBuf {
+    fn borrow(&self) -> &GPath {
+        self.as_path()
+    }
+}
+
+impl AsRef<GPath> for GPathBuf {
+    fn as_ref(&self) -> &GPath {
+        self.as_path()
+    }
+}
+
+impl AsRef<str> for GPathBuf {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for GPathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_path(), f)
+    }
+}
+
+impl fmt::Debug for GPathBuf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_path(), f)
+    }
+}
+
+impl From<&str> for GPathBuf {
+    fn from(path: &str) -> Self {
+        Self {
+            inner: path.to_string(),
+        }
+    }
+}
+
+impl From<String> for GPathBuf {
+    fn from(path: String) -> Self {
+        Self { inner: path }
+    }
+}
+
+impl From<&GPath> for GPathBuf {
+    fn from(path: &GPath) -> Self {
+        path.to_gpath_buf()
+    }
+}
+
+impl From<GString> for GPathBuf {
+    fn from(path: GString) -> Self {
+        Self::from(&path)
+    }
+}
+
+impl From<&GString> for GPathBuf {
+    fn from(path: &GString) -> Self {
+        Self {
+            inner: path.to_string(),
+        }
+    }
+}
+
+impl From<&GPathBuf> for GString {
+    fn from(path: &GPathBuf) -> Self {
+        path.to_gstring()
+    }
+}
+
+impl AsArg<GString> for &GPathBuf {
+    fn into_arg<'r>(self) -> CowArg<'r, GString> {
+        CowArg::Owned(self.to_gstring())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+/// Splits `name` into stem and extension, if it has one.
+fn split_extension(name: &str) -> Option<(&str, &str)> {
+    match name.rsplit_once('.') {
+        Some(("", _)) | None => None,
+        Some(split) => Some(split),
+    }
+}
+
+fn is_windows_drive(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    bytes.len() >= 3 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' && bytes[2] == b'/'
+}
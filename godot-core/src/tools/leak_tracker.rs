@@ -0,0 +1,246 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+use std::fmt;
+use std::panic::Location;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use godot_ffi as sys;
+use sys::Global;
+
+use crate::meta::ClassName;
+use crate::obj::InstanceId;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static TRACKER: Global<Tracker> = Global::default();
+
+/// Where and as what an object has been created.
+#[derive(Copy, Clone)]
+struct Creation {
+    class_name: ClassName,
+    location: Option<&'static Location<'static>>,
+}
+
+#[derive(Default)]
+struct Tracker {
+    creations: HashMap<InstanceId, Creation>,
+
+    // Dead objects are only removed when the map reaches this size, to keep recording cheap.
+    prune_at: usize,
+}
+
+impl Tracker {
+    fn prune_dead(&mut self) {
+        self.creations.retain(|id, _| id.lookup_validity());
+        self.prune_at = (self.creations.len() * 2).max(1024);
+    }
+}
+
+/// Enables tracking of object creations, to find leaked objects (Debug builds only).
+///
+/// Once enabled, the library records where each instance of a user-defined class and each manually managed object (`new_alloc()`) has
+/// been created. Objects that are still alive can be listed with [`leak_report()`]. When the library is unloaded (at deinit of
+/// [`InitLevel::Scene`][crate::init::InitLevel::Scene]), a report of all unreleased objects is printed as a warning.
+///
+/// Creation sites are the Rust call sites of `new_alloc()`, `new_gd()` and `Gd::from_init_fn()`. User objects instantiated by Godot
+/// (e.g. from GDScript or scenes) are tracked without a location.
+///
+/// Objects created before this call are not tracked. In Release builds, this function has no effect.
+///
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::tools::enable_leak_tracking;
+///
+/// struct MyExtension;
+///
+/// #[gdextension]
+/// unsafe impl ExtensionLibrary for MyExtension {
+///     fn on_level_init(level: InitLevel) {
+///         if level == InitLevel::Scene {
+///             enable_leak_tracking();
+///         }
+///     }
+/// }
+/// ```
+pub fn enable_leak_tracking() {
+    if cfg!(debug_assertions) {
+        ENABLED.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Disables leak tracking and forgets all recorded creations.
+pub fn disable_leak_tracking() {
+    ENABLED.store(false, Ordering::Relaxed);
+    TRACKER.lock().creations.clear();
+}
+
+/// Returns whether leak tracking has been enabled with [`enable_leak_tracking()`].
+pub fn is_leak_tracking_enabled() -> bool {
+    cfg!(debug_assertions) && ENABLED.load(Ordering::Relaxed)
+}
+
+/// Lists all tracked objects that are still alive, grouped by class and creation site.
+///
+/// Empty if leak tracking has not been enabled with [`enable_leak_tracking()`].
+pub fn leak_report() -> LeakReport {
+    if !is_leak_tracking_enabled() {
+        return LeakReport {
+            entries: Vec::new(),
+        };
+    }
+
+    let mut tracker = TRACKER.lock();
+    tracker.prune_dead();
+
+    let mut counts = HashMap::<(ClassName, Option<&'static Location<'static>>), usize>::new();
+    for creation in tracker.creations.values() {
+        *counts
+            .entry((creation.class_name, creation.location))
+            .or_default() += 1;
+    }
+    drop(tracker);
+
+    let mut entries: Vec<LeakEntry> = counts
+        .into_iter()
+        .map(|((class_name, location), count)| LeakEntry {
+            class_name,
+            location,
+            count,
+        })
+        .collect();
+
+    // Most frequent first, then deterministic order.
+    entries.sort_by_cached_key(|e| {
+        let location = e.location.map(|loc| (loc.file(), loc.line(), loc.column()));
+        (
+            std::cmp::Reverse(e.count),
+            e.class_name.to_string(),
+            location,
+        )
+    });
+
+    LeakReport { entries }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Report
+
+/// Objects which are still alive, as returned by [`leak_report()`].
+///
+/// The `Display` impl lists one line per class and creation site.
+#[derive(Clone, Debug)]
+pub struct LeakReport {
+    entries: Vec<LeakEntry>,
+}
+
+impl LeakReport {
+    /// Returns `true` if no tracked object is alive.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Total number of alive objects.
+    pub fn object_count(&self) -> usize {
+        self.entries.iter().map(|e| e.count).sum()
+    }
+
+    /// Alive objects, grouped by class and creation site. Largest groups come first.
+    pub fn entries(&self) -> &[LeakEntry] {
+        &self.entries
+    }
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} object(s) not released", self.object_count())?;
+
+        for entry in &self.entries {
+            write!(f, "\n  {entry}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Group of alive objects with the same class and creation site.
+#[derive(Copy, Clone, Debug)]
+pub struct LeakEntry {
+    /// Class, as which the objects were created.
+    pub class_name: ClassName,
+
+    /// Rust call site of the creation, or `None` if the objects were instantiated by Godot.
+    pub location: Option<&'static Location<'static>>,
+
+    /// Number of alive objects.
+    pub count: usize,
+}
+
+impl fmt::Display for LeakEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            class_name,
+            location,
+            count,
+        } = self;
+
+        match location {
+            Some(location) => write!(f, "{count}x {class_name}, created at {location}"),
+            None => write!(f, "{count}x {class_name}, created by Godot"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-internal recording
+
+/// Records the creation of an object.
+pub(crate) fn track_creation(
+    instance_id: InstanceId,
+    class_name: ClassName,
+    location: Option<&'static Location<'static>>,
+) {
+    if !is_leak_tracking_enabled() {
+        return;
+    }
+
+    let mut tracker = TRACKER.lock();
+    if tracker.creations.len() >= tracker.prune_at {
+        tracker.prune_dead();
+    }
+
+    let creation = Creation {
+        class_name,
+        location,
+    };
+
+    // Without location (e.g. re-creation on hot reload), keep a previously recorded creation site.
+    if location.is_some() {
+        tracker.creations.insert(instance_id, creation);
+    } else {
+        tracker.creations.entry(instance_id).or_insert(creation);
+    }
+}
+
+/// Sets the creation site of an already tracked object, e.g. a user object created through an outer API.
+pub(crate) fn relocate_creation(instance_id: InstanceId, location: &'static Location<'static>) {
+    if !is_leak_tracking_enabled() {
+        return;
+    }
+
+    if let Some(creation) = TRACKER.lock().creations.get_mut(&instance_id) {
+        creation.location = Some(location);
+    }
+}
+
+/// Prints all unreleased objects, if tracking is enabled.
+pub(crate) fn report_leaks_on_deinit() {
+    let report = leak_report();
+    if !report.is_empty() {
+        crate::godot_warn!("Leak tracker: {report}");
+    }
+}
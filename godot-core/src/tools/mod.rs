@@ -10,10 +10,30 @@
 //! Contains functionality that extends existing Godot classes and functions, to make them more versatile
 //! or better integrated with Rust.
 
+mod gdir;
 mod gfile;
+mod gpath;
+mod leak_tracker;
+mod resource_format;
 mod save_load;
+mod text_format;
 mod translate;
+mod translation_template;
 
+pub use gdir::*;
 pub use gfile::*;
+pub use gpath::*;
+pub use leak_tracker::{
+    disable_leak_tracking, enable_leak_tracking, is_leak_tracking_enabled, leak_report, LeakEntry,
+    LeakReport,
+};
+pub use resource_format::*;
 pub use save_load::*;
+pub use text_format::*;
 pub use translate::*;
+pub use translation_template::*;
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Crate-local utilities
+
+pub(crate) use leak_tracker::{relocate_creation, report_leaks_on_deinit, track_creation};
@@ -0,0 +1,68 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::classes::Resource;
+use crate::global::Error as GodotError;
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Custom file format, through which resources of type `R` are loaded from and saved to disk.
+///
+/// Implement this trait for a class annotated with `#[class(resource_format = (extensions = [...], resource = R))]`. godot-rust then
+/// generates a [`ResourceFormatLoader`][crate::classes::ResourceFormatLoader] and a [`ResourceFormatSaver`][crate::classes::ResourceFormatSaver]
+/// which are added to [`ResourceLoader`][crate::classes::ResourceLoader] and [`ResourceSaver`][crate::classes::ResourceSaver] once the
+/// `Scene` init level is loaded, and removed again when it is unloaded. From then on, [`load()`][crate::tools::load],
+/// [`save()`][crate::tools::save] and their GDScript counterparts work with files of the given extensions.
+///
+/// Reading and writing files is handled by godot-rust (through [`GFile`][crate::tools::GFile]); implementations only convert between
+/// bytes and resources. Errors are reported back to Godot, e.g. as the return value of `ResourceLoader.load()`.
+///
+/// # Example
+/// ```no_run
+/// use godot::prelude::*;
+/// use godot::global::Error;
+/// use godot::tools::ResourceFormat;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Resource)]
+/// struct LevelData {
+///     #[export]
+///     name: GString,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(no_init, resource_format = (extensions = ["lvl"], resource = LevelData))]
+/// struct LevelFormat {}
+///
+/// impl ResourceFormat<LevelData> for LevelFormat {
+///     fn load(bytes: &[u8]) -> Result<Gd<LevelData>, Error> {
+///         let name = std::str::from_utf8(bytes).map_err(|_| Error::ERR_PARSE_ERROR)?;
+///
+///         let mut level = LevelData::new_gd();
+///         level.bind_mut().name = GString::from(name);
+///         Ok(level)
+///     }
+///
+///     fn save(resource: &Gd<LevelData>) -> Result<Vec<u8>, Error> {
+///         Ok(resource.bind().name.to_string().into_bytes())
+///     }
+/// }
+/// ```
+pub trait ResourceFormat<R>: GodotClass
+where
+    R: Inherits<Resource>,
+{
+    /// Creates a resource from the contents of a file.
+    fn load(bytes: &[u8]) -> Result<Gd<R>, GodotError>;
+
+    /// Serializes a resource to the contents of a file.
+    ///
+    /// The default implementation returns [`ERR_UNAVAILABLE`][GodotError::ERR_UNAVAILABLE], for read-only formats.
+    fn save(resource: &Gd<R>) -> Result<Vec<u8>, GodotError> {
+        let _ = resource;
+        Err(GodotError::ERR_UNAVAILABLE)
+    }
+}
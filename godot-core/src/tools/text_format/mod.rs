@@ -0,0 +1,18 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Parser and writer for Godot's text serialization format.
+//!
+//! This is the syntax used by `.tres` and `.tscn` files, `project.godot` and `var_to_str()`. It is implemented in pure Rust, so it can
+//! be used in build scripts and tools that don't run the engine.
+
+mod parser;
+mod resource;
+mod value;
+
+pub use resource::*;
+pub use value::*;
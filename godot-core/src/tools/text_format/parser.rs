@@ -0,0 +1,1043 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::builtin::*;
+use crate::meta::error::TextFormatError;
+use crate::tools::text_format::{TextElementType, TextValue};
+
+/// Recursive-descent parser for values and resource file structure, following Godot's `VariantParser`.
+pub(super) struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl Parser {
+    pub fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            pos: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    pub fn error(&self, message: impl Into<String>) -> TextFormatError {
+        TextFormatError::new(message, self.line, self.column)
+    }
+
+    pub fn is_at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.peek().is_none()
+    }
+
+    pub fn expect_end(&mut self) -> Result<(), TextFormatError> {
+        if self.is_at_end() {
+            Ok(())
+        } else {
+            Err(self.error("unexpected characters after value"))
+        }
+    }
+
+    /// Skips whitespace (including line breaks) and `;` comments.
+    pub fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == ';' {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.advance();
+                }
+            } else if c.is_whitespace() {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+
+        Some(c)
+    }
+
+    /// Skips whitespace, then consumes `expected`.
+    pub fn expect(&mut self, expected: char) -> Result<(), TextFormatError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.advance();
+                Ok(())
+            }
+            Some(c) => Err(self.error(format!("expected `{expected}`, found `{c}`"))),
+            None => Err(self.error(format!("expected `{expected}`, found end of input"))),
+        }
+    }
+
+    /// Skips whitespace, then consumes `expected` if it is next.
+    fn accept(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+
+        if self.peek() == Some(expected) {
+            self.advance();
+            true
+        } else {
+            false
+        }
+    }
+
+    // ----------------------------------------------------------------------------------------------------------------------------------------------
+    // Values
+
+    pub fn parse_value(&mut self) -> Result<TextValue, TextFormatError> {
+        self.skip_whitespace();
+
+        let Some(c) = self.peek() else {
+            return Err(self.error("expected value, found end of input"));
+        };
+
+        match c {
+            '{' => Ok(TextValue::Dictionary(self.parse_dictionary()?)),
+            '[' => Ok(TextValue::Array(self.parse_array()?)),
+            '"' => Ok(TextValue::String(self.parse_string()?)),
+            '&' => {
+                self.advance();
+                Ok(TextValue::StringName(self.parse_string()?))
+            }
+            '^' => {
+                self.advance();
+                Ok(TextValue::NodePath(self.parse_string()?))
+            }
+            '-' | '+' | '.' | '0'..='9' => self.parse_number(),
+            c if c.is_alphabetic() || c == '_' => {
+                let identifier = self.parse_identifier()?;
+                self.parse_identifier_value(&identifier)
+            }
+            c => Err(self.error(format!("unexpected character `{c}`"))),
+        }
+    }
+
+    pub fn parse_string(&mut self) -> Result<String, TextFormatError> {
+        self.expect('"')?;
+
+        let mut text = String::new();
+        loop {
+            let c = self
+                .advance()
+                .ok_or_else(|| self.error("unterminated string"))?;
+
+            match c {
+                '"' => return Ok(text),
+                '\\' => text.push(self.parse_escape()?),
+                c => text.push(c),
+            }
+        }
+    }
+
+    pub fn parse_identifier(&mut self) -> Result<String, TextFormatError> {
+        self.skip_whitespace();
+
+        let mut identifier = String::new();
+        while let Some(c) = self.peek().filter(|&c| c.is_alphanumeric() || c == '_') {
+            identifier.push(c);
+            self.advance();
+        }
+
+        if identifier.is_empty() {
+            Err(self.error("expected identifier"))
+        } else {
+            Ok(identifier)
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<char, TextFormatError> {
+        let c = self
+            .advance()
+            .ok_or_else(|| self.error("unterminated string"))?;
+
+        let escaped = match c {
+            'b' => '\u{8}',
+            't' => '\t',
+            'n' => '\n',
+            'f' => '\u{c}',
+            'r' => '\r',
+            '"' | '\\' | '\'' | '/' => c,
+            'u' => {
+                let code = self.parse_hex(4)?;
+
+                // UTF-16 surrogate pair, written as two escapes.
+                if (0xD800..0xDC00).contains(&code) {
+                    if self.advance() != Some('\\') || self.advance() != Some('u') {
+                        return Err(self.error("expected low surrogate after high surrogate"));
+                    }
+
+                    let low = self.parse_hex(4)?;
+                    if !(0xDC00..0xE000).contains(&low) {
+                        return Err(self.error("invalid low surrogate"));
+                    }
+
+                    let code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                    return char::from_u32(code)
+                        .ok_or_else(|| self.error("invalid unicode escape"));
+                }
+
+                return char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"));
+            }
+            'U' => {
+                let code = self.parse_hex(6)?;
+                return char::from_u32(code).ok_or_else(|| self.error("invalid unicode escape"));
+            }
+            c => return Err(self.error(format!("invalid escape sequence `\\{c}`"))),
+        };
+
+        Ok(escaped)
+    }
+
+    fn parse_hex(&mut self, digits: usize) -> Result<u32, TextFormatError> {
+        let mut code = 0;
+        for _ in 0..digits {
+            let digit = self
+                .advance()
+                .and_then(|c| c.to_digit(16))
+                .ok_or_else(|| self.error("invalid hexadecimal digit in unicode escape"))?;
+
+            code = code * 16 + digit;
+        }
+
+        Ok(code)
+    }
+
+    fn parse_number(&mut self) -> Result<TextValue, TextFormatError> {
+        let mut number = String::new();
+        while let Some(c) = self.peek() {
+            let is_exponent_sign =
+                (c == '-' || c == '+') && number.ends_with(|p| p == 'e' || p == 'E');
+
+            if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || is_exponent_sign {
+                number.push(c);
+                self.advance();
+            } else if (c == '-' || c == '+') && number.is_empty() {
+                number.push(c);
+                self.advance();
+            } else {
+                break;
+            }
+        }
+
+        let is_float = number.contains(['.', 'e', 'E']);
+        let result = if is_float {
+            number.parse().map(TextValue::Float).ok()
+        } else {
+            number.parse().map(TextValue::Int).ok()
+        };
+
+        result.ok_or_else(|| self.error(format!("invalid number `{number}`")))
+    }
+
+    fn parse_dictionary(&mut self) -> Result<Vec<(TextValue, TextValue)>, TextFormatError> {
+        self.expect('{')?;
+
+        let mut entries = Vec::new();
+        while !self.accept('}') {
+            if !entries.is_empty() {
+                self.expect(',')?;
+
+                // Trailing comma.
+                if self.accept('}') {
+                    break;
+                }
+            }
+
+            let key = self.parse_value()?;
+            self.expect(':')?;
+            let value = self.parse_value()?;
+            entries.push((key, value));
+        }
+
+        Ok(entries)
+    }
+
+    fn parse_array(&mut self) -> Result<Vec<TextValue>, TextFormatError> {
+        self.expect('[')?;
+        self.parse_sequence(']')
+    }
+
+    /// Parses comma-separated values up to and including `end`.
+    fn parse_sequence(&mut self, end: char) -> Result<Vec<TextValue>, TextFormatError> {
+        let mut values = Vec::new();
+        while !self.accept(end) {
+            if !values.is_empty() {
+                self.expect(',')?;
+
+                // Trailing comma.
+                if self.accept(end) {
+                    break;
+                }
+            }
+
+            values.push(self.parse_value()?);
+        }
+
+        Ok(values)
+    }
+
+    fn parse_element_type(&mut self) -> Result<TextElementType, TextFormatError> {
+        let name = self.parse_identifier()?;
+
+        self.skip_whitespace();
+        if self.peek() == Some('(') {
+            let script = self.parse_identifier_value(&name)?;
+            Ok(TextElementType::Script(Box::new(script)))
+        } else {
+            Ok(TextElementType::Named(name))
+        }
+    }
+
+    fn parse_identifier_value(&mut self, identifier: &str) -> Result<TextValue, TextFormatError> {
+        let value = match identifier {
+            "true" => TextValue::Bool(true),
+            "false" => TextValue::Bool(false),
+            "null" | "nil" => TextValue::Nil,
+            "inf" => TextValue::Float(f64::INFINITY),
+            "inf_neg" => TextValue::Float(f64::NEG_INFINITY),
+            "nan" => TextValue::Float(f64::NAN),
+            "Array" => self.parse_array_constructor()?,
+            "Dictionary" => self.parse_dictionary_constructor()?,
+            "Object" => self.parse_object()?,
+            _ => {
+                self.expect('(')?;
+                let args = self.parse_sequence(')')?;
+                self.construct(identifier, args)?
+            }
+        };
+
+        Ok(value)
+    }
+
+    /// `Array[Type]([...])` or `Array([...])`.
+    fn parse_array_constructor(&mut self) -> Result<TextValue, TextFormatError> {
+        let element_type = if self.accept('[') {
+            let element_type = self.parse_element_type()?;
+            self.expect(']')?;
+            Some(element_type)
+        } else {
+            None
+        };
+
+        self.expect('(')?;
+        let elements = if self.accept(')') {
+            Vec::new()
+        } else {
+            let elements = self.parse_array()?;
+            self.expect(')')?;
+            elements
+        };
+
+        Ok(match element_type {
+            Some(element_type) => TextValue::TypedArray {
+                element_type,
+                elements,
+            },
+            None => TextValue::Array(elements),
+        })
+    }
+
+    /// `Dictionary[Key, Value]({...})` or `Dictionary({...})`.
+    fn parse_dictionary_constructor(&mut self) -> Result<TextValue, TextFormatError> {
+        let types = if self.accept('[') {
+            let key_type = self.parse_element_type()?;
+            self.expect(',')?;
+            let value_type = self.parse_element_type()?;
+            self.expect(']')?;
+            Some((key_type, value_type))
+        } else {
+            None
+        };
+
+        self.expect('(')?;
+        let entries = if self.accept(')') {
+            Vec::new()
+        } else {
+            let entries = self.parse_dictionary()?;
+            self.expect(')')?;
+            entries
+        };
+
+        Ok(match types {
+            Some((key_type, value_type)) => TextValue::TypedDictionary {
+                key_type,
+                value_type,
+                entries,
+            },
+            None => TextValue::Dictionary(entries),
+        })
+    }
+
+    /// `Object(Class,"property":value,...)`.
+    fn parse_object(&mut self) -> Result<TextValue, TextFormatError> {
+        self.expect('(')?;
+        let class = self.parse_identifier()?;
+
+        let mut properties = Vec::new();
+        while !self.accept(')') {
+            self.expect(',')?;
+
+            // Trailing comma.
+            if self.accept(')') {
+                break;
+            }
+
+            let name = self.parse_string()?;
+            self.expect(':')?;
+            properties.push((name, self.parse_value()?));
+        }
+
+        Ok(TextValue::Object { class, properties })
+    }
+
+    /// Builds a value from a constructor call with already parsed arguments.
+    fn construct(&self, name: &str, args: Vec<TextValue>) -> Result<TextValue, TextFormatError> {
+        let value = match name {
+            "Vector2" => {
+                let [x, y] = self.reals(name, &args)?;
+                TextValue::Vector2(Vector2::new(x, y))
+            }
+            "Vector2i" => {
+                let [x, y] = self.ints(name, &args)?;
+                TextValue::Vector2i(Vector2i::new(x, y))
+            }
+            "Rect2" => {
+                let [x, y, w, h] = self.reals(name, &args)?;
+                TextValue::Rect2(Rect2::new(Vector2::new(x, y), Vector2::new(w, h)))
+            }
+            "Rect2i" => {
+                let [x, y, w, h] = self.ints(name, &args)?;
+                TextValue::Rect2i(Rect2i::new(Vector2i::new(x, y), Vector2i::new(w, h)))
+            }
+            "Vector3" => {
+                let [x, y, z] = self.reals(name, &args)?;
+                TextValue::Vector3(Vector3::new(x, y, z))
+            }
+            "Vector3i" => {
+                let [x, y, z] = self.ints(name, &args)?;
+                TextValue::Vector3i(Vector3i::new(x, y, z))
+            }
+            "Transform2D" | "Matrix32" => {
+                let [ax, ay, bx, by, ox, oy] = self.reals(name, &args)?;
+                TextValue::Transform2D(Transform2D::from_cols(
+                    Vector2::new(ax, ay),
+                    Vector2::new(bx, by),
+                    Vector2::new(ox, oy),
+                ))
+            }
+            "Vector4" => {
+                let [x, y, z, w] = self.reals(name, &args)?;
+                TextValue::Vector4(Vector4::new(x, y, z, w))
+            }
+            "Vector4i" => {
+                let [x, y, z, w] = self.ints(name, &args)?;
+                TextValue::Vector4i(Vector4i::new(x, y, z, w))
+            }
+            "Plane" => {
+                let [a, b, c, d] = self.reals(name, &args)?;
+                TextValue::Plane(Plane {
+                    normal: Vector3::new(a, b, c),
+                    d,
+                })
+            }
+            "Quaternion" | "Quat" => {
+                let [x, y, z, w] = self.reals(name, &args)?;
+                TextValue::Quaternion(Quaternion::new(x, y, z, w))
+            }
+            "AABB" | "Rect3" => {
+                let [x, y, z, w, h, d] = self.reals(name, &args)?;
+                TextValue::Aabb(Aabb::new(Vector3::new(x, y, z), Vector3::new(w, h, d)))
+            }
+            "Basis" | "Matrix3" => {
+                let c = self.reals::<9>(name, &args)?;
+                TextValue::Basis(basis_from_rows(&c))
+            }
+            "Transform3D" | "Transform" => {
+                let c = self.reals::<12>(name, &args)?;
+                TextValue::Transform3D(Transform3D::new(
+                    basis_from_rows(&c[..9]),
+                    Vector3::new(c[9], c[10], c[11]),
+                ))
+            }
+            "Projection" => {
+                let c = self.reals::<16>(name, &args)?;
+                TextValue::Projection(Projection::from_cols(
+                    Vector4::new(c[0], c[1], c[2], c[3]),
+                    Vector4::new(c[4], c[5], c[6], c[7]),
+                    Vector4::new(c[8], c[9], c[10], c[11]),
+                    Vector4::new(c[12], c[13], c[14], c[15]),
+                ))
+            }
+            "Color" => {
+                let c = self.floats(name, &args)?;
+                match *c.as_slice() {
+                    [r, g, b] => {
+                        TextValue::Color(Color::from_rgba(r as f32, g as f32, b as f32, 1.0))
+                    }
+                    [r, g, b, a] => {
+                        TextValue::Color(Color::from_rgba(r as f32, g as f32, b as f32, a as f32))
+                    }
+                    _ => return Err(self.arg_count_error(name, "3 or 4", args.len())),
+                }
+            }
+            "RID" => match args.as_slice() {
+                [] => TextValue::Rid(Rid::Invalid),
+                [TextValue::Int(id)] => TextValue::Rid(Rid::new(*id as u64)),
+                _ => return Err(self.error("`RID` expects no argument or an integer")),
+            },
+            "Callable" => {
+                self.no_args(name, &args)?;
+                TextValue::Callable
+            }
+            "Signal" => {
+                self.no_args(name, &args)?;
+                TextValue::Signal
+            }
+            "NodePath" => TextValue::NodePath(self.string_arg(name, args)?),
+            "StringName" => TextValue::StringName(self.string_arg(name, args)?),
+            "SubResource" => TextValue::SubResource(self.resource_arg(name, args)?),
+            "ExtResource" => TextValue::ExtResource(self.resource_arg(name, args)?),
+            "Resource" => TextValue::Resource(self.string_arg(name, args)?),
+            "PackedByteArray" | "PoolByteArray" => match args.as_slice() {
+                // Base64 since Godot 4.3.
+                [TextValue::String(base64)] => TextValue::PackedByteArray(
+                    decode_base64(base64)
+                        .ok_or_else(|| self.error("invalid base64 in `PackedByteArray`"))?,
+                ),
+                _ => TextValue::PackedByteArray(self.int_elements(name, &args)?),
+            },
+            "PackedInt32Array" | "PoolIntArray" => {
+                TextValue::PackedInt32Array(self.int_elements(name, &args)?)
+            }
+            "PackedInt64Array" => TextValue::PackedInt64Array(self.int_elements(name, &args)?),
+            "PackedFloat32Array" | "PoolRealArray" => TextValue::PackedFloat32Array(
+                self.floats(name, &args)?
+                    .into_iter()
+                    .map(|x| x as f32)
+                    .collect(),
+            ),
+            "PackedFloat64Array" => TextValue::PackedFloat64Array(self.floats(name, &args)?),
+            "PackedStringArray" | "PoolStringArray" => {
+                let strings = args
+                    .into_iter()
+                    .map(|arg| match arg {
+                        TextValue::String(text) => Ok(text),
+                        _ => Err(self.error(format!("`{name}` expects strings"))),
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                TextValue::PackedStringArray(strings)
+            }
+            "PackedVector2Array" | "PoolVector2Array" => {
+                let values = self.real_chunks::<2>(name, &args)?;
+                TextValue::PackedVector2Array(values.map(|[x, y]| Vector2::new(x, y)).collect())
+            }
+            "PackedVector3Array" | "PoolVector3Array" => {
+                let values = self.real_chunks::<3>(name, &args)?;
+                TextValue::PackedVector3Array(
+                    values.map(|[x, y, z]| Vector3::new(x, y, z)).collect(),
+                )
+            }
+            "PackedColorArray" | "PoolColorArray" => {
+                let values = self.real_chunks::<4>(name, &args)?;
+                TextValue::PackedColorArray(
+                    values
+                        .map(|[r, g, b, a]| {
+                            Color::from_rgba(r as f32, g as f32, b as f32, a as f32)
+                        })
+                        .collect(),
+                )
+            }
+            "PackedVector4Array" => {
+                let values = self.real_chunks::<4>(name, &args)?;
+                TextValue::PackedVector4Array(
+                    values
+                        .map(|[x, y, z, w]| Vector4::new(x, y, z, w))
+                        .collect(),
+                )
+            }
+            _ => return Err(self.error(format!("unknown type or identifier `{name}`"))),
+        };
+
+        Ok(value)
+    }
+
+    // ----------------------------------------------------------------------------------------------------------------------------------------------
+    // Argument validation
+
+    fn floats(&self, name: &str, args: &[TextValue]) -> Result<Vec<f64>, TextFormatError> {
+        args.iter()
+            .map(|arg| {
+                arg.as_float()
+                    .ok_or_else(|| self.error(format!("`{name}` expects numbers")))
+            })
+            .collect()
+    }
+
+    fn reals<const N: usize>(
+        &self,
+        name: &str,
+        args: &[TextValue],
+    ) -> Result<[real; N], TextFormatError> {
+        let floats = self.floats(name, args)?;
+        let reals: Vec<real> = floats.into_iter().map(|x| x as real).collect();
+
+        reals
+            .try_into()
+            .map_err(|_| self.arg_count_error(name, &N.to_string(), args.len()))
+    }
+
+    fn ints<const N: usize>(
+        &self,
+        name: &str,
+        args: &[TextValue],
+    ) -> Result<[i32; N], TextFormatError> {
+        let ints: Vec<i32> = self.int_elements(name, args)?;
+
+        ints.try_into()
+            .map_err(|_| self.arg_count_error(name, &N.to_string(), args.len()))
+    }
+
+    fn int_elements<T: TryFrom<i64>>(
+        &self,
+        name: &str,
+        args: &[TextValue],
+    ) -> Result<Vec<T>, TextFormatError> {
+        args.iter()
+            .map(|arg| {
+                let int = match arg {
+                    TextValue::Int(int) => Some(*int),
+                    // Godot accepts floats for integer components and truncates them.
+                    TextValue::Float(float) => Some(*float as i64),
+                    _ => None,
+                };
+
+                int.and_then(|int| T::try_from(int).ok())
+                    .ok_or_else(|| self.error(format!("`{name}` expects integers in range")))
+            })
+            .collect()
+    }
+
+    fn real_chunks<const N: usize>(
+        &self,
+        name: &str,
+        args: &[TextValue],
+    ) -> Result<impl Iterator<Item = [real; N]>, TextFormatError> {
+        if args.len() % N != 0 {
+            return Err(self.error(format!(
+                "`{name}` expects a multiple of {N} numbers, found {}",
+                args.len()
+            )));
+        }
+
+        let floats = self.floats(name, args)?;
+        let chunks = floats
+            .chunks_exact(N)
+            .map(|chunk| std::array::from_fn(|i| chunk[i] as real))
+            .collect::<Vec<_>>();
+
+        Ok(chunks.into_iter())
+    }
+
+    fn no_args(&self, name: &str, args: &[TextValue]) -> Result<(), TextFormatError> {
+        if args.is_empty() {
+            Ok(())
+        } else {
+            Err(self.arg_count_error(name, "0", args.len()))
+        }
+    }
+
+    fn string_arg(&self, name: &str, args: Vec<TextValue>) -> Result<String, TextFormatError> {
+        match <[TextValue; 1]>::try_from(args) {
+            Ok([TextValue::String(text)]) => Ok(text),
+            _ => Err(self.error(format!("`{name}` expects a single string"))),
+        }
+    }
+
+    /// Resource IDs are strings since Godot 4, but integers in older files.
+    fn resource_arg(&self, name: &str, args: Vec<TextValue>) -> Result<String, TextFormatError> {
+        match <[TextValue; 1]>::try_from(args) {
+            Ok([TextValue::String(id)]) => Ok(id),
+            Ok([TextValue::Int(id)]) => Ok(id.to_string()),
+            _ => Err(self.error(format!("`{name}` expects a single string or integer"))),
+        }
+    }
+
+    fn arg_count_error(&self, name: &str, expected: &str, actual: usize) -> TextFormatError {
+        self.error(format!(
+            "`{name}` expects {expected} arguments, found {actual}"
+        ))
+    }
+
+    // ----------------------------------------------------------------------------------------------------------------------------------------------
+    // Resource files
+
+    /// Parses `[tag key=value ...]`, with the parser positioned at `[`.
+    pub fn parse_section_header(
+        &mut self,
+    ) -> Result<(String, Vec<(String, TextValue)>), TextFormatError> {
+        self.expect('[')?;
+
+        let tag = self.parse_key(|c| c.is_whitespace() || c == ']')?;
+
+        let mut attributes = Vec::new();
+        while !self.accept(']') {
+            let key = self.parse_key(|c| c == '=' || c == ']' || c.is_whitespace())?;
+            self.expect('=')?;
+            attributes.push((key, self.parse_value()?));
+        }
+
+        Ok((tag, attributes))
+    }
+
+    /// Parses `key = value`. Keys can contain any character except `=` (like `metadata/_edit_lock_`), or be quoted.
+    pub fn parse_assignment(&mut self) -> Result<(String, TextValue), TextFormatError> {
+        self.skip_whitespace();
+
+        let key = if self.peek() == Some('"') {
+            self.parse_string()?
+        } else {
+            self.parse_key(|c| c == '=' || c == '\n')?
+                .trim_end()
+                .to_string()
+        };
+
+        self.expect('=')?;
+        let value = self.parse_value()?;
+
+        Ok((key, value))
+    }
+
+    fn parse_key(&mut self, is_end: impl Fn(char) -> bool) -> Result<String, TextFormatError> {
+        self.skip_whitespace();
+
+        let mut key = String::new();
+        while let Some(c) = self.peek().filter(|&c| !is_end(c)) {
+            key.push(c);
+            self.advance();
+        }
+
+        if key.is_empty() {
+            Err(self.error("expected key"))
+        } else {
+            Ok(key)
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+/// Basis from 9 numbers in row-major order, like Godot's 9-argument `Basis` constructor.
+fn basis_from_rows(c: &[real]) -> Basis {
+    Basis::from_rows(
+        Vector3::new(c[0], c[1], c[2]),
+        Vector3::new(c[3], c[4], c[5]),
+        Vector3::new(c[6], c[7], c[8]),
+    )
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    fn sextet(c: u8) -> Option<u32> {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+
+        Some(value as u32)
+    }
+
+    let digits: Vec<u8> = text.bytes().filter(|c| !c.is_ascii_whitespace()).collect();
+    let digits = digits
+        .strip_suffix(b"==")
+        .or_else(|| digits.strip_suffix(b"="))
+        .unwrap_or(&digits);
+
+    let mut bytes = Vec::with_capacity(digits.len() * 3 / 4);
+    for chunk in digits.chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+
+        let mut bits = 0;
+        for (i, &c) in chunk.iter().enumerate() {
+            bits |= sextet(c)? << (18 - 6 * i);
+        }
+
+        let decoded = bits.to_be_bytes();
+        bytes.extend_from_slice(&decoded[1..chunk.len()]);
+    }
+
+    Some(bytes)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> TextValue {
+        TextValue::parse(text).unwrap_or_else(|err| panic!("failed to parse {text:?}: {err}"))
+    }
+
+    fn parse_err(text: &str) -> TextFormatError {
+        TextValue::parse(text).expect_err(text)
+    }
+
+    #[test]
+    fn parse_numbers() {
+        assert_eq!(parse("-12"), TextValue::Int(-12));
+        assert_eq!(parse("+3"), TextValue::Int(3));
+        assert_eq!(parse("1.5"), TextValue::Float(1.5));
+        assert_eq!(parse(".5"), TextValue::Float(0.5));
+        assert_eq!(parse("1e-05"), TextValue::Float(1e-5));
+        assert_eq!(parse("-2.5E+20"), TextValue::Float(-2.5e20));
+        assert_eq!(parse("inf_neg"), TextValue::Float(f64::NEG_INFINITY));
+        assert!(parse("nan").as_float().unwrap().is_nan());
+
+        assert_eq!(parse_err("1.2.3").message(), "invalid number `1.2.3`");
+        assert_eq!(
+            parse_err("99999999999999999999").message(),
+            "invalid number `99999999999999999999`"
+        );
+    }
+
+    #[test]
+    fn parse_string_escapes() {
+        assert_eq!(
+            parse(r#""a\tb\n\"c\" \\ \/ \' \b\f\r""#),
+            TextValue::String("a\tb\n\"c\" \\ / ' \u{8}\u{c}\r".into())
+        );
+        assert_eq!(
+            parse("\"multi\nline\""),
+            TextValue::String("multi\nline".into())
+        );
+        assert_eq!(parse(r#"&"name""#), TextValue::StringName("name".into()));
+        assert_eq!(parse(r#"^"a/b""#), TextValue::NodePath("a/b".into()));
+    }
+
+    #[test]
+    fn parse_unicode_escapes() {
+        assert_eq!(parse(r#""é\U01F600""#).as_str(), Some("é😀"));
+
+        // UTF-16 surrogate pair.
+        assert_eq!(parse(r#""\uD83D\uDE00!""#).as_str(), Some("😀!"));
+        assert_eq!(parse(r#""\ud83d\ude00""#).as_str(), Some("😀"));
+
+        assert_eq!(
+            parse_err(r#""\uD83D""#).message(),
+            "expected low surrogate after high surrogate"
+        );
+        assert_eq!(
+            parse_err(r#""\uD83D\u0041""#).message(),
+            "invalid low surrogate"
+        );
+        assert_eq!(parse_err(r#""\uDE00""#).message(), "invalid unicode escape");
+        assert_eq!(
+            parse_err(r#""\U110000""#).message(),
+            "invalid unicode escape"
+        );
+        assert_eq!(
+            parse_err(r#""\u12G4""#).message(),
+            "invalid hexadecimal digit in unicode escape"
+        );
+        assert_eq!(
+            parse_err(r#""\q""#).message(),
+            "invalid escape sequence `\\q`"
+        );
+    }
+
+    #[test]
+    fn parse_packed_byte_array() {
+        let bytes = |text: &str| match parse(text) {
+            TextValue::PackedByteArray(bytes) => bytes,
+            value => panic!("expected PackedByteArray, got {value:?}"),
+        };
+
+        assert_eq!(bytes("PackedByteArray(0, 1, 254, 255)"), [0u8, 1, 254, 255]);
+        assert_eq!(bytes("PackedByteArray()"), b"");
+
+        // Base64, with and without padding.
+        assert_eq!(bytes(r#"PackedByteArray("AAH+/w==")"#), [0u8, 1, 254, 255]);
+        assert_eq!(bytes(r#"PackedByteArray("AAH+")"#), [0u8, 1, 254]);
+        assert_eq!(bytes(r#"PackedByteArray("QUI=")"#), b"AB");
+        assert_eq!(bytes(r#"PackedByteArray("QQ")"#), b"A");
+        assert_eq!(bytes(r#"PackedByteArray("")"#), b"");
+
+        for invalid in ["\"Q\"", "\"QUJD!\"", "\"QU=I\""] {
+            let err = parse_err(&format!("PackedByteArray({invalid})"));
+            assert_eq!(err.message(), "invalid base64 in `PackedByteArray`");
+        }
+
+        assert_eq!(
+            parse_err("PackedByteArray(256)").message(),
+            "`PackedByteArray` expects integers in range"
+        );
+    }
+
+    #[test]
+    fn parse_constructors() {
+        assert_eq!(
+            parse("Basis(1, 2, 3, 4, 5, 6, 7, 8, 9)"),
+            TextValue::Basis(Basis::from_rows(
+                Vector3::new(1.0, 2.0, 3.0),
+                Vector3::new(4.0, 5.0, 6.0),
+                Vector3::new(7.0, 8.0, 9.0),
+            ))
+        );
+        assert_eq!(
+            parse("Color(1, 0.5, 0)"),
+            TextValue::Color(Color::from_rgba(1.0, 0.5, 0.0, 1.0))
+        );
+        assert_eq!(
+            parse("Vector2i(1.9, -2)"),
+            TextValue::Vector2i(Vector2i::new(1, -2))
+        );
+        assert_eq!(parse("RID()"), TextValue::Rid(Rid::Invalid));
+        assert_eq!(parse("SubResource(3)"), TextValue::SubResource("3".into()));
+        assert_eq!(
+            parse("PackedVector2Array(1, 2, 3, 4,)"),
+            TextValue::PackedVector2Array(vec![Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.0)])
+        );
+        assert_eq!(
+            parse("Array[int]()"),
+            TextValue::TypedArray {
+                element_type: TextElementType::Named("int".into()),
+                elements: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = parse_err("[\n  Vector2(1, 2, 3)\n]");
+        assert_eq!(err.message(), "`Vector2` expects 2 arguments, found 3");
+        assert_eq!((err.line(), err.column()), (2, 19));
+
+        assert_eq!(
+            parse_err("").message(),
+            "expected value, found end of input"
+        );
+        assert_eq!(parse_err("\"open").message(), "unterminated string");
+        assert_eq!(
+            parse_err("[1, 2").message(),
+            "expected `,`, found end of input"
+        );
+        assert_eq!(parse_err("{1 2}").message(), "expected `:`, found `2`");
+        assert_eq!(parse_err("#").message(), "unexpected character `#`");
+        assert_eq!(
+            parse_err("1 2").message(),
+            "unexpected characters after value"
+        );
+        assert_eq!(
+            parse_err("Unknown(1)").message(),
+            "unknown type or identifier `Unknown`"
+        );
+        assert_eq!(
+            parse_err("Vector2(1, \"x\")").message(),
+            "`Vector2` expects numbers"
+        );
+        assert_eq!(
+            parse_err("PackedVector3Array(1, 2)").message(),
+            "`PackedVector3Array` expects a multiple of 3 numbers, found 2"
+        );
+        assert_eq!(
+            parse_err("NodePath(1)").message(),
+            "`NodePath` expects a single string"
+        );
+        assert_eq!(
+            parse_err("Callable(1)").message(),
+            "`Callable` expects 0 arguments, found 1"
+        );
+    }
+
+    #[test]
+    fn parse_section_headers() {
+        let mut parser = Parser::new(r#"[ext_resource type="Script" path="res://a.gd" id="1_x"]"#);
+        let (tag, attributes) = parser.parse_section_header().unwrap();
+        assert_eq!(tag, "ext_resource");
+        assert_eq!(
+            attributes,
+            [
+                ("type".to_string(), TextValue::String("Script".into())),
+                ("path".to_string(), TextValue::String("res://a.gd".into())),
+                ("id".to_string(), TextValue::String("1_x".into())),
+            ]
+        );
+        assert!(parser.is_at_end());
+
+        let mut parser = Parser::new("[application]\n[gd_scene load_steps = 2 format=3]");
+        assert_eq!(
+            parser.parse_section_header().unwrap(),
+            ("application".to_string(), Vec::new())
+        );
+
+        let (tag, attributes) = parser.parse_section_header().unwrap();
+        assert_eq!(tag, "gd_scene");
+        assert_eq!(attributes[0], ("load_steps".to_string(), TextValue::Int(2)));
+        assert_eq!(attributes[1], ("format".to_string(), TextValue::Int(3)));
+
+        let header_err = |text: &str| Parser::new(text).parse_section_header().unwrap_err();
+        assert_eq!(header_err("[]").message(), "expected key");
+        assert_eq!(
+            header_err("[node name]").message(),
+            "expected `=`, found `]`"
+        );
+        assert_eq!(
+            header_err("[node name=]").message(),
+            "unexpected character `]`"
+        );
+        assert_eq!(header_err("[node name=\"a\"").message(), "expected key");
+    }
+
+    #[test]
+    fn parse_assignments() {
+        let mut parser = Parser::new(
+            "metadata/_edit_lock_ = true\n\"quoted key\"=1\nconfig/name=\"Demo\" ; comment\n",
+        );
+
+        assert_eq!(
+            parser.parse_assignment().unwrap(),
+            ("metadata/_edit_lock_".to_string(), TextValue::Bool(true))
+        );
+        assert_eq!(
+            parser.parse_assignment().unwrap(),
+            ("quoted key".to_string(), TextValue::Int(1))
+        );
+        assert_eq!(
+            parser.parse_assignment().unwrap(),
+            ("config/name".to_string(), TextValue::String("Demo".into()))
+        );
+        assert!(parser.is_at_end());
+
+        let err = Parser::new("key\nvalue").parse_assignment().unwrap_err();
+        assert_eq!(err.message(), "expected `=`, found `v`");
+    }
+}
@@ -0,0 +1,389 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::meta::error::TextFormatError;
+use crate::tools::text_format::parser::Parser;
+use crate::tools::text_format::value::write_string;
+use crate::tools::text_format::TextValue;
+
+/// Structure of a text resource file: `.tres`, `.tscn`, or a `ConfigFile` such as `project.godot`.
+///
+/// A file consists of sections, each starting with a header like `[sub_resource type="Gradient" id="Gradient_x"]` and followed by
+/// `key = value` properties. Properties before the first header (used by `ConfigFile`) are stored in [`properties`](Self::properties).
+///
+/// Writing with `to_string()` uses the `.tres`/`.tscn` layout, while [`to_config_string()`](Self::to_config_string) uses the
+/// `ConfigFile` layout. Comments are not preserved.
+///
+/// # Example
+/// ```
+/// use godot::tools::{TextResource, TextValue};
+///
+/// let text = r#"[gd_resource type="Curve" format=3]
+///
+/// [resource]
+/// bake_resolution = 50
+/// "#;
+///
+/// let resource = TextResource::parse(text).unwrap();
+/// let header = &resource.sections[0];
+/// assert_eq!(header.attribute("type"), Some(&TextValue::String("Curve".into())));
+///
+/// let body = resource.section("resource").unwrap();
+/// assert_eq!(body.property("bake_resolution"), Some(&TextValue::Int(50)));
+/// assert_eq!(resource.to_string(), text);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextResource {
+    /// Properties before the first section header.
+    pub properties: Vec<(String, TextValue)>,
+
+    /// Sections in file order.
+    pub sections: Vec<TextSection>,
+}
+
+impl TextResource {
+    /// Creates an empty resource file.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a resource file. `;` comments are skipped.
+    pub fn parse(text: &str) -> Result<Self, TextFormatError> {
+        let mut parser = Parser::new(text);
+        let mut resource = Self::new();
+
+        while !parser.is_at_end() {
+            if parser.peek() == Some('[') {
+                let (tag, attributes) = parser.parse_section_header()?;
+                resource.sections.push(TextSection {
+                    tag,
+                    attributes,
+                    properties: Vec::new(),
+                });
+            } else {
+                let property = parser.parse_assignment()?;
+                match resource.sections.last_mut() {
+                    Some(section) => section.properties.push(property),
+                    None => resource.properties.push(property),
+                }
+            }
+        }
+
+        Ok(resource)
+    }
+
+    /// First section with the given tag, e.g. `"resource"` or `"application"`.
+    pub fn section(&self, tag: &str) -> Option<&TextSection> {
+        self.sections.iter().find(|section| section.tag == tag)
+    }
+
+    /// Mutable access to the first section with the given tag.
+    pub fn section_mut(&mut self, tag: &str) -> Option<&mut TextSection> {
+        self.sections.iter_mut().find(|section| section.tag == tag)
+    }
+
+    /// All sections with the given tag, e.g. every `"ext_resource"`.
+    pub fn sections_with_tag<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a TextSection> {
+        self.sections
+            .iter()
+            .filter(move |section| section.tag == tag)
+    }
+
+    /// Value of a property before the first section header.
+    pub fn property(&self, key: &str) -> Option<&TextValue> {
+        find_property(&self.properties, key)
+    }
+
+    /// Writes the file in `ConfigFile` layout (as in `project.godot`), with `key=value` properties and an empty line after each header.
+    pub fn to_config_string(&self) -> String {
+        let mut text = String::new();
+        self.write_config(&mut text)
+            .expect("writing to String cannot fail");
+
+        text
+    }
+
+    fn write_config(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        for (key, value) in &self.properties {
+            write_property(f, key, "=", value)?;
+        }
+
+        for (i, section) in self.sections.iter().enumerate() {
+            if i > 0 || !self.properties.is_empty() {
+                f.write_str("\n")?;
+            }
+
+            section.write_header(f)?;
+            f.write_str("\n")?;
+
+            for (key, value) in &section.properties {
+                write_property(f, key, "=", value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for TextResource {
+    type Err = TextFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Writes the file in `.tres`/`.tscn` layout, with `key = value` properties.
+///
+/// Sections are separated by an empty line, except consecutive sections with the same tag and without properties (like `ext_resource`).
+impl fmt::Display for TextResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in &self.properties {
+            write_property(f, key, " = ", value)?;
+        }
+
+        let mut previous: Option<&TextSection> = None;
+        for section in &self.sections {
+            let is_grouped =
+                previous.is_some_and(|prev| prev.tag == section.tag && prev.properties.is_empty());
+
+            if !is_grouped && (previous.is_some() || !self.properties.is_empty()) {
+                f.write_str("\n")?;
+            }
+
+            section.write_header(f)?;
+
+            for (key, value) in &section.properties {
+                write_property(f, key, " = ", value)?;
+            }
+
+            previous = Some(section);
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Section of a [`TextResource`]: a `[tag key=value ...]` header and the properties following it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextSection {
+    /// Tag of the header, e.g. `gd_scene`, `ext_resource`, `node` or a `ConfigFile` section name like `application`.
+    pub tag: String,
+
+    /// Attributes of the header, e.g. `type="Node2D"`.
+    pub attributes: Vec<(String, TextValue)>,
+
+    /// Properties below the header.
+    pub properties: Vec<(String, TextValue)>,
+}
+
+impl TextSection {
+    /// Creates a section without attributes and properties.
+    pub fn new(tag: impl Into<String>) -> Self {
+        Self {
+            tag: tag.into(),
+            attributes: Vec::new(),
+            properties: Vec::new(),
+        }
+    }
+
+    /// Value of a header attribute, e.g. `"id"`.
+    pub fn attribute(&self, key: &str) -> Option<&TextValue> {
+        find_property(&self.attributes, key)
+    }
+
+    /// Value of a property below the header.
+    pub fn property(&self, key: &str) -> Option<&TextValue> {
+        find_property(&self.properties, key)
+    }
+
+    fn write_header(&self, f: &mut impl fmt::Write) -> fmt::Result {
+        write!(f, "[{}", self.tag)?;
+        for (key, value) in &self.attributes {
+            write!(f, " {key}={value}")?;
+        }
+
+        f.write_str("]\n")
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+fn find_property<'a>(properties: &'a [(String, TextValue)], key: &str) -> Option<&'a TextValue> {
+    properties
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value)
+}
+
+/// Writes `key<separator>value`, quoting the key like Godot's `String::property_name_encode()` if needed.
+fn write_property(
+    f: &mut impl fmt::Write,
+    key: &str,
+    separator: &str,
+    value: &TextValue,
+) -> fmt::Result {
+    let needs_quotes = key.is_empty()
+        || key
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '=' | '"' | ';' | '[' | ']'));
+
+    if needs_quotes {
+        write_string(f, key)?;
+    } else {
+        f.write_str(key)?;
+    }
+
+    writeln!(f, "{separator}{value}")
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_sections() {
+        let text = r#"[gd_resource type="Theme" load_steps=2 format=3]
+
+[ext_resource type="FontFile" path="res://font.ttf" id="1_f"]
+
+[sub_resource type="StyleBoxFlat" id="StyleBoxFlat_a"]
+bg_color = Color(0, 0, 0, 0.5)
+
+[resource]
+default_font = ExtResource("1_f")
+Button/styles/normal = SubResource("StyleBoxFlat_a")
+"#;
+
+        let resource = TextResource::parse(text).unwrap();
+        assert!(resource.properties.is_empty());
+        assert_eq!(
+            resource
+                .sections
+                .iter()
+                .map(|section| section.tag.as_str())
+                .collect::<Vec<_>>(),
+            ["gd_resource", "ext_resource", "sub_resource", "resource"]
+        );
+
+        let header = &resource.sections[0];
+        assert_eq!(header.attribute("load_steps"), Some(&TextValue::Int(2)));
+        assert_eq!(header.attribute("missing"), None);
+
+        let body = resource.section("resource").unwrap();
+        assert_eq!(
+            body.property("Button/styles/normal"),
+            Some(&TextValue::SubResource("StyleBoxFlat_a".into()))
+        );
+
+        assert_eq!(resource.to_string(), text);
+        assert_eq!(text.parse::<TextResource>().unwrap(), resource);
+    }
+
+    #[test]
+    fn resource_write_layout() {
+        let mut resource = TextResource::new();
+        resource
+            .properties
+            .push(("config_version".into(), TextValue::Int(5)));
+
+        for id in ["1", "2"] {
+            let mut section = TextSection::new("ext_resource");
+            section
+                .attributes
+                .push(("id".into(), TextValue::String(id.into())));
+            resource.sections.push(section);
+        }
+
+        let mut node = TextSection::new("node");
+        node.properties.extend([
+            ("with space".into(), TextValue::Int(1)),
+            ("".into(), TextValue::Nil),
+            ("a=b".into(), TextValue::Bool(false)),
+            ("metadata/x".into(), TextValue::Float(0.5)),
+        ]);
+        resource.sections.push(node);
+
+        // Sections without properties and with the same tag are grouped; special keys are quoted.
+        assert_eq!(
+            resource.to_string(),
+            "config_version = 5\n\n\
+             [ext_resource id=\"1\"]\n\
+             [ext_resource id=\"2\"]\n\n\
+             [node]\n\
+             \"with space\" = 1\n\
+             \"\" = null\n\
+             \"a=b\" = false\n\
+             metadata/x = 0.5\n"
+        );
+
+        assert_eq!(
+            resource.to_config_string(),
+            "config_version=5\n\n\
+             [ext_resource id=\"1\"]\n\n\n\
+             [ext_resource id=\"2\"]\n\n\n\
+             [node]\n\n\
+             \"with space\"=1\n\
+             \"\"=null\n\
+             \"a=b\"=false\n\
+             metadata/x=0.5\n"
+        );
+
+        // Both layouts can be read back.
+        assert_eq!(
+            TextResource::parse(&resource.to_string()).unwrap(),
+            resource
+        );
+        assert_eq!(
+            TextResource::parse(&resource.to_config_string()).unwrap(),
+            resource
+        );
+    }
+
+    #[test]
+    fn resource_section_access() {
+        let mut resource = TextResource::parse("top = 1\n[a]\nx = 1\n[b]\n[a]\nx = 2\n").unwrap();
+        assert_eq!(resource.property("top"), Some(&TextValue::Int(1)));
+        assert_eq!(resource.sections_with_tag("a").count(), 2);
+        assert!(resource.section("c").is_none());
+
+        resource
+            .section_mut("a")
+            .unwrap()
+            .properties
+            .push(("y".into(), TextValue::Int(3)));
+
+        let a = resource.section("a").unwrap();
+        assert_eq!(a.property("x"), Some(&TextValue::Int(1)));
+        assert_eq!(a.property("y"), Some(&TextValue::Int(3)));
+    }
+
+    #[test]
+    fn resource_parse_errors() {
+        let error = |text: &str| TextResource::parse(text).unwrap_err();
+
+        let err = error("[resource]\nsize = Vector2(1)\n");
+        assert_eq!(err.message(), "`Vector2` expects 2 arguments, found 1");
+        assert_eq!(err.line(), 2);
+
+        let err = error("[resource]\nkey value\n");
+        assert_eq!(err.message(), "expected `=`, found end of input");
+        assert_eq!(err.line(), 3);
+
+        assert_eq!(error("[resource").message(), "expected key");
+        assert_eq!(error("[node name=]").message(), "unexpected character `]`");
+        assert_eq!(error("= 1").message(), "expected key");
+    }
+}
@@ -0,0 +1,861 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::builtin::*;
+use crate::meta::error::TextFormatError;
+use crate::meta::ToGodot;
+use crate::obj::Gd;
+use crate::tools::text_format::parser::Parser;
+
+/// Value in Godot's text serialization format, as used by `.tres`/`.tscn` files, `project.godot` and `var_to_str()`.
+///
+/// Each variant corresponds to one syntax, e.g. `Vector3(1, 2, 3)`, `&"name"` or `SubResource("id")`. Math types are stored as their
+/// Rust builtins, while strings and containers use `String` and `Vec`, so values can be parsed and written without a running engine.
+///
+/// Parse a value with [`parse()`](Self::parse) (or `str::parse()`), and write it with `to_string()`. The output follows Godot's
+/// `var_to_str()`, so it can be read back by Godot. There are two known differences to Godot's own output:
+/// - Floats are written as the shortest representation that parses to the same value, while Godot uses a fixed number of significant
+///   digits. Both agree for values like `0.5`, `1e+20` or `2.5e-08`, but e.g. `123456789.0` is written in full, where Godot may round it.
+///   Exponents use Godot's notation with sign and at least two digits.
+/// - `PackedByteArray` is written as a list of numbers, like `var_to_str()`. The base64 syntax of Godot 4.3 resource files is only parsed.
+///
+/// # Example
+/// ```
+/// use godot::builtin::Vector3;
+/// use godot::tools::TextValue;
+///
+/// let value = TextValue::parse("Vector3(1, 2.5, -3)").unwrap();
+/// assert_eq!(value, TextValue::Vector3(Vector3::new(1.0, 2.5, -3.0)));
+///
+/// let array = TextValue::Array(vec![TextValue::Int(1), TextValue::String("two".into())]);
+/// assert_eq!(array.to_string(), r#"[1, "two"]"#);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextValue {
+    /// `null`.
+    Nil,
+    /// `true` or `false`.
+    Bool(bool),
+    /// Integer such as `-12`.
+    Int(i64),
+    /// Float such as `1.5`, `1e-05`, `inf`, `inf_neg` or `nan`.
+    Float(f64),
+    /// `"text"`, may span multiple lines.
+    String(String),
+    /// `&"name"`.
+    StringName(String),
+    /// `NodePath("path")`, or `^"path"`.
+    NodePath(String),
+    /// `Vector2(x, y)`.
+    Vector2(Vector2),
+    /// `Vector2i(x, y)`.
+    Vector2i(Vector2i),
+    /// `Rect2(x, y, width, height)`.
+    Rect2(Rect2),
+    /// `Rect2i(x, y, width, height)`.
+    Rect2i(Rect2i),
+    /// `Vector3(x, y, z)`.
+    Vector3(Vector3),
+    /// `Vector3i(x, y, z)`.
+    Vector3i(Vector3i),
+    /// `Transform2D(...)` with the 3 columns, 6 numbers in total.
+    Transform2D(Transform2D),
+    /// `Vector4(x, y, z, w)`.
+    Vector4(Vector4),
+    /// `Vector4i(x, y, z, w)`.
+    Vector4i(Vector4i),
+    /// `Plane(a, b, c, d)`.
+    Plane(Plane),
+    /// `Quaternion(x, y, z, w)`.
+    Quaternion(Quaternion),
+    /// `AABB(x, y, z, width, height, depth)`.
+    Aabb(Aabb),
+    /// `Basis(...)` with the 3 rows, 9 numbers in total.
+    Basis(Basis),
+    /// `Transform3D(...)` with the 3 basis rows and the origin, 12 numbers in total.
+    Transform3D(Transform3D),
+    /// `Projection(...)` with the 4 columns, 16 numbers in total.
+    Projection(Projection),
+    /// `Color(r, g, b, a)`. Alpha is optional when parsing.
+    Color(Color),
+    /// `RID()` or `RID(id)`.
+    Rid(Rid),
+    /// `Callable()`. Callables cannot be serialized, so they are always written empty.
+    Callable,
+    /// `Signal()`. Signals cannot be serialized, so they are always written empty.
+    Signal,
+    /// `Object(Class,"property":value,...)`, an object stored by value.
+    Object {
+        class: String,
+        properties: Vec<(String, TextValue)>,
+    },
+    /// `{key: value, ...}`, in insertion order.
+    Dictionary(Vec<(TextValue, TextValue)>),
+    /// `Dictionary[Key, Value]({...})`.
+    TypedDictionary {
+        key_type: TextElementType,
+        value_type: TextElementType,
+        entries: Vec<(TextValue, TextValue)>,
+    },
+    /// `[value, ...]`.
+    Array(Vec<TextValue>),
+    /// `Array[Type]([...])`.
+    TypedArray {
+        element_type: TextElementType,
+        elements: Vec<TextValue>,
+    },
+    /// `PackedByteArray(1, 2)`, or `PackedByteArray("base64")` in resource files since Godot 4.3. Written as list of numbers.
+    PackedByteArray(Vec<u8>),
+    /// `PackedInt32Array(...)`.
+    PackedInt32Array(Vec<i32>),
+    /// `PackedInt64Array(...)`.
+    PackedInt64Array(Vec<i64>),
+    /// `PackedFloat32Array(...)`.
+    PackedFloat32Array(Vec<f32>),
+    /// `PackedFloat64Array(...)`.
+    PackedFloat64Array(Vec<f64>),
+    /// `PackedStringArray("a", "b")`.
+    PackedStringArray(Vec<String>),
+    /// `PackedVector2Array(...)`, with the components of all vectors in sequence.
+    PackedVector2Array(Vec<Vector2>),
+    /// `PackedVector3Array(...)`, with the components of all vectors in sequence.
+    PackedVector3Array(Vec<Vector3>),
+    /// `PackedColorArray(...)`, with the components of all colors in sequence.
+    PackedColorArray(Vec<Color>),
+    /// `PackedVector4Array(...)`, with the components of all vectors in sequence.
+    PackedVector4Array(Vec<Vector4>),
+    /// `SubResource("id")`, referring to a `[sub_resource]` section of the same file.
+    SubResource(String),
+    /// `ExtResource("id")`, referring to an `[ext_resource]` section of the same file.
+    ExtResource(String),
+    /// `Resource("path")`, referring to a resource by path.
+    Resource(String),
+}
+
+/// Element type of [`TextValue::TypedArray`] and key/value type of [`TextValue::TypedDictionary`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TextElementType {
+    /// Builtin type or class, e.g. `int`, `StringName` or `Node`.
+    Named(String),
+    /// Script class, referred to by a resource like `ExtResource("id")`.
+    Script(Box<TextValue>),
+}
+
+impl TextValue {
+    /// Parses a single value. Surrounding whitespace and `;` comments are allowed.
+    pub fn parse(text: &str) -> Result<Self, TextFormatError> {
+        let mut parser = Parser::new(text);
+        let value = parser.parse_value()?;
+        parser.expect_end()?;
+
+        Ok(value)
+    }
+
+    /// Returns the value of `Bool`.
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of `Int`.
+    pub fn as_int(&self) -> Option<i64> {
+        match self {
+            Self::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of `Float`, or of `Int` converted to float.
+    pub fn as_float(&self) -> Option<f64> {
+        match self {
+            Self::Float(value) => Some(*value),
+            Self::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+
+    /// Returns the text of `String`, `StringName` or `NodePath`.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(text) | Self::StringName(text) | Self::NodePath(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of `Array` or `TypedArray`.
+    pub fn as_array(&self) -> Option<&[TextValue]> {
+        match self {
+            Self::Array(elements) | Self::TypedArray { elements, .. } => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// Returns the entries of `Dictionary` or `TypedDictionary`.
+    pub fn as_dictionary(&self) -> Option<&[(TextValue, TextValue)]> {
+        match self {
+            Self::Dictionary(entries) | Self::TypedDictionary { entries, .. } => Some(entries),
+            _ => None,
+        }
+    }
+
+    /// Converts to a variant. Requires a running engine.
+    ///
+    /// Returns `None` for objects and resource references, which cannot be created from text alone. Typed arrays and dictionaries are
+    /// converted to untyped ones. `Callable` and `Signal` become invalid callables and signals, and `PackedVector4Array` requires Godot 4.3.
+    pub fn to_variant(&self) -> Option<Variant> {
+        let variant = match self {
+            Self::Nil => Variant::nil(),
+            Self::Bool(value) => value.to_variant(),
+            Self::Int(value) => value.to_variant(),
+            Self::Float(value) => value.to_variant(),
+            Self::String(text) => GString::from(text).to_variant(),
+            Self::StringName(text) => StringName::from(text).to_variant(),
+            Self::NodePath(text) => NodePath::from(text).to_variant(),
+            Self::Vector2(value) => value.to_variant(),
+            Self::Vector2i(value) => value.to_variant(),
+            Self::Rect2(value) => value.to_variant(),
+            Self::Rect2i(value) => value.to_variant(),
+            Self::Vector3(value) => value.to_variant(),
+            Self::Vector3i(value) => value.to_variant(),
+            Self::Transform2D(value) => value.to_variant(),
+            Self::Vector4(value) => value.to_variant(),
+            Self::Vector4i(value) => value.to_variant(),
+            Self::Plane(value) => value.to_variant(),
+            Self::Quaternion(value) => value.to_variant(),
+            Self::Aabb(value) => value.to_variant(),
+            Self::Basis(value) => value.to_variant(),
+            Self::Transform3D(value) => value.to_variant(),
+            Self::Projection(value) => value.to_variant(),
+            Self::Color(value) => value.to_variant(),
+            Self::Rid(value) => value.to_variant(),
+            Self::Callable => Callable::invalid().to_variant(),
+            Self::Signal => Signal::invalid().to_variant(),
+            Self::Dictionary(entries) | Self::TypedDictionary { entries, .. } => {
+                let mut dictionary = Dictionary::new();
+                for (key, value) in entries {
+                    dictionary.set(key.to_variant()?, value.to_variant()?);
+                }
+                dictionary.to_variant()
+            }
+            Self::Array(elements) | Self::TypedArray { elements, .. } => {
+                let mut array = VariantArray::new();
+                for element in elements {
+                    array.push(&element.to_variant()?);
+                }
+                array.to_variant()
+            }
+            Self::PackedByteArray(values) => PackedByteArray::from(values.as_slice()).to_variant(),
+            Self::PackedInt32Array(values) => {
+                PackedInt32Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedInt64Array(values) => {
+                PackedInt64Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedFloat32Array(values) => {
+                PackedFloat32Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedFloat64Array(values) => {
+                PackedFloat64Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedStringArray(values) => values
+                .iter()
+                .map(GString::from)
+                .collect::<PackedStringArray>()
+                .to_variant(),
+            Self::PackedVector2Array(values) => {
+                PackedVector2Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedVector3Array(values) => {
+                PackedVector3Array::from(values.as_slice()).to_variant()
+            }
+            Self::PackedColorArray(values) => {
+                PackedColorArray::from(values.as_slice()).to_variant()
+            }
+            #[cfg(since_api = "4.3")]
+            Self::PackedVector4Array(values) => {
+                PackedVector4Array::from(values.as_slice()).to_variant()
+            }
+            #[cfg(before_api = "4.3")]
+            Self::PackedVector4Array(_) => return None,
+            Self::Object { .. }
+            | Self::SubResource(_)
+            | Self::ExtResource(_)
+            | Self::Resource(_) => return None,
+        };
+
+        Some(variant)
+    }
+
+    /// Converts from a variant. Requires a running engine.
+    ///
+    /// Returns `None` for non-null objects, which are only serialized by Godot's resource savers. Typed arrays and dictionaries are
+    /// converted to untyped ones.
+    pub fn from_variant(variant: &Variant) -> Option<Self> {
+        let value = match variant.get_type() {
+            VariantType::NIL => Self::Nil,
+            VariantType::BOOL => Self::Bool(variant.to()),
+            VariantType::INT => Self::Int(variant.to()),
+            VariantType::FLOAT => Self::Float(variant.to()),
+            VariantType::STRING => Self::String(variant.to::<GString>().to_string()),
+            VariantType::STRING_NAME => Self::StringName(variant.to::<StringName>().to_string()),
+            VariantType::NODE_PATH => Self::NodePath(variant.to::<NodePath>().to_string()),
+            VariantType::VECTOR2 => Self::Vector2(variant.to()),
+            VariantType::VECTOR2I => Self::Vector2i(variant.to()),
+            VariantType::RECT2 => Self::Rect2(variant.to()),
+            VariantType::RECT2I => Self::Rect2i(variant.to()),
+            VariantType::VECTOR3 => Self::Vector3(variant.to()),
+            VariantType::VECTOR3I => Self::Vector3i(variant.to()),
+            VariantType::TRANSFORM2D => Self::Transform2D(variant.to()),
+            VariantType::VECTOR4 => Self::Vector4(variant.to()),
+            VariantType::VECTOR4I => Self::Vector4i(variant.to()),
+            VariantType::PLANE => Self::Plane(variant.to()),
+            VariantType::QUATERNION => Self::Quaternion(variant.to()),
+            VariantType::AABB => Self::Aabb(variant.to()),
+            VariantType::BASIS => Self::Basis(variant.to()),
+            VariantType::TRANSFORM3D => Self::Transform3D(variant.to()),
+            VariantType::PROJECTION => Self::Projection(variant.to()),
+            VariantType::COLOR => Self::Color(variant.to()),
+            VariantType::RID => Self::Rid(variant.to()),
+            VariantType::CALLABLE => Self::Callable,
+            VariantType::SIGNAL => Self::Signal,
+            VariantType::OBJECT => {
+                let object = variant
+                    .try_to::<Option<Gd<crate::classes::Object>>>()
+                    .ok()?;
+                return object.is_none().then_some(Self::Nil);
+            }
+            VariantType::DICTIONARY => {
+                let dictionary = variant.to::<Dictionary>();
+                let entries = dictionary
+                    .iter_shared()
+                    .map(|(key, value)| {
+                        Some((Self::from_variant(&key)?, Self::from_variant(&value)?))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                Self::Dictionary(entries)
+            }
+            VariantType::ARRAY => {
+                let array = variant.to::<VariantArray>();
+                let elements = array
+                    .iter_shared()
+                    .map(|element| Self::from_variant(&element))
+                    .collect::<Option<Vec<_>>>()?;
+                Self::Array(elements)
+            }
+            VariantType::PACKED_BYTE_ARRAY => {
+                Self::PackedByteArray(variant.to::<PackedByteArray>().to_vec())
+            }
+            VariantType::PACKED_INT32_ARRAY => {
+                Self::PackedInt32Array(variant.to::<PackedInt32Array>().to_vec())
+            }
+            VariantType::PACKED_INT64_ARRAY => {
+                Self::PackedInt64Array(variant.to::<PackedInt64Array>().to_vec())
+            }
+            VariantType::PACKED_FLOAT32_ARRAY => {
+                Self::PackedFloat32Array(variant.to::<PackedFloat32Array>().to_vec())
+            }
+            VariantType::PACKED_FLOAT64_ARRAY => {
+                Self::PackedFloat64Array(variant.to::<PackedFloat64Array>().to_vec())
+            }
+            VariantType::PACKED_STRING_ARRAY => Self::PackedStringArray(
+                variant
+                    .to::<PackedStringArray>()
+                    .as_slice()
+                    .iter()
+                    .map(GString::to_string)
+                    .collect(),
+            ),
+            VariantType::PACKED_VECTOR2_ARRAY => {
+                Self::PackedVector2Array(variant.to::<PackedVector2Array>().to_vec())
+            }
+            VariantType::PACKED_VECTOR3_ARRAY => {
+                Self::PackedVector3Array(variant.to::<PackedVector3Array>().to_vec())
+            }
+            VariantType::PACKED_COLOR_ARRAY => {
+                Self::PackedColorArray(variant.to::<PackedColorArray>().to_vec())
+            }
+            #[cfg(since_api = "4.3")]
+            VariantType::PACKED_VECTOR4_ARRAY => {
+                Self::PackedVector4Array(variant.to::<PackedVector4Array>().to_vec())
+            }
+            _ => return None,
+        };
+
+        Some(value)
+    }
+}
+
+impl FromStr for TextValue {
+    type Err = TextFormatError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Writing
+
+/// Writes the value like Godot's `var_to_str()`.
+impl fmt::Display for TextValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nil => f.write_str("null"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write_float(f, *value),
+            Self::String(text) => write_string(f, text),
+            Self::StringName(text) => {
+                f.write_str("&")?;
+                write_string(f, text)
+            }
+            Self::NodePath(text) => {
+                f.write_str("NodePath(")?;
+                write_string(f, text)?;
+                f.write_str(")")
+            }
+            Self::Vector2(v) => write_reals(f, "Vector2", [v.x, v.y]),
+            Self::Vector2i(v) => write!(f, "Vector2i({}, {})", v.x, v.y),
+            Self::Rect2(r) => {
+                write_reals(f, "Rect2", [r.position.x, r.position.y, r.size.x, r.size.y])
+            }
+            Self::Rect2i(r) => write!(
+                f,
+                "Rect2i({}, {}, {}, {})",
+                r.position.x, r.position.y, r.size.x, r.size.y
+            ),
+            Self::Vector3(v) => write_reals(f, "Vector3", [v.x, v.y, v.z]),
+            Self::Vector3i(v) => write!(f, "Vector3i({}, {}, {})", v.x, v.y, v.z),
+            Self::Transform2D(t) => write_reals(
+                f,
+                "Transform2D",
+                [t.a.x, t.a.y, t.b.x, t.b.y, t.origin.x, t.origin.y],
+            ),
+            Self::Vector4(v) => write_reals(f, "Vector4", [v.x, v.y, v.z, v.w]),
+            Self::Vector4i(v) => write!(f, "Vector4i({}, {}, {}, {})", v.x, v.y, v.z, v.w),
+            Self::Plane(p) => write_reals(f, "Plane", [p.normal.x, p.normal.y, p.normal.z, p.d]),
+            Self::Quaternion(q) => write_reals(f, "Quaternion", [q.x, q.y, q.z, q.w]),
+            Self::Aabb(b) => write_reals(
+                f,
+                "AABB",
+                [
+                    b.position.x,
+                    b.position.y,
+                    b.position.z,
+                    b.size.x,
+                    b.size.y,
+                    b.size.z,
+                ],
+            ),
+            Self::Basis(b) => write_reals(f, "Basis", basis_rows(b)),
+            Self::Transform3D(t) => {
+                let mut components = basis_rows(&t.basis).to_vec();
+                components.extend([t.origin.x, t.origin.y, t.origin.z]);
+                write_reals(f, "Transform3D", components)
+            }
+            Self::Projection(p) => {
+                let components = p.cols.map(|col| [col.x, col.y, col.z, col.w]);
+                write_reals(f, "Projection", components.concat())
+            }
+            Self::Color(c) => write_reals(f, "Color", [c.r, c.g, c.b, c.a]),
+            Self::Rid(rid) => match rid.to_valid_u64() {
+                Some(id) => write!(f, "RID({id})"),
+                None => f.write_str("RID()"),
+            },
+            Self::Callable => f.write_str("Callable()"),
+            Self::Signal => f.write_str("Signal()"),
+            Self::Object { class, properties } => {
+                write!(f, "Object({class}")?;
+                for (name, value) in properties {
+                    f.write_str(",")?;
+                    write_string(f, name)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_str(")")
+            }
+            Self::Dictionary(entries) => write_dictionary(f, entries),
+            Self::TypedDictionary {
+                key_type,
+                value_type,
+                entries,
+            } => {
+                write!(f, "Dictionary[{key_type}, {value_type}](")?;
+                write_dictionary(f, entries)?;
+                f.write_str(")")
+            }
+            Self::Array(elements) => write_array(f, elements),
+            Self::TypedArray {
+                element_type,
+                elements,
+            } => {
+                write!(f, "Array[{element_type}](")?;
+                write_array(f, elements)?;
+                f.write_str(")")
+            }
+            Self::PackedByteArray(values) => {
+                write_list(f, "PackedByteArray", values, |f, v| write!(f, "{v}"))
+            }
+            Self::PackedInt32Array(values) => {
+                write_list(f, "PackedInt32Array", values, |f, v| write!(f, "{v}"))
+            }
+            Self::PackedInt64Array(values) => {
+                write_list(f, "PackedInt64Array", values, |f, v| write!(f, "{v}"))
+            }
+            Self::PackedFloat32Array(values) => {
+                write_list(f, "PackedFloat32Array", values, |f, v| write_real(f, *v))
+            }
+            Self::PackedFloat64Array(values) => {
+                write_list(f, "PackedFloat64Array", values, |f, v| write_real(f, *v))
+            }
+            Self::PackedStringArray(values) => {
+                write_list(f, "PackedStringArray", values, |f, v| write_string(f, v))
+            }
+            Self::PackedVector2Array(values) => {
+                write_list(f, "PackedVector2Array", values, |f, v| {
+                    write_components(f, [v.x, v.y])
+                })
+            }
+            Self::PackedVector3Array(values) => {
+                write_list(f, "PackedVector3Array", values, |f, v| {
+                    write_components(f, [v.x, v.y, v.z])
+                })
+            }
+            Self::PackedColorArray(values) => write_list(f, "PackedColorArray", values, |f, c| {
+                write_components(f, [c.r, c.g, c.b, c.a])
+            }),
+            Self::PackedVector4Array(values) => {
+                write_list(f, "PackedVector4Array", values, |f, v| {
+                    write_components(f, [v.x, v.y, v.z, v.w])
+                })
+            }
+            Self::SubResource(id) => write_call(f, "SubResource", id),
+            Self::ExtResource(id) => write_call(f, "ExtResource", id),
+            Self::Resource(path) => write_call(f, "Resource", path),
+        }
+    }
+}
+
+impl fmt::Display for TextElementType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Named(name) => f.write_str(name),
+            Self::Script(script) => write!(f, "{script}"),
+        }
+    }
+}
+
+/// Basis in row-major order, as Godot writes it (unlike `Transform2D` and `Projection`, which are written by column).
+fn basis_rows(basis: &Basis) -> [real; 9] {
+    let [a, b, c] = basis.rows;
+    [a.x, a.y, a.z, b.x, b.y, b.z, c.x, c.y, c.z]
+}
+
+fn write_dictionary(f: &mut fmt::Formatter<'_>, entries: &[(TextValue, TextValue)]) -> fmt::Result {
+    if entries.is_empty() {
+        return f.write_str("{}");
+    }
+
+    f.write_str("{\n")?;
+    for (index, (key, value)) in entries.iter().enumerate() {
+        if index > 0 {
+            f.write_str(",\n")?;
+        }
+        write!(f, "{key}: {value}")?;
+    }
+    f.write_str("\n}")
+}
+
+fn write_array(f: &mut fmt::Formatter<'_>, elements: &[TextValue]) -> fmt::Result {
+    f.write_str("[")?;
+    for (index, element) in elements.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write!(f, "{element}")?;
+    }
+    f.write_str("]")
+}
+
+fn write_list<T>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    values: &[T],
+    mut write_element: impl FnMut(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    write!(f, "{name}(")?;
+    for (index, value) in values.iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write_element(f, value)?;
+    }
+    f.write_str(")")
+}
+
+fn write_reals<T>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    components: impl AsRef<[T]>,
+) -> fmt::Result
+where
+    T: Copy + fmt::Debug + Into<f64>,
+{
+    write!(f, "{name}(")?;
+    write_components(f, components)?;
+    f.write_str(")")
+}
+
+fn write_components<T>(f: &mut fmt::Formatter<'_>, components: impl AsRef<[T]>) -> fmt::Result
+where
+    T: Copy + fmt::Debug + Into<f64>,
+{
+    for (index, component) in components.as_ref().iter().enumerate() {
+        if index > 0 {
+            f.write_str(", ")?;
+        }
+        write_real(f, *component)?;
+    }
+    Ok(())
+}
+
+fn write_call(f: &mut fmt::Formatter<'_>, name: &str, argument: &str) -> fmt::Result {
+    write!(f, "{name}(")?;
+    write_string(f, argument)?;
+    f.write_str(")")
+}
+
+/// Writes a float without forced fractional part, like Godot's vector components: `1`, `0.5`, `1e+20`, `inf`.
+fn write_real<T>(f: &mut fmt::Formatter<'_>, value: T) -> fmt::Result
+where
+    T: Copy + fmt::Debug + Into<f64>,
+{
+    let as_f64: f64 = value.into();
+
+    if as_f64 == 0.0 {
+        // Also for negative zero, like Godot.
+        f.write_str("0")
+    } else if as_f64.is_nan() {
+        f.write_str("nan")
+    } else if as_f64.is_infinite() {
+        f.write_str(if as_f64 > 0.0 { "inf" } else { "inf_neg" })
+    } else {
+        // Debug formatting is the shortest representation that round-trips in the original precision (f32 or f64).
+        let text = format!("{value:?}");
+
+        match text.split_once('e') {
+            // Godot (like C's printf) writes the exponent with sign and at least two digits: `1e+20`, `2.5e-08`.
+            Some((mantissa, exponent)) => {
+                let exponent: i32 = exponent.parse().expect("float exponent");
+                let sign = if exponent < 0 { '-' } else { '+' };
+                write!(f, "{mantissa}e{sign}{:02}", exponent.abs())
+            }
+            None => f.write_str(text.strip_suffix(".0").unwrap_or(&text)),
+        }
+    }
+}
+
+/// Writes a standalone float, which always has a fractional part or exponent: `1.0`, `0.5`, `1e+20`, `inf`.
+fn write_float(f: &mut fmt::Formatter<'_>, value: f64) -> fmt::Result {
+    if value == 0.0 {
+        f.write_str("0.0")
+    } else if value.is_finite() && value == value.trunc() && value.abs() < 1e16 {
+        write!(f, "{value:.1}")
+    } else {
+        write_real(f, value)
+    }
+}
+
+/// Writes a quoted string. Like Godot, only `\` and `"` are escaped; line breaks are kept.
+pub(super) fn write_string(f: &mut impl fmt::Write, text: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in text.chars() {
+        match c {
+            '\\' => f.write_str("\\\\")?,
+            '"' => f.write_str("\\\"")?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(value: TextValue, expected: &str) {
+        let text = value.to_string();
+        assert_eq!(text, expected);
+        assert_eq!(
+            TextValue::parse(&text).unwrap(),
+            value,
+            "parsed from {text:?}"
+        );
+    }
+
+    #[test]
+    fn write_floats() {
+        roundtrip(TextValue::Float(1.0), "1.0");
+        roundtrip(TextValue::Float(-0.5), "-0.5");
+        roundtrip(TextValue::Float(0.1), "0.1");
+        roundtrip(TextValue::Float(1e20), "1e+20");
+        roundtrip(TextValue::Float(1e16), "1e+16");
+        roundtrip(TextValue::Float(-2.5e-8), "-2.5e-08");
+        roundtrip(TextValue::Float(1.5e-300), "1.5e-300");
+        roundtrip(TextValue::Float(f64::INFINITY), "inf");
+        roundtrip(TextValue::Float(f64::NEG_INFINITY), "inf_neg");
+        assert_eq!(TextValue::Float(f64::NAN).to_string(), "nan");
+
+        // Negative zero is written without sign, like in Godot.
+        assert_eq!(TextValue::Float(-0.0).to_string(), "0.0");
+
+        // Shortest representation; Godot may write fewer digits (see type-level docs).
+        roundtrip(TextValue::Float(123456789.0), "123456789.0");
+        roundtrip(TextValue::Float(0.123456789), "0.123456789");
+
+        // Components of math types have no forced fractional part.
+        roundtrip(
+            TextValue::Vector3(Vector3::new(1.0, -0.0, 1e20)),
+            "Vector3(1, 0, 1e+20)",
+        );
+        roundtrip(
+            TextValue::PackedFloat64Array(vec![0.5, 2.0, 1e-7]),
+            "PackedFloat64Array(0.5, 2, 1e-07)",
+        );
+    }
+
+    #[test]
+    fn write_strings() {
+        roundtrip(
+            TextValue::String("a \"b\" \\ c\nd\té".into()),
+            "\"a \\\"b\\\" \\\\ c\nd\té\"",
+        );
+        roundtrip(TextValue::StringName("name".into()), "&\"name\"");
+        roundtrip(TextValue::NodePath("a/b:c".into()), "NodePath(\"a/b:c\")");
+        roundtrip(
+            TextValue::PackedStringArray(vec!["a".into(), "b\"c".into()]),
+            "PackedStringArray(\"a\", \"b\\\"c\")",
+        );
+    }
+
+    #[test]
+    fn write_containers() {
+        roundtrip(TextValue::Array(Vec::new()), "[]");
+        roundtrip(TextValue::Dictionary(Vec::new()), "{}");
+        roundtrip(
+            TextValue::Dictionary(vec![
+                (TextValue::String("a".into()), TextValue::Int(1)),
+                (
+                    TextValue::Int(2),
+                    TextValue::Array(vec![TextValue::Bool(true), TextValue::Nil]),
+                ),
+            ]),
+            "{\n\"a\": 1,\n2: [true, null]\n}",
+        );
+        roundtrip(
+            TextValue::TypedArray {
+                element_type: TextElementType::Script(Box::new(TextValue::ExtResource(
+                    "1_abc".into(),
+                ))),
+                elements: vec![TextValue::SubResource("Res_1".into())],
+            },
+            "Array[ExtResource(\"1_abc\")]([SubResource(\"Res_1\")])",
+        );
+        roundtrip(
+            TextValue::TypedDictionary {
+                key_type: TextElementType::Named("StringName".into()),
+                value_type: TextElementType::Named("int".into()),
+                entries: vec![(TextValue::StringName("x".into()), TextValue::Int(1))],
+            },
+            "Dictionary[StringName, int]({\n&\"x\": 1\n})",
+        );
+        roundtrip(
+            TextValue::Object {
+                class: "Node".into(),
+                properties: vec![("name".into(), TextValue::String("Child".into()))],
+            },
+            "Object(Node,\"name\":\"Child\")",
+        );
+    }
+
+    #[test]
+    fn write_builtins() {
+        roundtrip(TextValue::Vector2i(Vector2i::new(3, -4)), "Vector2i(3, -4)");
+        roundtrip(
+            TextValue::Rect2(Rect2::new(Vector2::new(0.5, 1.0), Vector2::new(2.0, 3.0))),
+            "Rect2(0.5, 1, 2, 3)",
+        );
+        roundtrip(
+            TextValue::Color(Color::from_rgba(1.0, 0.5, 0.25, 0.75)),
+            "Color(1, 0.5, 0.25, 0.75)",
+        );
+
+        // Basis is written by rows, Transform2D by columns.
+        roundtrip(
+            TextValue::Basis(Basis::from_cols(
+                Vector3::new(1.0, 2.0, 3.0),
+                Vector3::new(4.0, 5.0, 6.0),
+                Vector3::new(7.0, 8.0, 9.0),
+            )),
+            "Basis(1, 4, 7, 2, 5, 8, 3, 6, 9)",
+        );
+        roundtrip(
+            TextValue::Transform2D(Transform2D::from_cols(
+                Vector2::new(1.0, 2.0),
+                Vector2::new(3.0, 4.0),
+                Vector2::new(5.0, 6.0),
+            )),
+            "Transform2D(1, 2, 3, 4, 5, 6)",
+        );
+
+        roundtrip(TextValue::Rid(Rid::new(42)), "RID(42)");
+        roundtrip(TextValue::Rid(Rid::Invalid), "RID()");
+        roundtrip(TextValue::Callable, "Callable()");
+        roundtrip(
+            TextValue::Resource("res://a.tres".into()),
+            "Resource(\"res://a.tres\")",
+        );
+    }
+
+    #[test]
+    fn write_packed_byte_array() {
+        roundtrip(
+            TextValue::PackedByteArray(vec![0, 1, 254, 255]),
+            "PackedByteArray(0, 1, 254, 255)",
+        );
+
+        // Base64 is parsed, but written as list like `var_to_str()`.
+        let value: TextValue = "PackedByteArray(\"AAH+/w==\")".parse().unwrap();
+        assert_eq!(value.to_string(), "PackedByteArray(0, 1, 254, 255)");
+    }
+
+    #[test]
+    fn accessors() {
+        assert_eq!(TextValue::Bool(true).as_bool(), Some(true));
+        assert_eq!(TextValue::Int(3).as_int(), Some(3));
+        assert_eq!(TextValue::Int(3).as_float(), Some(3.0));
+        assert_eq!(TextValue::Float(3.0).as_int(), None);
+        assert_eq!(TextValue::NodePath("a".into()).as_str(), Some("a"));
+        assert_eq!(TextValue::Nil.as_str(), None);
+
+        let typed = TextValue::TypedArray {
+            element_type: TextElementType::Named("int".into()),
+            elements: vec![TextValue::Int(1)],
+        };
+        assert_eq!(typed.as_array(), Some(&[TextValue::Int(1)][..]));
+        assert_eq!(
+            TextValue::Dictionary(Vec::new()).as_dictionary(),
+            Some(&[][..])
+        );
+    }
+}
@@ -9,6 +9,73 @@
 
 pub use crate::{tr, tr_n};
 
+/// Message of a [`tr!`] or [`tr_n!`] invocation, recorded for [`TranslationTemplate`][super::TranslationTemplate].
+///
+/// With the `translation-template` feature, messages are recorded when the library is loaded, for every macro invocation in the code --
+/// regardless of whether it is executed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TranslationMessage {
+    context: Option<&'static str>,
+    singular: &'static str,
+    plural: Option<&'static str>,
+    file: &'static str,
+    line: u32,
+}
+
+impl TranslationMessage {
+    #[doc(hidden)]
+    pub const fn new(
+        context: Option<&'static str>,
+        singular: &'static str,
+        plural: Option<&'static str>,
+        file: &'static str,
+        line: u32,
+    ) -> Self {
+        Self {
+            context,
+            singular,
+            plural,
+            file,
+            line,
+        }
+    }
+
+    /// All messages recorded in the library, sorted by source location.
+    #[cfg(feature = "translation-template")]
+    pub fn all() -> Vec<TranslationMessage> {
+        let mut messages = Vec::new();
+        crate::private::iterate_tr_messages(|message| messages.push(message.clone()));
+
+        messages.sort_by_key(|message| (message.file, message.line));
+        messages
+    }
+
+    /// Translation context, if a string literal was passed.
+    pub fn context(&self) -> Option<&'static str> {
+        self.context
+    }
+
+    /// Format string of the message, or of its singular form for `tr_n!`.
+    pub fn singular(&self) -> &'static str {
+        self.singular
+    }
+
+    /// Format string of the plural form, for `tr_n!`.
+    pub fn plural(&self) -> Option<&'static str> {
+        self.plural
+    }
+
+    /// Source file of the macro invocation, as returned by [`file!()`].
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+
+    /// Line of the macro invocation.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+}
+
 /// A convenience macro for using the [`Object::tr()`](crate::classes::Object::tr()) and [`Object::tr_ex()`](crate::classes::Object::tr_ex())
 ///  methods.
 ///
@@ -34,17 +101,16 @@ pub use crate::{tr, tr_n};
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
+/// With the `translation-template` feature, messages are recorded as [`TranslationMessage`][crate::tools::TranslationMessage]s, so they
+/// can be extracted into a POT file with [`TranslationTemplate`][crate::tools::TranslationTemplate]. Messages whose context is not a
+/// literal are not recorded.
+///
 /// See also: [Translation contexts](https://docs.godotengine.org/en/stable/tutorials/i18n/internationalizing_games.html#translation-contexts)
 /// in Godot.
 #[macro_export]
 macro_rules! tr {
-    ($fmt:literal $(, $($args:tt)*)?) => {{
-        let msg = format!($fmt $(, $($args)*)?);
-
-        $crate::classes::Engine::singleton().tr(&msg)
-    }};
-
-    ($context:expr; $fmt:literal $(, $($args:tt)*)?) => {{
+    // Internal: without recording.
+    (@dynamic $context:expr; $fmt:literal $(, $($args:tt)*)?) => {{
         let msg = format!($fmt $(, $($args)*)?);
         let context = format!("{}", $context);
 
@@ -53,6 +119,22 @@ macro_rules! tr {
             .context(&context)
             .done()
     }};
+
+    ($fmt:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_record!(None, $fmt, None);
+        let msg = format!($fmt $(, $($args)*)?);
+
+        $crate::classes::Engine::singleton().tr(&msg)
+    }};
+
+    ($context:literal; $fmt:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_record!(Some(concat!($context)), $fmt, None);
+        $crate::tr!(@dynamic $context; $fmt $(, $($args)*)?)
+    }};
+
+    ($context:expr; $fmt:literal $(, $($args:tt)*)?) => {
+        $crate::tr!(@dynamic $context; $fmt $(, $($args)*)?)
+    };
 }
 
 /// A convenience macro for using the [`Object::tr_n()`](crate::classes::Object::tr_n()) and
@@ -82,27 +164,66 @@ macro_rules! tr {
 /// ```
 /// The methods are called from the [`Engine`](crate::classes::Engine) singleton.
 ///
+/// Like with [`tr!`], messages are recorded for [`TranslationTemplate`][crate::tools::TranslationTemplate] if the `translation-template`
+/// feature is enabled.
+///
 /// See also: [Translation contexts](https://docs.godotengine.org/en/stable/tutorials/i18n/internationalizing_games.html#translation-contexts)
 /// in Godot.
 #[macro_export]
 macro_rules! tr_n {
-    ($n:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {
+    // Internal: without recording.
+    (@dynamic $n:expr, $context:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {
         $crate::classes::Engine::singleton()
-            .tr_n(
+            .tr_n_ex(
                 &format!($singular$(, $($args)*)?),
                 &format!($plural$(, $($args)*)?),
                 $n,
             )
+            .context(&format!("{}", $context))
+            .done()
     };
 
-    ($n:expr, $context:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {
+    ($n:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_record!(None, $singular, Some(concat!($plural)));
+
         $crate::classes::Engine::singleton()
-            .tr_n_ex(
+            .tr_n(
                 &format!($singular$(, $($args)*)?),
                 &format!($plural$(, $($args)*)?),
                 $n,
             )
-            .context(&format!("{}", $context))
-            .done()
+    }};
+
+    ($n:expr, $context:literal; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {{
+        $crate::__tr_record!(Some(concat!($context)), $singular, Some(concat!($plural)));
+        $crate::tr_n!(@dynamic $n, $context; $singular, $plural $(, $($args)*)?)
+    }};
+
+    ($n:expr, $context:expr; $singular:literal, $plural:literal $(, $($args:tt)*)?) => {
+        $crate::tr_n!(@dynamic $n, $context; $singular, $plural $(, $($args)*)?)
     };
 }
+
+/// Records a message for `TranslationMessage::all()`.
+#[cfg(feature = "translation-template")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tr_record {
+    ($context:expr, $singular:literal, $plural:expr) => {
+        $crate::sys::plugin_add!($crate::private::__GODOT_TR_REGISTRY; $crate::tools::TranslationMessage::new(
+            $context,
+            concat!($singular),
+            $plural,
+            file!(),
+            line!(),
+        ));
+    };
+}
+
+/// Without the `translation-template` feature, messages are not recorded. Generates no code (in particular, no static constructor).
+#[cfg(not(feature = "translation-template"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tr_record {
+    ($context:expr, $singular:literal, $plural:expr) => {};
+}
@@ -0,0 +1,723 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::io::ErrorKind;
+use std::path::Path;
+
+use crate::tools::TranslationMessage;
+
+const DEFAULT_HEADER: &str = r#"# LANGUAGE translation for this project.
+# This file is distributed under the same license as the project.
+#
+# FIRST AUTHOR <EMAIL@ADDRESS>, YEAR.
+#
+#, fuzzy
+msgid ""
+msgstr ""
+"Project-Id-Version: \n"
+"MIME-Version: 1.0\n"
+"Content-Type: text/plain; charset=UTF-8\n"
+"Content-Transfer-Encoding: 8-bit\n""#;
+
+/// Translation template (POT file) with the messages of [`tr!`][crate::tools::tr] and [`tr_n!`][crate::tools::tr_n].
+///
+/// Godot's POT generation only scans scenes and scripts, so messages from Rust code are missing from it. `TranslationTemplate` collects
+/// the messages [recorded](TranslationMessage) by the macros, including their source locations, and writes them in gettext format.
+/// Existing POT files, for example generated by the Godot editor, can be merged with them.
+///
+/// Only messages with a static message ID are included:
+/// - Format strings with placeholders (`"Score: {points}"`) are skipped, since Godot looks up the _formatted_ string, which is not known
+///   in advance. Escaped braces (`{{` and `}}`) are fine.
+/// - Messages whose context is not a literal are not recorded by the macros in the first place.
+///
+/// Recording requires the `translation-template` feature, and happens when the library is loaded. The template does not need a running
+/// engine, so it can be written from a plain `#[test]` function in the GDExtension library crate, which `cargo test` compiles into a
+/// binary together with all `tr!` invocations of the crate:
+/// ```no_run
+/// #[test]
+/// fn update_translation_template() {
+///     use godot::tools::TranslationTemplate;
+///
+///     TranslationTemplate::update_file("../godot/translations/messages.pot")
+///         .expect("update POT file");
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct TranslationTemplate {
+    header: String,
+    blocks: Vec<Block>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Block {
+    Entry(TemplateEntry),
+
+    /// Lines of a block without message, e.g. obsolete entries (`#~ msgid ...`). Kept as-is.
+    Comments(Vec<String>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct TemplateEntry {
+    comments: Vec<String>,
+    references: Vec<String>,
+    context: Option<String>,
+    message: String,
+    plural: Option<String>,
+}
+
+impl TranslationTemplate {
+    /// Creates a template without messages, with a default header.
+    pub fn new() -> Self {
+        Self {
+            header: DEFAULT_HEADER.to_string(),
+            blocks: Vec::new(),
+        }
+    }
+
+    /// Creates a template with all messages recorded by `tr!` and `tr_n!`.
+    #[cfg(feature = "translation-template")]
+    pub fn from_recorded() -> Self {
+        let mut template = Self::new();
+        for message in TranslationMessage::all() {
+            template.add_message(&message);
+        }
+
+        template
+    }
+
+    /// Parses a template in gettext POT format.
+    ///
+    /// Translations (`msgstr`) are ignored. Comments, flags and source references are kept, as are blocks consisting only of comments.
+    pub fn parse(pot: &str) -> std::io::Result<Self> {
+        let mut template = Self::new();
+        let mut parser = EntryParser::default();
+
+        // Trailing empty line terminates the last entry.
+        for (index, line) in pot.lines().chain(std::iter::once("")).enumerate() {
+            let line = line.trim();
+            if !line.is_empty() {
+                parser.parse_line(line, index + 1)?;
+                continue;
+            }
+
+            match parser.finish(index + 1)? {
+                None => {}
+                Some(Block::Entry(entry))
+                    if entry.message.is_empty() && entry.context.is_none() =>
+                {
+                    template.header = header_block(pot, index);
+                }
+                Some(Block::Entry(entry)) => template.add_entry(entry),
+                Some(block) => template.blocks.push(block),
+            }
+        }
+
+        Ok(template)
+    }
+
+    /// Reads and parses a POT file. See [`parse()`](Self::parse).
+    pub fn read(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let pot = std::fs::read_to_string(path)?;
+        Self::parse(&pot)
+    }
+
+    /// Writes the template to a file, replacing existing contents.
+    pub fn write(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+
+    /// Merges the recorded messages into a POT file, or creates it if it does not exist.
+    ///
+    /// Shorthand for `TranslationTemplate::from_recorded().merge_into_file(path)`, see [`merge_into_file()`](Self::merge_into_file).
+    #[cfg(feature = "translation-template")]
+    pub fn update_file(path: impl AsRef<Path>) -> std::io::Result<()> {
+        Self::from_recorded().merge_into_file(path)
+    }
+
+    /// Merges the messages of `self` into a POT file, or creates it if it does not exist.
+    ///
+    /// Other entries and references of the file are kept, while references to Rust source files (`*.rs`) are replaced with the ones in
+    /// `self`. Entries only referenced from Rust are removed if `self` no longer contains the message. The file is only written if its
+    /// contents change.
+    pub fn merge_into_file(self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+
+        let (mut template, existing) = match std::fs::read_to_string(path) {
+            Ok(pot) => (Self::parse(&pot)?, Some(pot)),
+            Err(err) if err.kind() == ErrorKind::NotFound => (Self::new(), None),
+            Err(err) => return Err(err),
+        };
+
+        template.remove_references(is_rust_reference);
+        template.merge(self);
+
+        let pot = template.to_string();
+        if existing.as_ref() == Some(&pot) {
+            return Ok(());
+        }
+
+        std::fs::write(path, pot)
+    }
+
+    /// Adds a recorded message. Returns `false` if the message is not static (see type-level docs) and was skipped.
+    pub fn add_message(&mut self, message: &TranslationMessage) -> bool {
+        let Some(text) = static_message(message.singular()) else {
+            return false;
+        };
+
+        let plural = match message.plural().map(static_message) {
+            Some(None) => return false,
+            Some(Some(plural)) => Some(plural),
+            None => None,
+        };
+
+        let file = message.file().replace('\\', "/");
+
+        self.add_entry(TemplateEntry {
+            comments: Vec::new(),
+            references: vec![format!("{file}:{}", message.line())],
+            context: message.context().map(String::from),
+            message: text,
+            plural,
+        });
+
+        true
+    }
+
+    /// Adds all entries of `other`. Entries with the same message and context are combined.
+    ///
+    /// The header of `self` is kept. Comment-only blocks of `other` are added, unless `self` has an identical block.
+    pub fn merge(&mut self, other: TranslationTemplate) {
+        for block in other.blocks {
+            match block {
+                Block::Entry(entry) => self.add_entry(entry),
+                block if !self.blocks.contains(&block) => self.blocks.push(block),
+                _ => {}
+            }
+        }
+    }
+
+    /// Number of messages.
+    pub fn len(&self) -> usize {
+        self.entries().count()
+    }
+
+    /// Returns `true` if the template has no messages.
+    pub fn is_empty(&self) -> bool {
+        self.entries().next().is_none()
+    }
+
+    /// Returns `true` if the template has a message with the given message ID and context.
+    pub fn contains(&self, message: &str, context: Option<&str>) -> bool {
+        self.entries().any(|entry| entry.matches(message, context))
+    }
+
+    // ----------------------------------------------------------------------------------------------------------------------------------------------
+    // Private methods.
+
+    fn entries(&self) -> impl Iterator<Item = &TemplateEntry> {
+        self.blocks.iter().filter_map(|block| match block {
+            Block::Entry(entry) => Some(entry),
+            Block::Comments(_) => None,
+        })
+    }
+
+    fn add_entry(&mut self, entry: TemplateEntry) {
+        let existing = self.blocks.iter_mut().find_map(|block| match block {
+            Block::Entry(existing)
+                if existing.matches(&entry.message, entry.context.as_deref()) =>
+            {
+                Some(existing)
+            }
+            _ => None,
+        });
+
+        let Some(existing) = existing else {
+            self.blocks.push(Block::Entry(entry));
+            return;
+        };
+
+        for reference in entry.references {
+            if !existing.references.contains(&reference) {
+                existing.references.push(reference);
+            }
+        }
+
+        for comment in entry.comments {
+            if !existing.comments.contains(&comment) {
+                existing.comments.push(comment);
+            }
+        }
+
+        if existing.plural.is_none() {
+            existing.plural = entry.plural;
+        }
+    }
+
+    /// Removes references matching `predicate`, and entries that had only such references.
+    fn remove_references(&mut self, predicate: impl Fn(&str) -> bool) {
+        self.blocks.retain_mut(|block| {
+            let Block::Entry(entry) = block else {
+                return true;
+            };
+
+            if entry.references.is_empty() {
+                return true;
+            }
+
+            entry.references.retain(|reference| !predicate(reference));
+            !entry.references.is_empty()
+        });
+    }
+}
+
+impl TemplateEntry {
+    fn matches(&self, message: &str, context: Option<&str>) -> bool {
+        self.message == message && self.context.as_deref() == context
+    }
+}
+
+impl Default for TranslationTemplate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Formats the template in gettext POT format.
+impl fmt::Display for TranslationTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.header)?;
+
+        for block in &self.blocks {
+            writeln!(f)?;
+
+            let entry = match block {
+                Block::Entry(entry) => entry,
+                Block::Comments(lines) => {
+                    for line in lines {
+                        writeln!(f, "{line}")?;
+                    }
+                    continue;
+                }
+            };
+
+            for comment in &entry.comments {
+                writeln!(f, "{comment}")?;
+            }
+
+            for reference in &entry.references {
+                writeln!(f, "#: {reference}")?;
+            }
+
+            if let Some(context) = &entry.context {
+                writeln!(f, "msgctxt \"{}\"", escape(context))?;
+            }
+
+            writeln!(f, "msgid \"{}\"", escape(&entry.message))?;
+
+            match &entry.plural {
+                Some(plural) => {
+                    writeln!(f, "msgid_plural \"{}\"", escape(plural))?;
+                    writeln!(f, "msgstr[0] \"\"")?;
+                    writeln!(f, "msgstr[1] \"\"")?;
+                }
+                None => writeln!(f, "msgstr \"\"")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Parsing
+
+#[derive(Copy, Clone)]
+enum Field {
+    Context,
+    Message,
+    Plural,
+    Translation,
+}
+
+#[derive(Default)]
+struct EntryParser {
+    entry: Option<TemplateEntry>,
+    last_field: Option<Field>,
+}
+
+impl EntryParser {
+    fn parse_line(&mut self, line: &str, line_number: usize) -> std::io::Result<()> {
+        let entry = self.entry.get_or_insert_with(|| TemplateEntry {
+            comments: Vec::new(),
+            references: Vec::new(),
+            context: None,
+            message: String::new(),
+            plural: None,
+        });
+
+        if let Some(references) = line.strip_prefix("#:") {
+            entry
+                .references
+                .extend(references.split_whitespace().map(String::from));
+            return Ok(());
+        }
+
+        if line.starts_with('#') {
+            entry.comments.push(line.to_string());
+            return Ok(());
+        }
+
+        let (field, quoted) = if line.starts_with('"') {
+            let field = self
+                .last_field
+                .ok_or_else(|| parse_error(line_number, "string without keyword"))?;
+            (field, line)
+        } else {
+            let (keyword, rest) = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| parse_error(line_number, "expected keyword and string"))?;
+
+            let field = match keyword {
+                "msgctxt" => Field::Context,
+                "msgid" => Field::Message,
+                "msgid_plural" => Field::Plural,
+                _ if keyword.starts_with("msgstr") => Field::Translation,
+                _ => return Err(parse_error(line_number, "unknown keyword")),
+            };
+            (field, rest.trim_start())
+        };
+
+        let text = unquote(quoted).ok_or_else(|| parse_error(line_number, "invalid string"))?;
+        let target = match field {
+            Field::Context => entry.context.get_or_insert_with(String::new),
+            Field::Message => &mut entry.message,
+            Field::Plural => entry.plural.get_or_insert_with(String::new),
+            Field::Translation => {
+                self.last_field = Some(field);
+                return Ok(());
+            }
+        };
+
+        target.push_str(&text);
+        self.last_field = Some(field);
+        Ok(())
+    }
+
+    /// Returns the block that ended at the current empty line, if any.
+    fn finish(&mut self, line_number: usize) -> std::io::Result<Option<Block>> {
+        let last_field = self.last_field.take();
+        let Some(entry) = self.entry.take() else {
+            return Ok(None);
+        };
+
+        match last_field {
+            None => {
+                let mut lines = entry.comments;
+                lines.extend(
+                    entry
+                        .references
+                        .iter()
+                        .map(|reference| format!("#: {reference}")),
+                );
+                Ok(Some(Block::Comments(lines)))
+            }
+            Some(Field::Translation) => Ok(Some(Block::Entry(entry))),
+            Some(_) => Err(parse_error(line_number, "entry without msgstr")),
+        }
+    }
+}
+
+/// Lines of the header entry, which ends before `end_line` (0-based).
+fn header_block(pot: &str, end_line: usize) -> String {
+    let lines: Vec<&str> = pot.lines().take(end_line).collect();
+    let start = lines
+        .iter()
+        .rposition(|line| line.trim().is_empty())
+        .map_or(0, |pos| pos + 1);
+
+    lines[start..].join("\n")
+}
+
+fn parse_error(line_number: usize, message: &str) -> std::io::Error {
+    std::io::Error::new(
+        ErrorKind::InvalidData,
+        format!("invalid POT file, line {line_number}: {message}"),
+    )
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Helpers
+
+/// Message ID that Godot looks up for a format string, or `None` if it has placeholders.
+fn static_message(format_string: &str) -> Option<String> {
+    let mut result = String::with_capacity(format_string.len());
+    let mut chars = format_string.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' if chars.peek() == Some(&c) => {
+                chars.next();
+                result.push(c);
+            }
+            '{' => return None,
+            c => result.push(c),
+        }
+    }
+
+    Some(result)
+}
+
+fn is_rust_reference(reference: &str) -> bool {
+    let file = reference
+        .rsplit_once(':')
+        .filter(|(_, line)| line.bytes().all(|b| b.is_ascii_digit()))
+        .map_or(reference, |(file, _)| file);
+
+    file.ends_with(".rs")
+}
+
+fn escape(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '"' => result.push_str("\\\""),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c => result.push(c),
+        }
+    }
+
+    result
+}
+
+fn unquote(quoted: &str) -> Option<String> {
+    let inner = quoted.strip_prefix('"')?.strip_suffix('"')?;
+
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            'r' => result.push('\r'),
+            't' => result.push('\t'),
+            c @ ('\\' | '"') => result.push(c),
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded(messages: &[TranslationMessage]) -> TranslationTemplate {
+        let mut template = TranslationTemplate::new();
+        for message in messages {
+            template.add_message(message);
+        }
+
+        template
+    }
+
+    #[test]
+    fn template_add_message() {
+        let mut template = TranslationTemplate::new();
+
+        let files = TranslationMessage::new(
+            Some("menu"),
+            "{{1}} file",
+            Some("{{n}} files"),
+            "src\\menu.rs",
+            3,
+        );
+        assert!(template.add_message(&files));
+
+        let score = TranslationMessage::new(None, "Score: {points}", None, "src/hud.rs", 7);
+        assert!(!template.add_message(&score));
+
+        let more = TranslationMessage::new(None, "One", Some("{n} more"), "src/hud.rs", 8);
+        assert!(!template.add_message(&more));
+
+        assert_eq!(template.len(), 1);
+        assert!(template.contains("{1} file", Some("menu")));
+        assert!(template.to_string().ends_with(
+            "\n#: src/menu.rs:3\nmsgctxt \"menu\"\nmsgid \"{1} file\"\nmsgid_plural \"{n} files\"\nmsgstr[0] \"\"\nmsgstr[1] \"\"\n"
+        ));
+    }
+
+    #[test]
+    fn template_parse_and_merge() {
+        let existing = r#"# Generated by the editor.
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+
+#: res://main.tscn
+msgid "Start"
+msgstr ""
+
+#: res://main.tscn src/old.rs:3
+msgid "Quit"
+msgstr ""
+
+#: src/removed.rs:7
+msgctxt "menu"
+msgid "Old "
+"entry"
+msgstr ""
+"#;
+
+        let mut template = TranslationTemplate::parse(existing).unwrap();
+        assert_eq!(template.len(), 3);
+        assert!(template.contains("Old entry", Some("menu")));
+
+        let mut other = TranslationTemplate::new();
+        other.merge(
+            TranslationTemplate::parse("#: src/lib.rs:1\nmsgid \"Quit\"\nmsgstr \"\"\n").unwrap(),
+        );
+        template.merge(other);
+
+        let pot = template.to_string();
+        assert!(pot.starts_with("# Generated by the editor.\nmsgid \"\"\n"));
+        assert!(
+            pot.contains("#: res://main.tscn\n#: src/old.rs:3\n#: src/lib.rs:1\nmsgid \"Quit\"")
+        );
+
+        // Roundtrip.
+        let reparsed = TranslationTemplate::parse(&pot).unwrap();
+        assert_eq!(reparsed.to_string(), pot);
+    }
+
+    #[test]
+    fn template_parse_escapes() {
+        let template = TranslationTemplate::parse(
+            "msgid \"Line \"\n\"two\\n\\\"quoted\\\"\\t\\\\\"\nmsgstr \"\"\n",
+        )
+        .unwrap();
+
+        assert!(template.contains("Line two\n\"quoted\"\t\\", None));
+        assert!(template
+            .to_string()
+            .ends_with("\nmsgid \"Line two\\n\\\"quoted\\\"\\t\\\\\"\nmsgstr \"\"\n"));
+    }
+
+    #[test]
+    fn template_parse_keeps_comment_blocks() {
+        let pot = "# Header\nmsgid \"\"\nmsgstr \"\"\n\n\
+                   #: src/lib.rs:1\nmsgid \"Quit\"\nmsgstr \"\"\n\n\
+                   # Obsolete messages.\n#~ msgid \"Old\"\n#~ msgstr \"Alt\"\n";
+
+        let template = TranslationTemplate::parse(pot).unwrap();
+        assert_eq!(template.len(), 1);
+        assert_eq!(template.to_string(), pot);
+
+        // Merging does not duplicate entries or comment blocks.
+        let mut merged = template.clone();
+        merged.merge(template);
+        assert_eq!(merged.to_string(), pot);
+    }
+
+    #[test]
+    fn template_parse_errors() {
+        let error = |pot: &str| TranslationTemplate::parse(pot).unwrap_err().to_string();
+
+        assert!(error("msgid \"unterminated\n").contains("line 1: invalid string"));
+        assert!(error("msgid \"\\q\"\nmsgstr \"\"\n").contains("line 1: invalid string"));
+        assert!(error("\"orphan\"\n").contains("line 1: string without keyword"));
+        assert!(error("msgid\n").contains("line 1: expected keyword and string"));
+        assert!(error("msgfoo \"a\"\n").contains("line 1: unknown keyword"));
+        assert!(error("msgid \"a\"\n").contains("line 2: entry without msgstr"));
+    }
+
+    #[test]
+    fn template_remove_references() {
+        let mut template = TranslationTemplate::parse(
+            "#: res://main.tscn src/menu.rs:1\nmsgid \"Both\"\nmsgstr \"\"\n\n\
+             #: src/menu.rs:2 C:/game/src/hud.rs\nmsgid \"Rust\"\nmsgstr \"\"\n\n\
+             msgid \"Unreferenced\"\nmsgstr \"\"\n\n\
+             #~ msgid \"Obsolete\"\n",
+        )
+        .unwrap();
+
+        template.remove_references(is_rust_reference);
+
+        assert!(template.contains("Both", None));
+        assert!(!template.contains("Rust", None));
+        assert!(template.contains("Unreferenced", None));
+
+        let pot = template.to_string();
+        assert!(pot.contains("\n#: res://main.tscn\nmsgid \"Both\""));
+        assert!(!pot.contains(".rs"));
+        assert!(pot.ends_with("\n#~ msgid \"Obsolete\"\n"));
+    }
+
+    #[test]
+    fn template_merge_into_file() {
+        let path = std::env::temp_dir().join(format!(
+            "godot_translation_template_test_{}.pot",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        // File is created if missing.
+        recorded(&[TranslationMessage::new(
+            None,
+            "Quit",
+            None,
+            "src/menu.rs",
+            3,
+        )])
+        .merge_into_file(&path)
+        .unwrap();
+        let created = std::fs::read_to_string(&path).unwrap();
+        assert!(created.starts_with(DEFAULT_HEADER));
+        assert!(created.ends_with("\n#: src/menu.rs:3\nmsgid \"Quit\"\nmsgstr \"\"\n"));
+
+        // Entries from the editor are kept, Rust references are replaced.
+        std::fs::write(
+            &path,
+            "# Editor header\nmsgid \"\"\nmsgstr \"\"\n\n\
+             #: res://main.tscn src/menu.rs:3\nmsgid \"Quit\"\nmsgstr \"\"\n\n\
+             #: src/menu.rs:4\nmsgid \"Start\"\nmsgstr \"\"\n",
+        )
+        .unwrap();
+
+        let messages = [
+            TranslationMessage::new(None, "Quit", None, "src/menu.rs", 10),
+            TranslationMessage::new(None, "Options", None, "src/options.rs", 1),
+        ];
+        recorded(&messages).merge_into_file(&path).unwrap();
+
+        let expected = "# Editor header\nmsgid \"\"\nmsgstr \"\"\n\n\
+                        #: res://main.tscn\n#: src/menu.rs:10\nmsgid \"Quit\"\nmsgstr \"\"\n\n\
+                        #: src/options.rs:1\nmsgid \"Options\"\nmsgstr \"\"\n";
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), expected);
+
+        // Unchanged contents are not written again.
+        let modified = std::fs::metadata(&path).unwrap().modified().unwrap();
+        recorded(&messages).merge_into_file(&path).unwrap();
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().modified().unwrap(),
+            modified
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -15,6 +15,18 @@ pub struct ConstDefinition {
     pub raw_constant: venial::Constant,
 }
 
+/// `#[export_enum] type Name = RustType;` inside `#[godot_api]`.
+pub struct ExportEnumDefinition {
+    /// Name of the enum inside the class, as seen by Godot.
+    pub name: Ident,
+
+    /// Rust enum or bitfield type.
+    pub ty: venial::TypeExpr,
+
+    /// `#[cfg]` attributes of the declaration.
+    pub cfg_attrs: Vec<venial::Attribute>,
+}
+
 pub fn make_constant_registration(
     consts: Vec<ConstDefinition>,
     class_name: &Ident,
@@ -70,3 +82,46 @@ pub fn make_constant_registration(
 
     Ok(tokens)
 }
+
+/// Generates registration of class-scoped enums.
+///
+/// Returns the constant registration (run together with other constants) and the plugin registration linking each type to the class.
+pub fn make_export_enum_registration(
+    enums: &[ExportEnumDefinition],
+    class_name: &Ident,
+    class_name_obj: &TokenStream,
+) -> (TokenStream, TokenStream) {
+    let prv = quote! { ::godot::private };
+
+    let mut constant_registrations = Vec::new();
+    let mut plugin_registrations = Vec::new();
+
+    for enum_ in enums {
+        let ExportEnumDefinition {
+            name,
+            ty,
+            cfg_attrs,
+        } = enum_;
+        let name_str = name.to_string();
+
+        constant_registrations.push(quote! {
+            #(#cfg_attrs)*
+            ::godot::register::private::constant::ExportConstant::new(
+                #class_name_obj,
+                ::godot::register::private::constant::ConstantKind::from_export_enum::<#ty>(#name_str)
+            ).register();
+        });
+
+        plugin_registrations.push(quote! {
+            #(#cfg_attrs)*
+            ::godot::sys::plugin_add!(#prv::__GODOT_PLUGIN_REGISTRY; #prv::ClassPlugin::new::<#class_name>(
+                #prv::PluginItem::ClassEnum(#prv::ClassEnum::new::<#ty>(#name_str))
+            ));
+        });
+    }
+
+    (
+        quote! { #(#constant_registrations)* },
+        quote! { #(#plugin_registrations)* },
+    )
+}
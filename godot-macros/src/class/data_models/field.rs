@@ -9,17 +9,22 @@ use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
 
 use crate::class::data_models::group_export::FieldGroup;
-use crate::class::{FieldExport, FieldVar};
+use crate::class::{FieldExport, FieldOnChange, FieldVar};
 use crate::util::{error, KvParser};
 
 pub struct Field {
     pub name: Ident,
     pub ty: venial::TypeExpr,
+    pub vis_marker: Option<venial::VisMarker>,
     pub default_val: Option<FieldDefault>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
     pub group: Option<FieldGroup>,
     pub subgroup: Option<FieldGroup>,
+    /// Change-notification hooks, from either `#[var]` or `#[export]`.
+    pub on_change: Option<FieldOnChange>,
+    /// Method invoked by an `#[export_tool_button]`.
+    pub tool_button: Option<Ident>,
     pub is_onready: bool,
     pub is_oneditor: bool,
     pub is_phantomvar: bool,
@@ -33,11 +38,14 @@ impl Field {
         Self {
             name: field.name.clone(),
             ty: field.ty.clone(),
+            vis_marker: field.vis_marker.clone(),
             default_val: None,
             var: None,
             export: None,
             group: None,
             subgroup: None,
+            on_change: None,
+            tool_button: None,
             is_onready: false,
             is_oneditor: false,
             is_phantomvar: false,
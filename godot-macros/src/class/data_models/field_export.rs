@@ -32,6 +32,23 @@ impl FieldExport {
     pub fn to_export_usage(&self) -> Option<Ident> {
         self.export_type.to_export_usage()
     }
+
+    /// Parse an `#[export_tool_button(fn = ..., text = ..., icon = ...)]` attribute.
+    ///
+    /// Returns the export together with the method invoked when the button is pressed.
+    pub(crate) fn new_tool_button_from_kv(parser: &mut KvParser) -> ParseResult<(Self, Ident)> {
+        let span = parser.span();
+        let method = parser.handle_ident_required("fn")?;
+        let text = parser.handle_expr_required("text")?;
+        let icon = parser.handle_expr("icon")?;
+
+        let export = Self {
+            export_type: ExportType::ToolButton { text, icon },
+            span,
+        };
+
+        Ok((export, method))
+    }
 }
 
 /// Store info from `#[export]` attribute.
@@ -155,6 +172,21 @@ pub enum ExportType {
     /// ### Property hints
     /// - `COLOR_NO_ALPHA`
     ColorNoAlpha,
+
+    /// ### GDScript annotations
+    /// - `@export_tool_button`
+    ///
+    /// ### Property hints
+    /// - `TOOL_BUTTON`
+    ///
+    /// ### Property usage
+    /// - `EDITOR`
+    ///
+    /// Not parsed from `#[export]`, but from the separate `#[export_tool_button]` attribute, which also names the method to invoke.
+    ToolButton {
+        text: TokenStream,
+        icon: Option<TokenStream>,
+    },
 }
 
 impl ExportType {
@@ -546,6 +578,11 @@ impl ExportType {
             },
 
             Self::ColorNoAlpha => quote_export_func! { export_color_no_alpha() },
+
+            Self::ToolButton { text, icon } => {
+                let icon = icon.clone().unwrap_or(quote! { "" });
+                quote_export_func! { export_tool_button(#text, #icon) }
+            }
         }
     }
 
@@ -553,6 +590,7 @@ impl ExportType {
     pub fn to_export_usage(&self) -> Option<Ident> {
         match self {
             Self::Storage => Some(ident("STORAGE")),
+            Self::ToolButton { .. } => Some(ident("EDITOR")),
             _ => None,
         }
     }
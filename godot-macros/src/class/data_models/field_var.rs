@@ -12,7 +12,10 @@ use crate::class::{
     into_signature_info, make_existence_check, make_method_registration, Field, FieldHint,
     FuncDefinition,
 };
-use crate::util::{make_funcs_collection_constant, KvParser};
+use crate::util::{
+    format_funcs_collection_constant, format_funcs_collection_struct,
+    make_funcs_collection_constant, require_api_version, KvParser,
+};
 use crate::{util, ParseResult};
 
 /// Store info from `#[var]` attribute.
@@ -33,7 +36,9 @@ impl FieldVar {
     /// - `set = expr`
     /// - `hint = ident`
     /// - `hint_string = expr`
-    /// - `usage_flags =
+    /// - `usage_flags = [flags...]`
+    ///
+    /// Change-notification keys (`on_change`, ...) are handled separately by [`FieldOnChange`].
     pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Self> {
         let span = parser.span();
         let getter = GetterSetter::parse(parser, "get")?;
@@ -93,6 +98,65 @@ impl Default for FieldVar {
     }
 }
 
+/// Store info from the change-notification keys in `#[var]` or `#[export]`.
+#[derive(Clone, Debug)]
+pub struct FieldOnChange {
+    /// User method invoked as `self.method(old, new)` after the field has been assigned.
+    pub callback: Option<Ident>,
+
+    /// Whether `notify_property_list_changed()` is called after the field has been assigned.
+    pub notify_property_list: bool,
+
+    /// Whether a `<field>_changed` signal is registered and emitted after the field has been assigned.
+    pub emit_changed: bool,
+
+    pub span: Span,
+}
+
+impl FieldOnChange {
+    /// Parses change-notification keys shared by `#[var]` and `#[export]`.
+    ///
+    /// Possible keys:
+    /// - `on_change = ident`
+    /// - `notify_property_list`
+    /// - `emit_changed`
+    ///
+    /// Returns `None` if none of the keys is present.
+    pub(crate) fn new_from_kv(parser: &mut KvParser) -> ParseResult<Option<Self>> {
+        let span = parser.span();
+        let callback = parser.handle_ident("on_change")?;
+        let notify_property_list = parser.handle_alone("notify_property_list")?;
+
+        let emit_changed = if let Some(key) = parser.handle_alone_with_span("emit_changed")? {
+            require_api_version!("4.2", &key, "#[var(emit_changed)]")?;
+            true
+        } else {
+            false
+        };
+
+        if callback.is_none() && !notify_property_list && !emit_changed {
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            callback,
+            notify_property_list,
+            emit_changed,
+            span,
+        }))
+    }
+
+    /// Whether the generated setter needs access to the `Base<T>` field.
+    pub fn requires_base(&self) -> bool {
+        self.notify_property_list || self.emit_changed
+    }
+
+    /// Name of the per-property signal, e.g. `health_changed`.
+    pub fn signal_name(field_name: &Ident) -> Ident {
+        format_ident!("{field_name}_changed")
+    }
+}
+
 #[derive(Default, Clone, Eq, PartialEq, Debug)]
 pub enum GetterSetter {
     /// Getter/setter should be omitted, field is write/read only.
@@ -198,8 +262,11 @@ impl GetterSetterImpl {
                 signature = quote! {
                     fn #function_name(&mut self, #field_name: <#field_type as ::godot::meta::GodotConvert>::Via)
                 };
-                function_body = quote! {
-                    <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+                function_body = match &field.on_change {
+                    None => quote! {
+                        <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+                    },
+                    Some(on_change) => make_on_change_setter_body(field, on_change),
                 };
             }
         }
@@ -241,6 +308,53 @@ impl GetterSetterImpl {
         }
     }
 
+    /// Getter of an `#[export_tool_button]` field, returning a callable that invokes `method` on this object.
+    pub(super) fn from_tool_button(
+        class_name: &Ident,
+        field: &Field,
+        method: &Ident,
+    ) -> ParseResult<Self> {
+        let function_name = format_ident!("{}{}", GetSet::Get.prefix(), field.name);
+        let funcs_collection = format_funcs_collection_struct(class_name);
+        let method_constant = format_funcs_collection_constant(class_name, method);
+
+        // Going through the funcs collection constant ensures that `method` is a #[func], and respects #[func(rename)].
+        let signature = quote! {
+            fn #function_name(&self) -> ::godot::builtin::Callable
+        };
+        let function_impl = quote! {
+            pub #signature {
+                ::godot::builtin::Callable::from_object_method(
+                    &<Self as ::godot::obj::WithBaseField>::to_gd(self),
+                    #funcs_collection::#method_constant,
+                )
+            }
+        };
+
+        let funcs_collection_constant =
+            make_funcs_collection_constant(class_name, &function_name, None, &[]);
+
+        let signature = util::parse_signature(signature);
+        let export_token = make_method_registration(
+            class_name,
+            FuncDefinition {
+                signature_info: into_signature_info(signature, class_name, false),
+                external_attributes: Vec::new(),
+                registered_name: None,
+                is_script_virtual: false,
+                rpc_info: None,
+            },
+            None,
+        )?;
+
+        Ok(Self {
+            function_name,
+            function_impl,
+            export_token,
+            funcs_collection_constant,
+        })
+    }
+
     fn from_custom_impl(function_name: &Ident) -> Self {
         Self {
             function_name: function_name.clone(),
@@ -251,6 +365,57 @@ impl GetterSetterImpl {
     }
 }
 
+/// Body of a generated setter that assigns the field and then runs the change-notification hooks.
+fn make_on_change_setter_body(field: &Field, on_change: &FieldOnChange) -> TokenStream {
+    let Field {
+        name: field_name,
+        ty: field_type,
+        ..
+    } = field;
+
+    let get_property = quote! {
+        <#field_type as ::godot::register::property::Var>::get_property(&self.#field_name)
+    };
+
+    // Old value is only needed by the user callback; avoid the extra conversion otherwise.
+    let (old_value, callback) = match &on_change.callback {
+        Some(callback) => (
+            quote! { let __old_value = #get_property; },
+            quote! {
+                let __new_value = #get_property;
+                self.#callback(__old_value, __new_value);
+            },
+        ),
+        None => (TokenStream::new(), TokenStream::new()),
+    };
+
+    let notify = if on_change.notify_property_list {
+        quote! {
+            ::godot::obj::WithBaseField::base_mut(self).notify_property_list_changed();
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let emit = if on_change.emit_changed {
+        let signal_name = FieldOnChange::signal_name(field_name);
+        quote! {
+            let __value = #get_property;
+            ::godot::obj::WithUserSignals::signals(self).#signal_name().emit_tuple((__value,));
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    quote! {
+        #old_value
+        <#field_type as ::godot::register::property::Var>::set_property(&mut self.#field_name, #field_name);
+        #callback
+        #notify
+        #emit
+    }
+}
+
 #[derive(Default, Clone, Debug)]
 pub enum UsageFlags {
     /// The usage flags should be inferred based on context.
@@ -10,9 +10,9 @@ use quote::spanned::Spanned;
 use quote::{format_ident, quote, ToTokens};
 
 use crate::class::{
-    into_signature_info, make_constant_registration, make_method_registration,
-    make_signal_registrations, ConstDefinition, FuncDefinition, RpcAttr, RpcMode, SignalDefinition,
-    SignatureInfo, TransferMode,
+    into_signature_info, make_constant_registration, make_export_enum_registration,
+    make_method_registration, make_signal_registrations, ConstDefinition, ExportEnumDefinition,
+    FuncDefinition, ReceiverType, RpcAttr, RpcMode, SignalDefinition, SignatureInfo, TransferMode,
 };
 use crate::util::{
     bail, c_str, format_funcs_collection_struct, ident, make_funcs_collection_constants,
@@ -89,6 +89,7 @@ pub fn transform_inherent_impl(
     // Can add extra functions to the end of the impl block.
     let (funcs, signals) = process_godot_fns(&class_name, &mut impl_block, meta.secondary)?;
     let consts = process_godot_constants(&mut impl_block)?;
+    let export_enums = process_export_enums(&mut impl_block)?;
 
     #[cfg(all(feature = "register-docs", since_api = "4.3"))]
     let docs = crate::docs::document_inherent_impl(&funcs, &consts, &signals);
@@ -122,6 +123,8 @@ pub fn transform_inherent_impl(
         .collect::<ParseResult<Vec<TokenStream>>>()?;
 
     let constant_registration = make_constant_registration(consts, &class_name, &class_name_obj)?;
+    let (enum_registration, enum_plugins) =
+        make_export_enum_registration(&export_enums, &class_name, &class_name_obj);
 
     let method_storage_name = format_ident!("__registration_methods_{class_name}");
     let constants_storage_name = format_ident!("__registration_constants_{class_name}");
@@ -135,6 +138,7 @@ pub fn transform_inherent_impl(
 
             #constants_storage_name.lock().unwrap().push(|| {
                 #constant_registration
+                #enum_registration
             });
         });
     };
@@ -184,6 +188,7 @@ pub fn transform_inherent_impl(
             #trait_impl
             #fill_storage
             #class_registration
+            #enum_plugins
             impl #funcs_collection {
                 #( #func_name_constants )*
             }
@@ -198,6 +203,7 @@ pub fn transform_inherent_impl(
         let result = quote! {
             #impl_block
             #fill_storage
+            #enum_plugins
             impl #funcs_collection {
                 #( #func_name_constants )*
             }
@@ -425,6 +431,50 @@ fn process_godot_constants(decl: &mut venial::Impl) -> ParseResult<Vec<ConstDefi
     Ok(constant_signatures)
 }
 
+/// Extracts `#[export_enum] type Name = Type;` declarations and removes them from the impl block.
+///
+/// Inherent associated types are not stable Rust, so the declarations only exist for the macro.
+fn process_export_enums(decl: &mut venial::Impl) -> ParseResult<Vec<ExportEnumDefinition>> {
+    let mut export_enums = vec![];
+    let mut removed_indexes = vec![];
+
+    for (index, item) in decl.body_items.iter_mut().enumerate() {
+        let venial::ImplMember::AssocType(alias) = item else {
+            continue;
+        };
+
+        let Some(parser) = KvParser::parse(&alias.attributes, "export_enum")? else {
+            continue;
+        };
+        parser.finish()?;
+
+        let Some(ty) = alias.initializer_ty.clone() else {
+            return bail!(
+                &alias.name,
+                "#[export_enum] requires a type, e.g. `type {} = MyEnum;`",
+                alias.name
+            );
+        };
+
+        export_enums.push(ExportEnumDefinition {
+            name: alias.name.clone(),
+            ty,
+            cfg_attrs: util::extract_cfg_attrs(&alias.attributes)
+                .into_iter()
+                .cloned()
+                .collect(),
+        });
+        removed_indexes.push(index);
+    }
+
+    // Remove in reverse order, so indexes stay valid.
+    for index in removed_indexes.into_iter().rev() {
+        decl.body_items.remove(index);
+    }
+
+    Ok(export_enums)
+}
+
 /// Replaces the body of `function` with custom code that performs virtual dispatch.
 ///
 /// Appends the virtual function to `virtual_functions`.
@@ -464,13 +514,20 @@ fn add_virtual_script_call(
     let call_ret = &signature_info.return_type;
     let arg_names = &signature_info.param_idents;
 
-    let (object_ptr, receiver);
+    let (object_ptr, receiver, dispatch_object);
     if let Some(gd_self_parameter) = gd_self_parameter {
         object_ptr = quote! { #gd_self_parameter.obj_sys() };
+        dispatch_object = quote! { #gd_self_parameter.clone() };
         receiver = gd_self_parameter;
     } else {
         object_ptr = quote! { <Self as ::godot::obj::WithBaseField>::base_field(self).obj_sys() };
         receiver = ident("self");
+
+        // With `&mut self`, base_mut() allows the overriding Rust class to re-borrow this object.
+        dispatch_object = match signature_info.receiver_type {
+            ReceiverType::Mut => quote! { <Self as ::godot::obj::WithBaseField>::base_mut(self) },
+            _ => quote! { <Self as ::godot::obj::WithBaseField>::to_gd(self) },
+        };
     };
 
     let code = quote! {
@@ -493,6 +550,12 @@ fn add_virtual_script_call(
                     args,
                 )
             }
+        } else if unsafe { ::godot::private::has_virtual_rust_override(object_ptr, <Self as ::godot::obj::GodotClass>::class_name(), &method_sname) } {
+            // Rust class deriving from this one (#[class(rust_base)]) overrides the method.
+            let args = [ #( ::godot::meta::ToGodot::to_variant(&#arg_names) ),* ];
+            let mut dispatch_object = #dispatch_object;
+            let result = dispatch_object.call(&method_sname, &args);
+            ::godot::meta::FromGodot::from_variant(&result)
         } else {
             // Fall back to default implementation.
             Self::#early_bound_name(#receiver, #( #arg_names ),*)
@@ -12,7 +12,9 @@ use quote::quote;
 
 use crate::class::data_models::fields::Fields;
 use crate::class::data_models::group_export::FieldGroup;
-use crate::class::{Field, FieldVar, GetSet, GetterSetterImpl, UsageFlags};
+use crate::class::{
+    make_property_changed_signal, Field, FieldVar, GetSet, GetterSetterImpl, UsageFlags,
+};
 use crate::util::{format_funcs_collection_constant, format_funcs_collection_struct, ident};
 
 #[derive(Default, Clone, Debug)]
@@ -40,8 +42,13 @@ impl FieldHint {
     }
 }
 
-pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+pub fn make_property_impl(
+    class_name: &Ident,
+    class_vis: Option<&venial::VisMarker>,
+    fields: &Fields,
+) -> TokenStream {
     let mut getter_setter_impls = Vec::new();
+    let mut changed_signal_symbols = Vec::new();
     let mut phantom_var_dummy_uses = Vec::new();
     let mut func_name_consts = Vec::new();
     let mut export_tokens = Vec::new();
@@ -96,7 +103,13 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             }
 
             export_hint = export.to_export_hint();
-            registration_fn = quote! { register_export };
+
+            // Callable does not implement Export, so tool buttons skip the additional Export checks.
+            registration_fn = if field.tool_button.is_some() {
+                quote! { register_var }
+            } else {
+                quote! { register_export }
+            };
         } else {
             export_hint = None;
             registration_fn = quote! { register_var };
@@ -150,21 +163,46 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
         // Note: {getter,setter}_tokens can be either a path `Class_Functions::constant_name` or an empty string `""`.
 
+        // Tool buttons are read-only; their getter creates a callable bound to the user method.
+        let (getter_impl, setter_impl) = match &field.tool_button {
+            Some(method) => match GetterSetterImpl::from_tool_button(class_name, field, method) {
+                Ok(getter_impl) => (Some(getter_impl), None),
+                Err(error) => {
+                    export_tokens.push(error.to_compile_error());
+                    (None, None)
+                }
+            },
+            None => (
+                getter.to_impl(class_name, GetSet::Get, field),
+                setter.to_impl(class_name, GetSet::Set, field),
+            ),
+        };
+
         let getter_tokens = make_getter_setter(
-            getter.to_impl(class_name, GetSet::Get, field),
+            getter_impl,
             &mut getter_setter_impls,
             &mut func_name_consts,
             &mut export_tokens,
             class_name,
         );
         let setter_tokens = make_getter_setter(
-            setter.to_impl(class_name, GetSet::Set, field),
+            setter_impl,
             &mut getter_setter_impls,
             &mut func_name_consts,
             &mut export_tokens,
             class_name,
         );
 
+        if field.on_change.as_ref().is_some_and(|o| o.emit_changed) {
+            match make_property_changed_signal(class_name, class_vis, field) {
+                Ok((registration, symbols)) => {
+                    export_tokens.push(registration);
+                    changed_signal_symbols.push(symbols);
+                }
+                Err(error) => changed_signal_symbols.push(error.to_compile_error()),
+            }
+        }
+
         if field.is_phantomvar {
             let field_name = field.name.clone();
             phantom_var_dummy_uses.push(quote! {
@@ -223,6 +261,8 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
                 )*
             }
         }
+
+        #( #changed_signal_symbols )*
     }
 }
 
@@ -0,0 +1,160 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::util::{bail, ListParser};
+use crate::ParseResult;
+
+/// Parsed `#[class(resource_format = (extensions = [...], resource = Type))]`.
+pub struct ResourceFormat {
+    /// File extensions (string literals) handled by the format, without leading dot.
+    pub extensions: Vec<TokenStream>,
+
+    /// Resource class that is loaded and saved.
+    pub resource: Ident,
+}
+
+impl ResourceFormat {
+    pub fn new_from_list(mut parser: ListParser, span: Span) -> ParseResult<Self> {
+        let mut extensions = None;
+        let mut resource = None;
+
+        while let Some((key, value)) =
+            parser.next_allowed_key_optional_value(&[], &["extensions", "resource"])?
+        {
+            let value = value.expect("keys with values only");
+
+            match key.to_string().as_str() {
+                "extensions" => {
+                    let mut list = ListParser::new_from_tree(
+                        value.single()?,
+                        proc_macro2::Delimiter::Bracket,
+                    )?;
+
+                    let mut exts = Vec::new();
+                    while list.peek().is_some() {
+                        exts.push(list.next_expr()?);
+                    }
+                    list.finish()?;
+
+                    if exts.is_empty() {
+                        return bail!(key, "`extensions` must contain at least one file extension");
+                    }
+                    extensions = Some(exts);
+                }
+                "resource" => resource = Some(value.ident()?),
+                _ => unreachable!(),
+            }
+        }
+        parser.finish()?;
+
+        let Some(extensions) = extensions else {
+            return bail!(
+                span,
+                "#[class(resource_format)] requires key `extensions = [...]`"
+            );
+        };
+        let Some(resource) = resource else {
+            return bail!(
+                span,
+                "#[class(resource_format)] requires key `resource = Type`"
+            );
+        };
+
+        Ok(Self {
+            extensions,
+            resource,
+        })
+    }
+
+    /// Generates a `ResourceFormatLoader` and a `ResourceFormatSaver` class, which delegate to the `ResourceFormat` impl of `class_name`.
+    ///
+    /// Returns the generated items, plus the names of loader and saver classes.
+    pub fn make_loader_saver(
+        &self,
+        class_name: &Ident,
+        vis_marker: Option<&venial::VisMarker>,
+    ) -> (TokenStream, Ident, Ident) {
+        let loader = format_ident!("__godot_{class_name}_Loader");
+        let saver = format_ident!("__godot_{class_name}_Saver");
+        let resource = &self.resource;
+        let extensions = &self.extensions;
+        let rf = quote! { ::godot::register::private::resource_format };
+
+        // Loaders and savers are an implementation detail, which should not appear in the editor's class list.
+        #[cfg(since_api = "4.2")]
+        let internal = quote! { , internal };
+        #[cfg(before_api = "4.2")]
+        let internal = TokenStream::new();
+
+        let items = quote! {
+            #[derive(::godot::register::GodotClass)]
+            #[class(base = ResourceFormatLoader, init, tool #internal)]
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #vis_marker struct #loader {}
+
+            #[::godot::register::godot_api]
+            impl ::godot::classes::IResourceFormatLoader for #loader {
+                fn get_recognized_extensions(&self) -> ::godot::builtin::PackedStringArray {
+                    #rf::recognized_extensions(&[ #(#extensions),* ])
+                }
+
+                fn handles_type(&self, type_: ::godot::builtin::StringName) -> bool {
+                    #rf::handles_type::<#resource>(&type_)
+                }
+
+                fn get_resource_type(&self, path: ::godot::builtin::GString) -> ::godot::builtin::GString {
+                    #rf::resource_type::<#resource>(&path, &[ #(#extensions),* ])
+                }
+
+                fn load(
+                    &self,
+                    path: ::godot::builtin::GString,
+                    _original_path: ::godot::builtin::GString,
+                    _use_sub_threads: bool,
+                    _cache_mode: i32,
+                ) -> ::godot::builtin::Variant {
+                    #rf::load::<#class_name, #resource>(&path)
+                }
+            }
+
+            #[derive(::godot::register::GodotClass)]
+            #[class(base = ResourceFormatSaver, init, tool #internal)]
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #vis_marker struct #saver {}
+
+            #[::godot::register::godot_api]
+            impl ::godot::classes::IResourceFormatSaver for #saver {
+                fn save(
+                    &mut self,
+                    resource: Option<::godot::obj::Gd<::godot::classes::Resource>>,
+                    path: ::godot::builtin::GString,
+                    _flags: u32,
+                ) -> ::godot::global::Error {
+                    #rf::save::<#class_name, #resource>(resource, &path)
+                }
+
+                fn recognize(&self, resource: Option<::godot::obj::Gd<::godot::classes::Resource>>) -> bool {
+                    #rf::recognize::<#resource>(&resource)
+                }
+
+                fn get_recognized_extensions(
+                    &self,
+                    resource: Option<::godot::obj::Gd<::godot::classes::Resource>>,
+                ) -> ::godot::builtin::PackedStringArray {
+                    #rf::recognized_extensions_for::<#resource>(&resource, &[ #(#extensions),* ])
+                }
+            }
+        };
+
+        (items, loader, saver)
+    }
+}
@@ -0,0 +1,503 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::class::ResourceFormat;
+
+/// Generates the classes behind `#[class(script_language)]`: a `ScriptExtension`, a `ScriptLanguageExtension`, and a loader/saver pair.
+///
+/// All of them delegate to the `RustScriptLanguage` impl of `class_name`. Returns the generated items, plus the modifiers to register
+/// the language and resource format.
+pub fn make_script_language(
+    class_name: &Ident,
+    vis_marker: Option<&venial::VisMarker>,
+) -> (TokenStream, Vec<TokenStream>) {
+    let script = format_ident!("__godot_{class_name}_Script");
+    let language = format_ident!("__godot_{class_name}_Language");
+
+    let script_class = make_script_class(class_name, &script, vis_marker);
+    let language_class = make_language_class(class_name, &script, &language, vis_marker);
+
+    let resource_format = ResourceFormat {
+        extensions: vec![
+            quote! { <#class_name as ::godot::obj::script::RustScriptLanguage>::EXTENSION },
+        ],
+        resource: format_ident!("Script"),
+    };
+    let (loader_saver, loader, saver) = resource_format.make_loader_saver(class_name, vis_marker);
+
+    let sl = quote! { ::godot::register::private::script_language };
+
+    let items = quote! {
+        #script_class
+        #language_class
+        #loader_saver
+
+        impl ::godot::tools::ResourceFormat<::godot::classes::Script> for #class_name {
+            fn load(bytes: &[u8]) -> Result<::godot::obj::Gd<::godot::classes::Script>, ::godot::global::Error> {
+                #sl::load_script::<#class_name, #script>(bytes)
+            }
+
+            fn save(resource: &::godot::obj::Gd<::godot::classes::Script>) -> Result<Vec<u8>, ::godot::global::Error> {
+                #sl::save_script::<#class_name, #script>(resource)
+            }
+        }
+    };
+
+    let modifiers = vec![
+        quote! { with_resource_format::<#loader, #saver> },
+        quote! { with_script_language::<#class_name, #language> },
+    ];
+
+    (items, modifiers)
+}
+
+fn make_script_class(
+    class_name: &Ident,
+    script: &Ident,
+    vis_marker: Option<&venial::VisMarker>,
+) -> TokenStream {
+    let sl = quote! { ::godot::register::private::script_language };
+
+    #[cfg(since_api = "4.4")]
+    let methods_4_4 = quote! {
+        fn get_doc_class_name(&self) -> ::godot::builtin::StringName {
+            self.data.global_name()
+        }
+    };
+    #[cfg(before_api = "4.4")]
+    let methods_4_4 = TokenStream::new();
+
+    quote! {
+        #[derive(::godot::register::GodotClass)]
+        #[class(base = ScriptExtension, init, tool, internal)]
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis_marker struct #script {
+            data: #sl::ScriptData<#class_name>,
+            base: ::godot::obj::Base<::godot::classes::ScriptExtension>,
+        }
+
+        impl #sl::HasScriptData<#class_name> for #script {
+            fn script_data(&self) -> &#sl::ScriptData<#class_name> {
+                &self.data
+            }
+
+            fn script_data_mut(&mut self) -> &mut #sl::ScriptData<#class_name> {
+                &mut self.data
+            }
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IScriptExtension for #script {
+            fn editor_can_reload_from_file(&mut self) -> bool {
+                true
+            }
+
+            fn can_instantiate(&self) -> bool {
+                self.data.can_instantiate()
+            }
+
+            fn get_base_script(&self) -> Option<::godot::obj::Gd<::godot::classes::Script>> {
+                None
+            }
+
+            fn get_global_name(&self) -> ::godot::builtin::StringName {
+                self.data.global_name()
+            }
+
+            fn inherits_script(&self, script: ::godot::obj::Gd<::godot::classes::Script>) -> bool {
+                script == ::godot::obj::WithBaseField::to_gd(self).upcast::<::godot::classes::Script>()
+            }
+
+            fn get_instance_base_type(&self) -> ::godot::builtin::StringName {
+                self.data.instance_base_type()
+            }
+
+            unsafe fn instance_create_rawptr(&self, for_object: ::godot::obj::Gd<::godot::classes::Object>) -> *mut ::std::ffi::c_void {
+                let script = ::godot::obj::WithBaseField::to_gd(self).upcast::<::godot::classes::Script>();
+                self.data.instance_create(script, for_object)
+            }
+
+            unsafe fn placeholder_instance_create_rawptr(&self, for_object: ::godot::obj::Gd<::godot::classes::Object>) -> *mut ::std::ffi::c_void {
+                let script = ::godot::obj::WithBaseField::to_gd(self).upcast::<::godot::classes::Script>();
+
+                // Godot reports freed placeholders through placeholder_erased_rawptr() below.
+                self.data.placeholder_instance_create(script, for_object)
+            }
+
+            unsafe fn placeholder_erased_rawptr(&mut self, placeholder: *mut ::std::ffi::c_void) {
+                self.data.placeholder_erased(placeholder);
+            }
+
+            fn instance_has(&self, object: ::godot::obj::Gd<::godot::classes::Object>) -> bool {
+                #sl::instance_has(::godot::obj::WithBaseField::to_gd(self), &object)
+            }
+
+            fn has_source_code(&self) -> bool {
+                !self.data.source_code().is_empty()
+            }
+
+            fn get_source_code(&self) -> ::godot::builtin::GString {
+                self.data.source_code()
+            }
+
+            fn set_source_code(&mut self, code: ::godot::builtin::GString) {
+                self.data.set_source_code(code);
+            }
+
+            fn reload(&mut self, _keep_state: bool) -> ::godot::global::Error {
+                self.data.reload()
+            }
+
+            fn get_documentation(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn has_method(&self, method: ::godot::builtin::StringName) -> bool {
+                self.data.has_method(&method)
+            }
+
+            fn has_static_method(&self, _method: ::godot::builtin::StringName) -> bool {
+                false
+            }
+
+            fn get_method_info(&self, method: ::godot::builtin::StringName) -> ::godot::builtin::Dictionary {
+                self.data.method_info(&method)
+            }
+
+            fn is_tool(&self) -> bool {
+                self.data.is_tool()
+            }
+
+            fn is_valid(&self) -> bool {
+                self.data.is_valid()
+            }
+
+            fn has_script_signal(&self, _signal: ::godot::builtin::StringName) -> bool {
+                false
+            }
+
+            fn get_script_signal_list(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn has_property_default_value(&self, _property: ::godot::builtin::StringName) -> bool {
+                false
+            }
+
+            fn get_property_default_value(&self, _property: ::godot::builtin::StringName) -> ::godot::builtin::Variant {
+                ::godot::builtin::Variant::nil()
+            }
+
+            fn update_exports(&mut self) {}
+
+            fn get_script_method_list(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                self.data.script_method_list()
+            }
+
+            fn get_script_property_list(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                self.data.script_property_list()
+            }
+
+            fn get_member_line(&self, _member: ::godot::builtin::StringName) -> i32 {
+                -1
+            }
+
+            fn get_constants(&self) -> ::godot::builtin::Dictionary {
+                ::godot::builtin::Dictionary::new()
+            }
+
+            fn get_members(&self) -> ::godot::builtin::Array<::godot::builtin::StringName> {
+                self.data.members()
+            }
+
+            fn is_placeholder_fallback_enabled(&self) -> bool {
+                false
+            }
+
+            fn get_rpc_config(&self) -> ::godot::builtin::Variant {
+                ::godot::meta::ToGodot::to_variant(&::godot::builtin::Dictionary::new())
+            }
+
+            fn get_language(&self) -> Option<::godot::obj::Gd<::godot::classes::ScriptLanguage>> {
+                let language = #sl::language::<#class_name>();
+                if language.is_none() {
+                    ::godot::global::godot_error!(
+                        "script language `{}` is not registered",
+                        <#class_name as ::godot::obj::GodotClass>::class_name()
+                    );
+                }
+                language
+            }
+
+            #methods_4_4
+        }
+    }
+}
+
+fn make_language_class(
+    class_name: &Ident,
+    script: &Ident,
+    language: &Ident,
+    vis_marker: Option<&venial::VisMarker>,
+) -> TokenStream {
+    let sl = quote! { ::godot::register::private::script_language };
+    let rsl = quote! { <#class_name as ::godot::obj::script::RustScriptLanguage> };
+
+    #[cfg(since_api = "4.3")]
+    let methods_4_3 = quote! {
+        fn profiling_set_save_native_calls(&mut self, _enable: bool) {}
+
+        fn debug_get_stack_level_source(&self, _level: i32) -> ::godot::builtin::GString {
+            ::godot::builtin::GString::new()
+        }
+
+        fn can_make_function(&self) -> bool {
+            false
+        }
+
+        fn preferred_file_name_casing(&self) -> ::godot::classes::script_language::ScriptNameCasing {
+            ::godot::classes::script_language::ScriptNameCasing::AUTO
+        }
+    };
+    #[cfg(before_api = "4.3")]
+    let methods_4_3 = TokenStream::new();
+
+    #[cfg(since_api = "4.4")]
+    let methods_4_4 = quote! {
+        fn reload_scripts(&mut self, _scripts: ::godot::builtin::Array<::godot::builtin::Variant>, _soft: bool) {}
+    };
+    #[cfg(before_api = "4.4")]
+    let methods_4_4 = TokenStream::new();
+
+    quote! {
+        #[derive(::godot::register::GodotClass)]
+        #[class(base = ScriptLanguageExtension, init, tool, internal)]
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis_marker struct #language {
+            base: ::godot::obj::Base<::godot::classes::ScriptLanguageExtension>,
+        }
+
+        #[::godot::register::godot_api]
+        impl ::godot::classes::IScriptLanguageExtension for #language {
+            fn get_name(&self) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::from(#rsl::NAME)
+            }
+
+            fn init_ext(&mut self) {}
+
+            fn get_type(&self) -> ::godot::builtin::GString {
+                <#script as ::godot::obj::GodotClass>::class_name().to_gstring()
+            }
+
+            fn get_extension(&self) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::from(#rsl::EXTENSION)
+            }
+
+            fn finish(&mut self) {}
+
+            fn get_reserved_words(&self) -> ::godot::builtin::PackedStringArray {
+                #sl::string_list(#rsl::reserved_words())
+            }
+
+            fn is_control_flow_keyword(&self, keyword: ::godot::builtin::GString) -> bool {
+                #rsl::is_control_flow_keyword(&keyword.to_string())
+            }
+
+            fn get_comment_delimiters(&self) -> ::godot::builtin::PackedStringArray {
+                #sl::string_list(#rsl::comment_delimiters())
+            }
+
+            fn get_string_delimiters(&self) -> ::godot::builtin::PackedStringArray {
+                #sl::string_list(#rsl::string_delimiters())
+            }
+
+            fn make_template(&self, _template: ::godot::builtin::GString, class_name: ::godot::builtin::GString, base_class_name: ::godot::builtin::GString) -> Option<::godot::obj::Gd<::godot::classes::Script>> {
+                #sl::make_template::<#class_name, #script>(&class_name, &base_class_name)
+            }
+
+            fn get_built_in_templates(&self, _object: ::godot::builtin::StringName) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn is_using_templates(&mut self) -> bool {
+                false
+            }
+
+            fn validate(
+                &self,
+                script: ::godot::builtin::GString,
+                path: ::godot::builtin::GString,
+                _validate_functions: bool,
+                _validate_errors: bool,
+                _validate_warnings: bool,
+                _validate_safe_lines: bool,
+            ) -> ::godot::builtin::Dictionary {
+                #sl::validate::<#class_name>(&script, &path)
+            }
+
+            fn validate_path(&self, _path: ::godot::builtin::GString) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::new()
+            }
+
+            fn create_script(&self) -> Option<::godot::obj::Gd<::godot::classes::Object>> {
+                #sl::create_script::<#class_name, #script>()
+            }
+
+            fn has_named_classes(&self) -> bool {
+                false
+            }
+
+            fn supports_builtin_mode(&self) -> bool {
+                false
+            }
+
+            fn supports_documentation(&self) -> bool {
+                false
+            }
+
+            fn can_inherit_from_file(&self) -> bool {
+                false
+            }
+
+            fn find_function(&self, _class_name: ::godot::builtin::GString, _function_name: ::godot::builtin::GString) -> i32 {
+                -1
+            }
+
+            fn make_function(&self, _class_name: ::godot::builtin::GString, _function_name: ::godot::builtin::GString, _function_args: ::godot::builtin::PackedStringArray) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::new()
+            }
+
+            fn open_in_external_editor(&mut self, _script: Option<::godot::obj::Gd<::godot::classes::Script>>, _line: i32, _column: i32) -> ::godot::global::Error {
+                ::godot::global::Error::ERR_UNAVAILABLE
+            }
+
+            fn overrides_external_editor(&mut self) -> bool {
+                false
+            }
+
+            fn complete_code(&self, _code: ::godot::builtin::GString, _path: ::godot::builtin::GString, _owner: Option<::godot::obj::Gd<::godot::classes::Object>>) -> ::godot::builtin::Dictionary {
+                #sl::code_unavailable()
+            }
+
+            fn lookup_code(&self, _code: ::godot::builtin::GString, _symbol: ::godot::builtin::GString, _path: ::godot::builtin::GString, _owner: Option<::godot::obj::Gd<::godot::classes::Object>>) -> ::godot::builtin::Dictionary {
+                #sl::code_unavailable()
+            }
+
+            fn auto_indent_code(&self, code: ::godot::builtin::GString, _from_line: i32, _to_line: i32) -> ::godot::builtin::GString {
+                code
+            }
+
+            fn add_global_constant(&mut self, _name: ::godot::builtin::StringName, _value: ::godot::builtin::Variant) {}
+
+            fn add_named_global_constant(&mut self, _name: ::godot::builtin::StringName, _value: ::godot::builtin::Variant) {}
+
+            fn remove_named_global_constant(&mut self, _name: ::godot::builtin::StringName) {}
+
+            fn thread_enter(&mut self) {}
+
+            fn thread_exit(&mut self) {}
+
+            fn debug_get_error(&self) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::new()
+            }
+
+            fn debug_get_stack_level_count(&self) -> i32 {
+                0
+            }
+
+            fn debug_get_stack_level_line(&self, _level: i32) -> i32 {
+                -1
+            }
+
+            fn debug_get_stack_level_function(&self, _level: i32) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::new()
+            }
+
+            fn debug_get_stack_level_locals(&mut self, _level: i32, _max_subitems: i32, _max_depth: i32) -> ::godot::builtin::Dictionary {
+                ::godot::builtin::Dictionary::new()
+            }
+
+            fn debug_get_stack_level_members(&mut self, _level: i32, _max_subitems: i32, _max_depth: i32) -> ::godot::builtin::Dictionary {
+                ::godot::builtin::Dictionary::new()
+            }
+
+            unsafe fn debug_get_stack_level_instance_rawptr(&mut self, _level: i32) -> *mut ::std::ffi::c_void {
+                ::std::ptr::null_mut()
+            }
+
+            fn debug_get_globals(&mut self, _max_subitems: i32, _max_depth: i32) -> ::godot::builtin::Dictionary {
+                ::godot::builtin::Dictionary::new()
+            }
+
+            fn debug_parse_stack_level_expression(&mut self, _level: i32, _expression: ::godot::builtin::GString, _max_subitems: i32, _max_depth: i32) -> ::godot::builtin::GString {
+                ::godot::builtin::GString::new()
+            }
+
+            fn debug_get_current_stack_info(&mut self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn reload_all_scripts(&mut self) {}
+
+            fn reload_tool_script(&mut self, _script: Option<::godot::obj::Gd<::godot::classes::Script>>, _soft_reload: bool) {}
+
+            fn get_recognized_extensions(&self) -> ::godot::builtin::PackedStringArray {
+                #sl::recognized_extensions::<#class_name>()
+            }
+
+            fn get_public_functions(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn get_public_constants(&self) -> ::godot::builtin::Dictionary {
+                ::godot::builtin::Dictionary::new()
+            }
+
+            fn get_public_annotations(&self) -> ::godot::builtin::Array<::godot::builtin::Dictionary> {
+                ::godot::builtin::Array::new()
+            }
+
+            fn profiling_start(&mut self) {}
+
+            fn profiling_stop(&mut self) {}
+
+            unsafe fn profiling_get_accumulated_data_rawptr(
+                &mut self,
+                _info_array: *mut ::godot::classes::native::ScriptLanguageExtensionProfilingInfo,
+                _info_max: i32,
+            ) -> i32 {
+                0
+            }
+
+            unsafe fn profiling_get_frame_data_rawptr(
+                &mut self,
+                _info_array: *mut ::godot::classes::native::ScriptLanguageExtensionProfilingInfo,
+                _info_max: i32,
+            ) -> i32 {
+                0
+            }
+
+            fn frame(&mut self) {}
+
+            fn handles_global_class_type(&self, type_: ::godot::builtin::GString) -> bool {
+                type_ == <#script as ::godot::obj::GodotClass>::class_name().to_gstring()
+            }
+
+            fn get_global_class_name(&self, _path: ::godot::builtin::GString) -> ::godot::builtin::Dictionary {
+                ::godot::builtin::Dictionary::new()
+            }
+
+            #methods_4_3
+            #methods_4_4
+        }
+    }
+}
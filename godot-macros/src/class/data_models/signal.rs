@@ -10,6 +10,7 @@
 use proc_macro2::{Delimiter, Ident, TokenStream, TokenTree};
 use quote::{format_ident, quote, ToTokens};
 
+use crate::class::{Field, FieldOnChange};
 use crate::util::bail;
 use crate::{util, ParseResult};
 
@@ -223,6 +224,56 @@ pub fn make_signal_registrations(
     Ok((signal_registrations, signal_symbols))
 }
 
+/// Returns registration code and symbolic API for the `<field>_changed` signal of a `#[var(emit_changed)]` field.
+///
+/// The signal has a single parameter of the property's `Via` type. Its provider method is added to the signal collection declared by
+/// `#[godot_api]` through a separate `impl` block, so the class needs a `#[godot_api]` block with typed signals enabled.
+///
+/// The provider method gets the narrower visibility of class and field, so a `pub` field in a private class does not expose the class.
+pub fn make_property_changed_signal(
+    class_name: &Ident,
+    class_vis: Option<&venial::VisMarker>,
+    field: &Field,
+) -> ParseResult<(TokenStream, TokenStream)> {
+    let field_name = &field.name;
+    let field_type = &field.ty;
+    let signal_name = FieldOnChange::signal_name(field_name);
+
+    let mut fn_signature = util::parse_signature(quote! {
+        fn #signal_name(#field_name: <#field_type as ::godot::meta::GodotConvert>::Via)
+    });
+    fn_signature.vis_marker = match (
+        SignalVisibility::try_parse(class_vis),
+        SignalVisibility::try_parse(field.vis_marker.as_ref()),
+    ) {
+        (Some(class), Some(field_vis)) if field_vis <= class => field.vis_marker.clone(),
+        (Some(_), Some(_)) => class_vis.cloned(),
+        // `pub(in path)` cannot be ordered; fall back to private.
+        _ => None,
+    };
+
+    let details = SignalDetails::extract(&fn_signature, class_name, &[])?;
+    let registration = make_signal_registration(&details, &util::class_name_obj(class_name));
+
+    let collection_struct_name = format_collection_struct(class_name);
+    let provider_method = make_provider_method(&details);
+    let individual_struct = make_signal_individual_struct(&details);
+
+    let symbols = quote! {
+        impl<'c, C> #collection_struct_name<'c, C>
+        where // Same bounds as in make_signal_symbols().
+            C: ::godot::obj::WithUserSignals +
+               ::godot::obj::WithSignals<__SignalObj<'c> = ::godot::private::UserSignalObject<'c, C>>,
+        {
+            #provider_method
+        }
+
+        #individual_struct
+    };
+
+    Ok((registration, symbols))
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 fn make_signal_registration(details: &SignalDetails, class_name_obj: &TokenStream) -> TokenStream {
@@ -288,30 +339,7 @@ struct SignalCollection {
 
 impl SignalCollection {
     fn extend_with(&mut self, details: &SignalDetails) {
-        let SignalDetails {
-            signal_name,
-            signal_name_str,
-            signal_cfg_attrs,
-            individual_struct_name,
-            vis_marker,
-            ..
-        } = details;
-
-        self.provider_methods.push(quote! {
-            // Deliberately not #[doc(hidden)] for IDE completion.
-            #(#signal_cfg_attrs)*
-            // Note: this could be `pub` always and would still compile (maybe warning with the following message).
-            //   associated function `SignalCollection::my_signal` is reachable at visibility `pub(crate)`
-            //
-            // However, it would still lead to a compile error when declaring the individual signal struct `pub` (or any other
-            // visibility that exceeds the class visibility). So, we can as well declare the visibility here.
-            #vis_marker fn #signal_name(&mut self) -> #individual_struct_name<'c, C> {
-                #individual_struct_name {
-                    __typed: ::godot::register::TypedSignal::<'c, C, _>::extract(&mut self.__internal_obj, #signal_name_str)
-                }
-            }
-        });
-
+        self.provider_methods.push(make_provider_method(details));
         self.individual_structs
             .push(make_signal_individual_struct(details))
     }
@@ -321,6 +349,32 @@ impl SignalCollection {
     }
 }
 
+fn make_provider_method(details: &SignalDetails) -> TokenStream {
+    let SignalDetails {
+        signal_name,
+        signal_name_str,
+        signal_cfg_attrs,
+        individual_struct_name,
+        vis_marker,
+        ..
+    } = details;
+
+    quote! {
+        // Deliberately not #[doc(hidden)] for IDE completion.
+        #(#signal_cfg_attrs)*
+        // Note: this could be `pub` always and would still compile (maybe warning with the following message).
+        //   associated function `SignalCollection::my_signal` is reachable at visibility `pub(crate)`
+        //
+        // However, it would still lead to a compile error when declaring the individual signal struct `pub` (or any other
+        // visibility that exceeds the class visibility). So, we can as well declare the visibility here.
+        #vis_marker fn #signal_name(&mut self) -> #individual_struct_name<'c, C> {
+            #individual_struct_name {
+                __typed: ::godot::register::TypedSignal::<'c, C, _>::extract(&mut self.__internal_obj, #signal_name_str)
+            }
+        }
+    }
+}
+
 fn make_asarg_params(params: &venial::Punctuated<venial::FnParam>) -> TokenStream {
     // Could be specialized by trying to parse types, but won't be 100% accurate due to lack of semantics (AsArg could be a safe fallback). E.g.:
     // if ty.tokens.iter().any(|tk| matches!(tk, TokenTree::Ident(ident) if ident == "Gd")) {
@@ -438,7 +492,7 @@ fn make_signal_symbols(
     // We also provide opt-out via #[godot_api(no_typed_signals)].
 
     let declares_no_signals = collection_api.is_empty();
-    let collection_struct_name = format_ident!("__godot_Signals_{}", class_name);
+    let collection_struct_name = format_collection_struct(class_name);
     let collection_struct_methods = &collection_api.provider_methods;
     let with_signals_impl = make_with_signals_impl(class_name, &collection_struct_name);
     let upcast_deref_impl = make_upcast_deref_impl(class_name, &collection_struct_name);
@@ -515,6 +569,10 @@ fn make_signal_symbols(
     code
 }
 
+fn format_collection_struct(class_name: &Ident) -> Ident {
+    format_ident!("__godot_Signals_{}", class_name)
+}
+
 /// Declare `impl WithSignals` and `impl WithUserSignals` with own signal collection.
 fn make_with_signals_impl(class_name: &Ident, collection_struct_name: &Ident) -> TokenStream {
     quote! {
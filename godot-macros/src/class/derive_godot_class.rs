@@ -5,15 +5,16 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use proc_macro2::{Ident, Punct, TokenStream};
+use proc_macro2::{Ident, Literal, Punct, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 use venial::Error;
 
 use crate::class::data_models::fields::{named_fields, Fields};
 use crate::class::data_models::group_export::FieldGroup;
 use crate::class::{
-    make_property_impl, make_virtual_callback, BeforeKind, Field, FieldCond, FieldDefault,
-    FieldExport, FieldVar, GetterSetter, SignatureInfo,
+    make_property_impl, make_script_language, make_virtual_callback, BeforeKind, Field, FieldCond,
+    FieldDefault, FieldExport, FieldOnChange, FieldVar, GetterSetter, ResourceFormat,
+    SignatureInfo,
 };
 use crate::util::{
     bail, error, format_funcs_collection_struct, ident, path_ends_with_complex,
@@ -48,6 +49,8 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let mut deprecations = std::mem::take(&mut struct_cfg.deprecations);
     deprecations.append(&mut fields.deprecations);
 
+    validate_tool_buttons(&mut fields, struct_cfg.is_tool);
+
     let errors = fields.errors.iter().map(|error| error.to_compile_error());
 
     let class_name = &class.name;
@@ -73,14 +76,35 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         crate::docs::document_struct(base_ty.to_string(), &class.attributes, &fields.all_fields);
     #[cfg(not(all(feature = "register-docs", since_api = "4.3")))]
     let docs = quote! {};
-    let base_class = quote! { ::godot::classes::#base_ty };
+    let prv = quote! { ::godot::private };
 
-    // Use this name because when typing a non-existent class, users will be met with the following error:
-    //    could not find `inherit_from_OS__ensure_class_exists` in `class_macros`.
-    let inherits_macro_ident = format_ident!("inherit_from_{}__ensure_class_exists", base_ty);
+    let (base_class, inherit_base, create_base_part);
+    if struct_cfg.is_rust_base {
+        // The base class is declared in Rust, together with an Inherits macro for its own base classes.
+        let base_inherits_macro = util::format_class_inherits_macro(base_ty);
+
+        base_class = quote! { #base_ty };
+        inherit_base = quote! { #base_inherits_macro! };
+        create_base_part = quote! {
+            fn __create_base_part(
+                base: &::godot::obj::Base<#base_ty>,
+            ) -> ::std::result::Result<
+                ::std::option::Option<#prv::ErasedBaseStorage>,
+                #prv::PanicPayload,
+            > {
+                #prv::callbacks::create_base_part::<#base_ty>(base)
+            }
+        };
+    } else {
+        // Use this name because when typing a non-existent class, users will be met with the following error:
+        //    could not find `inherit_from_OS__ensure_class_exists` in `class_macros`.
+        let inherits_macro_ident = format_ident!("inherit_from_{}__ensure_class_exists", base_ty);
 
-    let prv = quote! { ::godot::private };
-    let godot_exports_impl = make_property_impl(class_name, &fields);
+        base_class = quote! { ::godot::classes::#base_ty };
+        inherit_base = quote! { #prv::class_macros::#inherits_macro_ident! };
+        create_base_part = TokenStream::new();
+    }
+    let godot_exports_impl = make_property_impl(class_name, class.vis_marker.as_ref(), &fields);
 
     let godot_withbase_impl = if let Some(Field { name, ty, .. }) = &fields.base_field {
         // Apply the span of the field's type so that errors show up on the field's type.
@@ -134,10 +158,40 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             modifiers.push(quote! { with_generated_no_default::<#class_name> });
         }
     };
+    // Singletons are created once by the library; Godot must not construct further instances.
+    let mut singleton_impl = TokenStream::new();
+    if struct_cfg.is_singleton {
+        is_instantiable = false;
+        singleton_impl = make_singleton_impl(class_name);
+        modifiers.push(quote! { with_singleton::<#class_name> });
+    }
+
     if is_instantiable {
         modifiers.push(quote! { with_instantiable });
     }
 
+    let mut resource_format_impl = TokenStream::new();
+    if let Some(resource_format) = &struct_cfg.resource_format {
+        let (items, loader, saver) =
+            resource_format.make_loader_saver(class_name, class.vis_marker.as_ref());
+
+        resource_format_impl = items;
+        modifiers.push(quote! { with_resource_format::<#loader, #saver> });
+    }
+
+    let mut script_language_impl = TokenStream::new();
+    if struct_cfg.is_script_language {
+        let (items, language_modifiers) =
+            make_script_language(class_name, class.vis_marker.as_ref());
+
+        script_language_impl = items;
+        modifiers.extend(language_modifiers);
+    }
+
+    if let Some(icon) = &struct_cfg.icon {
+        modifiers.push(quote! { with_icon(#icon) });
+    }
+
     if has_default_virtual {
         modifiers.push(quote! { with_default_get_virtual_fn::<#class_name> });
     }
@@ -159,6 +213,7 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
     let visibility_macro = make_visibility_macro(class_name, class.vis_marker.as_ref());
     let base_field_macro = make_base_field_macro(class_name, fields.base_field.is_some());
     let deny_manual_init_macro = make_deny_manual_init_macro(class_name, struct_cfg.init_strategy);
+    let inherits_macro = make_inherits_macro(class_name, &inherit_base);
 
     Ok(quote! {
         impl ::godot::obj::GodotClass for #class_name {
@@ -174,6 +229,8 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
                 let name: &'static ClassName = CLASS_NAME.get_or_init(|| #class_name_allocation);
                 *name
             }
+
+            #create_base_part
         }
 
         unsafe impl ::godot::obj::Bounds for #class_name {
@@ -189,9 +246,13 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
         #godot_exports_impl
         #user_class_impl
         #init_expecter
+        #singleton_impl
+        #resource_format_impl
+        #script_language_impl
         #visibility_macro
         #base_field_macro
         #deny_manual_init_macro
+        #inherits_macro
         #( #deprecations )*
         #( #errors )*
 
@@ -201,10 +262,26 @@ pub fn derive_godot_class(item: venial::Item) -> ParseResult<TokenStream> {
             )
         ));
 
-        #prv::class_macros::#inherits_macro_ident!(#class_name);
+        #inherit_base(#class_name);
     })
 }
 
+/// Generates the typed `singleton()` accessor for `#[class(singleton)]`, mirroring the one of engine singletons.
+fn make_singleton_impl(class_name: &Ident) -> TokenStream {
+    let doc = format!(
+        "Returns the one instance of `{class_name}`, which is created and registered with the engine when the class is loaded."
+    );
+
+    quote! {
+        impl #class_name {
+            #[doc = #doc]
+            pub fn singleton() -> ::godot::obj::Gd<Self> {
+                ::godot::private::user_singleton::<Self>()
+            }
+        }
+    }
+}
+
 /// Generates code for a decl-macro, which takes any item and prepends it with the visibility marker of the class.
 ///
 /// Used to access the visibility of the class in other proc-macros like `#[godot_api]`.
@@ -248,6 +325,23 @@ fn make_base_field_macro(class_name: &Ident, has_base_field: bool) -> TokenStrea
     }
 }
 
+/// Generates code for a decl-macro, which implements `Inherits` for a class deriving from this one (and for all further base classes).
+///
+/// Used by subclasses declared with `#[class(base = ThisClass, rust_base)]`.
+fn make_inherits_macro(class_name: &Ident, inherit_base: &TokenStream) -> TokenStream {
+    let macro_name = util::format_class_inherits_macro(class_name);
+
+    quote! {
+        #[allow(unused_macros)]
+        macro_rules! #macro_name {
+            ($Class:ident) => {
+                unsafe impl ::godot::obj::Inherits<#class_name> for $Class {}
+                #inherit_base($Class);
+            };
+        }
+    }
+}
+
 /// Generates code for a decl-macro that prevents manual `init()` for incompatible init strategies.
 fn make_deny_manual_init_macro(class_name: &Ident, init_strategy: InitStrategy) -> TokenStream {
     let macro_name = util::format_class_deny_manual_init_macro(class_name);
@@ -298,9 +392,14 @@ enum InitStrategy {
 
 struct ClassAttributes {
     base_ty: Ident,
+    is_rust_base: bool,
     init_strategy: InitStrategy,
     is_tool: bool,
     is_internal: bool,
+    is_singleton: bool,
+    resource_format: Option<ResourceFormat>,
+    is_script_language: bool,
+    icon: Option<Literal>,
     rename: Option<Ident>,
     deprecations: Vec<TokenStream>,
 }
@@ -498,19 +597,37 @@ fn make_user_class_impl(
 /// Returns the name of the base and the default mode
 fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttributes> {
     let mut base_ty = ident("RefCounted");
+    let mut is_rust_base = false;
     let mut init_strategy = InitStrategy::UserDefined;
     let mut is_tool = false;
     let mut is_internal = false;
+    let mut is_singleton = false;
+    let mut resource_format = None;
+    let mut is_script_language = false;
+    let mut icon = None;
     let mut rename: Option<Ident> = None;
     let mut deprecations = vec![];
 
     // #[class] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
         // #[class(base = Base)]
-        if let Some(base) = parser.handle_ident("base")? {
+        let explicit_base = parser.handle_ident("base")?;
+        let has_explicit_base = explicit_base.is_some();
+        if let Some(base) = explicit_base {
             base_ty = base;
         }
 
+        // #[class(base = RustClass, rust_base)]
+        if let Some(span) = parser.handle_alone_with_span("rust_base")? {
+            if !has_explicit_base {
+                return bail!(
+                    span,
+                    "#[class(rust_base)] requires the Rust base class to be specified with `base = ...`"
+                );
+            }
+            is_rust_base = true;
+        }
+
         // #[class(init)], #[class(no_init)]
         match handle_opposite_keys(&mut parser, "init", "class")? {
             Some(true) => init_strategy = InitStrategy::Generated,
@@ -550,16 +667,61 @@ fn parse_struct_attributes(class: &venial::Struct) -> ParseResult<ClassAttribute
             });
         }
 
+        // #[class(singleton)]
+        if let Some(span) = parser.handle_alone_with_span("singleton")? {
+            if matches!(init_strategy, InitStrategy::Absent) {
+                return bail!(
+                    span,
+                    "#[class(singleton)] requires a constructor to create the instance; remove #[class(no_init)]"
+                );
+            }
+            if base_ty == "EditorPlugin" {
+                return bail!(
+                    span,
+                    "#[class(singleton)] cannot be used with base=EditorPlugin, as the editor creates its own plugin instances"
+                );
+            }
+            is_singleton = true;
+        }
+
+        // #[class(resource_format = (extensions = ["ext"], resource = Type))]
+        if let Some(list) = parser.handle_list("resource_format")? {
+            resource_format = Some(ResourceFormat::new_from_list(list, parser.span())?);
+        }
+
+        // #[class(script_language)]
+        if let Some(span) = parser.handle_alone_with_span("script_language")? {
+            require_api_version!("4.2", &span, "#[class(script_language)]")?;
+            if resource_format.is_some() {
+                return bail!(
+                    span,
+                    "#[class(script_language)] already registers a resource format for scripts; remove #[class(resource_format)]"
+                );
+            }
+            is_script_language = true;
+        }
+
+        // #[class(icon = "res://path/to/icon.svg")]
+        icon = parser.handle_literal("icon", "string")?;
+
         parser.finish()?;
     }
 
-    post_validate(&base_ty, is_tool)?;
+    // Engine-specific constraints were already checked for the Rust base class.
+    if !is_rust_base {
+        post_validate(&base_ty, is_tool)?;
+    }
 
     Ok(ClassAttributes {
         base_ty,
+        is_rust_base,
         init_strategy,
         is_tool,
         is_internal,
+        is_singleton,
+        resource_format,
+        is_script_language,
+        icon,
         rename,
         deprecations,
     })
@@ -670,11 +832,31 @@ fn parse_fields(
 
         // #[export]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export")? {
+            // #[export(on_change = ...)] -- must come before the export type, which may consume the remaining keys.
+            field.on_change = FieldOnChange::new_from_kv(&mut parser)?;
+
             let export = FieldExport::new_from_kv(&mut parser)?;
             field.export = Some(export);
             parser.finish()?;
         }
 
+        // #[export_tool_button(fn = ..., text = ..., icon = ...)]
+        if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_tool_button")? {
+            require_api_version!("4.4", parser.span(), "#[export_tool_button]")?;
+
+            if field.export.is_some() {
+                return bail!(
+                    parser.span(),
+                    "#[export_tool_button] cannot be combined with #[export]"
+                );
+            }
+
+            let (export, method) = FieldExport::new_tool_button_from_kv(&mut parser)?;
+            field.export = Some(export);
+            field.tool_button = Some(method);
+            parser.finish()?;
+        }
+
         // #[export_group(name = ..., prefix = ...)]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "export_group")? {
             let group = FieldGroup::new_from_kv(&mut parser)?;
@@ -692,6 +874,18 @@ fn parse_fields(
         // #[var]
         if let Some(mut parser) = KvParser::parse(&named_field.attributes, "var")? {
             let mut var = FieldVar::new_from_kv(&mut parser)?;
+
+            // #[var(on_change = ...)]
+            if let Some(on_change) = FieldOnChange::new_from_kv(&mut parser)? {
+                if field.on_change.is_some() {
+                    return bail!(
+                        on_change.span,
+                        "change-notification keys can be specified in either #[var] or #[export], not both"
+                    );
+                }
+                field.on_change = Some(on_change);
+            }
+
             if !field.is_phantomvar {
                 var.default_to_generated_getter_setter();
             }
@@ -727,14 +921,29 @@ fn parse_fields(
                 ));
             }
         } else {
-            if field.is_phantomvar {
+            if field.tool_button.is_some() {
+                validate_tool_button_field(&field, &mut errors);
+            } else if field.is_phantomvar {
                 validate_phantomvar_field(&field, &mut errors);
             }
 
+            validate_on_change(&field, &mut errors);
             all_fields.push(field);
         }
     }
 
+    // Notifications go through the base object, so they need a Base<T> field. Checked after all fields are known.
+    if base_field.is_none() {
+        for field in all_fields.iter() {
+            if let Some(on_change) = field.on_change.as_ref().filter(|o| o.requires_base()) {
+                errors.push(error!(
+                    on_change.span,
+                    "keys `notify_property_list` and `emit_changed` require a field of type Base<T>"
+                ));
+            }
+        }
+    }
+
     Ok(Fields {
         all_fields,
         base_field,
@@ -816,6 +1025,78 @@ fn validate_phantomvar_field(field: &Field, errors: &mut Vec<Error>) {
     }
 }
 
+/// Checks class-level requirements of `#[export_tool_button]` fields.
+fn validate_tool_buttons(fields: &mut Fields, is_tool: bool) {
+    for field in fields.all_fields.iter() {
+        let Some(export) = field.tool_button.as_ref().and(field.export.as_ref()) else {
+            continue;
+        };
+
+        // Like GDScript's @export_tool_button, which only works in @tool scripts.
+        if !is_tool {
+            fields.errors.push(error!(
+                export.span,
+                "#[export_tool_button] requires #[class(tool)]"
+            ));
+        }
+
+        if fields.base_field.is_none() {
+            fields.errors.push(error!(
+                export.span,
+                "#[export_tool_button] requires a field of type Base<T>"
+            ));
+        }
+    }
+}
+
+fn validate_tool_button_field(field: &Field, errors: &mut Vec<Error>) {
+    let span = field
+        .export
+        .as_ref()
+        .map_or(field.span, |export| export.span);
+
+    // The button has no state of its own; the getter creates the callable on the fly.
+    if !field.is_phantomvar {
+        errors.push(error!(
+            span,
+            "#[export_tool_button] requires field type `PhantomVar<Callable>`"
+        ));
+    }
+
+    if let Some(var) = &field.var {
+        errors.push(error!(
+            var.span,
+            "#[export_tool_button] cannot be combined with #[var]"
+        ));
+    }
+
+    if let Some(on_change) = &field.on_change {
+        errors.push(error!(
+            on_change.span,
+            "#[export_tool_button] does not support change-notification keys"
+        ));
+    }
+}
+
+fn validate_on_change(field: &Field, errors: &mut Vec<Error>) {
+    let Some(on_change) = &field.on_change else {
+        return;
+    };
+
+    // Hooks are run by the generated setter. With only #[export], a generated setter is implied.
+    let has_generated_setter = match &field.var {
+        Some(var) => var.setter == GetterSetter::Generated,
+        None => true,
+    };
+
+    if field.is_phantomvar || !has_generated_setter {
+        errors.push(error!(
+            on_change.span,
+            "change-notification keys require a generated setter; call the hooks manually in a custom `set` function instead"
+        ));
+    }
+}
+
 fn handle_opposite_keys(
     parser: &mut KvParser,
     key: &str,
@@ -20,8 +20,10 @@ mod data_models {
     pub mod inherent_impl;
     pub mod interface_trait_impl;
     pub mod property;
+    pub mod resource_format;
     #[cfg_attr(not(feature = "codegen-full"), allow(dead_code))]
     pub mod rpc;
+    pub mod script_language;
     pub mod signal;
 }
 
@@ -33,7 +35,9 @@ pub(crate) use data_models::func::*;
 pub(crate) use data_models::inherent_impl::*;
 pub(crate) use data_models::interface_trait_impl::*;
 pub(crate) use data_models::property::*;
+pub(crate) use data_models::resource_format::*;
 pub(crate) use data_models::rpc::*;
+pub(crate) use data_models::script_language::*;
 pub(crate) use data_models::signal::*;
 pub(crate) use derive_godot_class::*;
 pub(crate) use godot_api::*;
@@ -0,0 +1,260 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::derive::data_models::CStyleEnum;
+use crate::derive::make_class_enum_fn;
+use crate::util::{bail, KvParser, ListParser};
+use crate::ParseResult;
+
+/// Derives `EngineEnum` (and `ExportEnum`) for a C-style enum.
+pub fn derive_engine_enum(item: venial::Item) -> ParseResult<TokenStream> {
+    let venial::Item::Enum(enum_) = item else {
+        return bail!(item, "#[derive(EngineEnum)] only supports C-style enums");
+    };
+
+    if let Some(generic_params) = &enum_.generic_params {
+        return bail!(
+            generic_params,
+            "#[derive(EngineEnum)] does not support lifetimes or generic parameters"
+        );
+    }
+
+    let name = &enum_.name;
+    let c_style = CStyleEnum::parse_enum(&enum_)?;
+    let names = c_style.enumerator_names();
+    let names_str = names.iter().map(ToString::to_string).collect::<Vec<_>>();
+    let constant = quote! { ::godot::register::private::constant };
+
+    Ok(quote! {
+        impl ::godot::obj::EngineEnum for #name {
+            fn try_from_ord(ord: i32) -> ::std::option::Option<Self> {
+                #(
+                    if ord == #name::#names as i32 {
+                        return Some(#name::#names);
+                    }
+                )*
+                None
+            }
+
+            fn ord(self) -> i32 {
+                self as i32
+            }
+
+            fn as_str(&self) -> &'static str {
+                match self {
+                    #( #name::#names => #names_str, )*
+                }
+            }
+
+            fn godot_name(&self) -> &'static str {
+                self.as_str()
+            }
+
+            fn values() -> &'static [Self] {
+                &[ #( #name::#names ),* ]
+            }
+
+            fn all_constants() -> &'static [::godot::meta::inspect::EnumConstant<Self>] {
+                const {
+                    &[
+                        #( ::godot::meta::inspect::EnumConstant::new(#names_str, #names_str, #name::#names) ),*
+                    ]
+                }
+            }
+        }
+
+        impl #constant::ExportEnum for #name {
+            const IS_BITFIELD: bool = false;
+
+            fn enumerators() -> ::std::vec::Vec<#constant::IntegerConstant> {
+                ::std::vec![
+                    #( #constant::IntegerConstant::new(#names_str, #name::#names as i64) ),*
+                ]
+            }
+        }
+    })
+}
+
+/// Derives `EngineBitfield` (and conversion, property and `ExportEnum` traits) for a newtype struct `Name(u64)`.
+pub fn derive_engine_bitfield(item: venial::Item) -> ParseResult<TokenStream> {
+    let venial::Item::Struct(struct_) = item else {
+        return bail!(
+            item,
+            "#[derive(EngineBitfield)] only supports newtype structs, e.g. `struct Flags(u64);`"
+        );
+    };
+
+    if let Some(generic_params) = &struct_.generic_params {
+        return bail!(
+            generic_params,
+            "#[derive(EngineBitfield)] does not support lifetimes or generic parameters"
+        );
+    }
+
+    match &struct_.fields {
+        venial::Fields::Tuple(fields) if fields.fields.len() == 1 => {}
+        _ => {
+            return bail!(
+                struct_,
+                "#[derive(EngineBitfield)] requires a newtype struct with a single `u64` field, e.g. `struct Flags(u64);`"
+            )
+        }
+    }
+
+    let Some(mut parser) = KvParser::parse(&struct_.attributes, "godot")? else {
+        return bail!(
+            struct_,
+            "#[derive(EngineBitfield)] requires an attribute #[godot(flags = (NAME = value, ...))]"
+        );
+    };
+    let Some(list) = parser.handle_list("flags")? else {
+        return bail!(
+            parser.span(),
+            "#[derive(EngineBitfield)] requires key `flags = (NAME = value, ...)`"
+        );
+    };
+    parser.finish()?;
+
+    let (flag_names, flag_values) = parse_flags(list)?;
+
+    let name = &struct_.name;
+    let vis = &struct_.vis_marker;
+    let flag_names_str = flag_names
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    let constant = quote! { ::godot::register::private::constant };
+    let class_enum_fn = make_class_enum_fn();
+    let bad_flags_error = format!("invalid {name} flags");
+
+    // Same format as CStyleEnum::to_int_hint(): "NAME:value,...".
+    let hint_fmt = flag_names_str
+        .iter()
+        .map(|flag| format!("{flag}:{{}}"))
+        .collect::<Vec<_>>()
+        .join(",");
+
+    Ok(quote! {
+        impl #name {
+            #(
+                #vis const #flag_names: Self = Self(#flag_values);
+            )*
+        }
+
+        impl ::godot::obj::EngineBitfield for #name {
+            fn try_from_ord(ord: u64) -> ::std::option::Option<Self> {
+                Some(Self(ord))
+            }
+
+            fn ord(self) -> u64 {
+                self.0
+            }
+
+            fn all_constants() -> &'static [::godot::meta::inspect::EnumConstant<Self>] {
+                const {
+                    &[
+                        #( ::godot::meta::inspect::EnumConstant::new(#flag_names_str, #flag_names_str, #name::#flag_names) ),*
+                    ]
+                }
+            }
+        }
+
+        impl ::std::ops::BitOr for #name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl ::std::ops::BitOrAssign for #name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl ::godot::meta::GodotConvert for #name {
+            type Via = i64;
+            #class_enum_fn
+        }
+
+        impl ::godot::meta::ToGodot for #name {
+            type ToVia<'v> = i64;
+
+            fn to_godot(&self) -> Self::ToVia<'_> {
+                self.0 as i64
+            }
+        }
+
+        impl ::godot::meta::FromGodot for #name {
+            fn try_from_godot(via: i64) -> ::std::result::Result<Self, ::godot::meta::error::ConvertError> {
+                match u64::try_from(via) {
+                    Ok(ord) => Ok(Self(ord)),
+                    Err(_) => Err(::godot::meta::error::ConvertError::with_error_value(#bad_flags_error, via)),
+                }
+            }
+        }
+
+        impl ::godot::register::property::Var for #name {
+            fn get_property(&self) -> i64 {
+                ::godot::meta::ToGodot::to_godot(self)
+            }
+
+            fn set_property(&mut self, value: i64) {
+                *self = ::godot::meta::FromGodot::from_godot(value);
+            }
+
+            fn var_hint() -> ::godot::meta::PropertyHintInfo {
+                ::godot::meta::PropertyHintInfo {
+                    hint: ::godot::global::PropertyHint::FLAGS,
+                    hint_string: ::godot::builtin::GString::from(format!(#hint_fmt, #( #name::#flag_names.0 ),*)),
+                }
+            }
+        }
+
+        impl ::godot::register::property::Export for #name {}
+
+        impl #constant::ExportEnum for #name {
+            const IS_BITFIELD: bool = true;
+
+            fn enumerators() -> ::std::vec::Vec<#constant::IntegerConstant> {
+                ::std::vec![
+                    #( #constant::IntegerConstant::new(#flag_names_str, #name::#flag_names.0) ),*
+                ]
+            }
+        }
+    })
+}
+
+/// Parses `(NAME = value, ...)` into flag names and value expressions.
+fn parse_flags(mut list: ListParser) -> ParseResult<(Vec<Ident>, Vec<TokenStream>)> {
+    let mut names = Vec::new();
+    let mut values = Vec::new();
+
+    while list.peek().is_some() {
+        let Some((flag, value)) = list.try_next_key_value() else {
+            let expr = list.next_expr()?;
+            return bail!(expr, "expected flag of the form `NAME = value`");
+        };
+
+        names.push(flag);
+        values.push(value.expr()?);
+    }
+    list.finish()?;
+
+    if names.is_empty() {
+        return bail!(
+            proc_macro2::Span::call_site(),
+            "#[godot(flags)] must contain at least one flag"
+        );
+    }
+
+    Ok((names, values))
+}
@@ -10,7 +10,7 @@ use std::collections::HashMap;
 use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::quote;
 
-use crate::derive::data_models::GodotConvert;
+use crate::derive::data_models::{ConvertType, GodotConvert, ViaType};
 use crate::derive::{make_fromgodot, make_togodot};
 use crate::ParseResult;
 
@@ -27,9 +27,19 @@ pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
     let to_godot_impl = make_togodot(&convert, &mut cache);
     let from_godot_impl = make_fromgodot(&convert, &mut cache);
 
+    // Integer enums can be attached to a class with #[export_enum], which changes their metadata.
+    let class_enum_fn = match &convert.convert_type {
+        ConvertType::Enum {
+            via: ViaType::Int { .. },
+            ..
+        } => make_class_enum_fn(),
+        _ => TokenStream::new(),
+    };
+
     Ok(quote! {
         impl ::godot::meta::GodotConvert for #name  {
             type Via = #via_type;
+            #class_enum_fn
         }
 
         #to_godot_impl
@@ -40,6 +50,15 @@ pub fn derive_godot_convert(item: venial::Item) -> ParseResult<TokenStream> {
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Helpers for submodules
 
+/// Implements `GodotConvert::class_enum()` by looking up `#[export_enum]` declarations of `Self`.
+pub fn make_class_enum_fn() -> TokenStream {
+    quote! {
+        fn class_enum() -> ::std::option::Option<::godot::register::private::constant::ClassEnumInfo> {
+            ::godot::register::private::constant::class_enum_info::<Self>()
+        }
+    }
+}
+
 /// Caches enumerator ordinal expressions that are modified, e.g. `(1 + 2) as isize` -> `(1 + 2) as i64`.
 #[derive(Default)]
 pub struct EnumeratorExprCache {
@@ -8,12 +8,14 @@
 //! Derive macros on types outside of classes.
 
 mod data_models;
+mod derive_engine_enum;
 mod derive_export;
 mod derive_from_godot;
 mod derive_godot_convert;
 mod derive_to_godot;
 mod derive_var;
 
+pub(crate) use derive_engine_enum::*;
 pub(crate) use derive_export::*;
 pub(crate) use derive_from_godot::*;
 pub(crate) use derive_godot_convert::*;
@@ -49,11 +49,16 @@ pub fn attribute_gdextension(item: venial::Item) -> ParseResult<TokenStream> {
         .unwrap_or_else(|| ident("gdext_rust_init"));
 
     let impl_ty = &impl_decl.self_ty;
+    let entry_symbol_str = entry_point.to_string();
 
     Ok(quote! {
         #deprecation
         #impl_decl
 
+        impl ::godot::init::ExtensionEntrySymbol for #impl_ty {
+            const ENTRY_SYMBOL: &'static str = #entry_symbol_str;
+        }
+
         // This cfg cannot be checked from the outer proc-macro since its 'target' is the build
         // host. See: https://github.com/rust-lang/rust/issues/42587
         #[cfg(target_os = "emscripten")]
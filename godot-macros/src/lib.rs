@@ -127,6 +127,50 @@ use crate::util::{bail, ident, KvParser};
 /// }
 /// ```
 ///
+/// ## Rust base classes
+///
+/// A class can also inherit from another class declared in Rust, by adding the `rust_base` key. The derived class is registered
+/// after its base class, so it inherits all `#[func]` methods, `#[var]` properties and signals. `Gd<Derived>` can be upcast to
+/// `Gd<Base>`, and `base()`/`base_mut()` give access to the nearest engine class.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Character {
+///     #[var]
+///     health: i64,
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Character {
+///     #[func(virtual)]
+///     fn damage(&mut self, amount: i64) {
+///         self.health -= amount;
+///     }
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Character, rust_base)]
+/// struct Enemy {
+///     base: Base<Character>,
+/// }
+///
+/// #[godot_api]
+/// impl Enemy {
+///     // Overrides Character::damage(), also when called through Gd<Character>.
+///     #[func(virtual)]
+///     fn damage(&mut self, _amount: i64) {}
+/// }
+/// ```
+///
+/// Some restrictions apply:
+/// - The base class must be declared before the derived class, either in the same module or in a `#[macro_use]` module declared earlier.
+/// - The base class must be default-constructible (`init`), as its Rust part is created together with the derived object.
+/// - Interface methods such as `ready()` or `on_notification()` implemented in the derived class replace those of the base class.
+/// - Overriding `#[func(virtual)]` methods requires Godot 4.3 or later.
+///
 ///
 /// # Properties and exports
 ///
@@ -210,6 +254,40 @@ use crate::util::{bail, ident, KvParser};
 /// To create a property without a backing field to store data, you can use [`PhantomVar`](../obj/struct.PhantomVar.html).
 /// This disables autogenerated getters and setters for that field.
 ///
+/// ## Reacting to changes -- `on_change`
+///
+/// Instead of writing a custom setter just to react to modifications, you can let the generated setter call a method after
+/// it has assigned the new value. The method receives the old and new values, in the property's `Via` type. The same keys are
+/// available on `#[export]`.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[var(on_change = on_health_changed, notify_property_list, emit_changed)]
+///     health: i32,
+///
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     fn on_health_changed(&mut self, old: i32, new: i32) {
+///         godot_print!("health: {old} -> {new}");
+///     }
+/// }
+/// ```
+///
+/// The hooks run in this order:
+/// 1. `on_change = method` calls `self.method(old, new)`.
+/// 2. `notify_property_list` calls `notify_property_list_changed()` on the base object, e.g. to refresh the inspector.
+/// 3. `emit_changed` emits a typed signal named `<field>_changed` with the new value as its only parameter. The signal is registered
+///    with Godot and available as `self.signals().health_changed()`. Requires a `#[godot_api]` impl block with typed signals (Godot 4.2+).
+///
+/// Each key can be used on its own. `notify_property_list` and `emit_changed` need a `Base<T>` field. Change hooks are run by the
+/// generated setter, so they cannot be combined with a custom `set = ...` function, nor be triggered by assigning the Rust field directly.
+///
 /// ## Export properties -- `#[export]`
 ///
 /// To export properties to the editor, you can use the `#[export]` attribute, which supports types implementing
@@ -360,6 +438,36 @@ use crate::util::{bail, ident, KvParser};
 ///```
 ///
 ///
+/// ## Inspector buttons -- `#[export_tool_button]`
+///
+/// Tool classes can show a button in the inspector, which invokes a `#[func]` when pressed. This corresponds to GDScript's
+/// [`@export_tool_button`](https://docs.godotengine.org/en/latest/classes/class_@gdscript.html#class-gdscript-annotation-export-tool-button)
+/// and requires Godot 4.4 or later.
+///
+/// The field must have type `PhantomVar<Callable>`, and the class needs `#[class(tool)]` and a `Base<T>` field. Key `icon` is optional
+/// and refers to an editor theme icon.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, tool, base=Node)]
+/// struct Terrain {
+///     // @export_tool_button("Regenerate", "Reload") var regenerate_button = regenerate
+///     #[export_tool_button(fn = regenerate, text = "Regenerate", icon = "Reload")]
+///     regenerate_button: PhantomVar<Callable>,
+///
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Terrain {
+///     #[func]
+///     fn regenerate(&mut self) {
+///         godot_print!("regenerating...");
+///     }
+/// }
+/// ```
+///
 /// ## Low-level property hints and usage
 ///
 /// You can specify custom property hints, hint strings, and usage flags in a `#[var]` attribute using the `hint`, `hint_string`
@@ -464,6 +572,89 @@ use crate::util::{bail, ident, KvParser};
 /// Even though this class is a `Node` and it has an init function, it still won't show up in the editor as a node you can add to a scene
 /// because we have added a `hidden` key to the class. This will also prevent it from showing up in documentation.
 ///
+/// ## Engine singletons
+///
+/// With `#[class(singleton)]`, godot-rust creates one instance of the class when it is loaded, and registers it with
+/// [`Engine::register_singleton()`](../classes/struct.Engine.html#method.register_singleton). This makes it accessible from GDScript
+/// by its class name, and from Rust through a generated `singleton()` accessor -- just like engine singletons such as `Input`.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(base=Object, init, singleton)]
+/// pub struct GameState {
+///     score: i64,
+/// }
+///
+/// fn add_points(points: i64) {
+///     let mut state: Gd<GameState> = GameState::singleton();
+///     state.bind_mut().score += points;
+/// }
+/// ```
+///
+/// The instance is created after all classes of its init level are registered (but not before `Scene` level, as the engine's singleton
+/// registry may not be available earlier), and freed again before the classes are unregistered. The class needs a constructor (`init` or a user-defined one), which is only used for the singleton itself;
+/// Godot cannot instantiate further objects. Singletons must be manually managed, i.e. they cannot inherit `RefCounted`.
+///
+/// Editor plugins cannot be singletons, since the editor creates their instances itself:
+/// ```compile_fail
+/// # use godot::prelude::*;
+/// # use godot::classes::EditorPlugin;
+/// #[derive(GodotClass)]
+/// #[class(base=EditorPlugin, init, tool, singleton)]
+/// struct MyPlugin {
+///     base: Base<EditorPlugin>,
+/// }
+/// ```
+///
+/// ## Custom resource formats
+///
+/// `#[class(resource_format = (extensions = ["lvl", ...], resource = LevelData))]` registers a file format for the resource class
+/// `LevelData`. The annotated class must implement [`ResourceFormat<LevelData>`](../tools/trait.ResourceFormat.html), which converts
+/// between bytes and resources. A `ResourceFormatLoader` and `ResourceFormatSaver` are generated and added to the engine while the
+/// `Scene` init level is loaded, so that `load()`, `save()` and the editor work with files of the given extensions.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::global::Error;
+/// # use godot::tools::ResourceFormat;
+/// # #[derive(GodotClass)]
+/// # #[class(init, base=Resource)]
+/// # struct LevelData {}
+/// #[derive(GodotClass)]
+/// #[class(no_init, resource_format = (extensions = ["lvl"], resource = LevelData))]
+/// struct LevelFormat {}
+///
+/// impl ResourceFormat<LevelData> for LevelFormat {
+///     fn load(bytes: &[u8]) -> Result<Gd<LevelData>, Error> {
+///         // Parse bytes...
+/// #       Ok(LevelData::new_gd())
+///     }
+/// }
+/// ```
+///
+/// ## Script languages
+///
+/// `#[class(script_language)]` registers a scripting language, whose semantics are defined by the annotated class' implementation of
+/// [`RustScriptLanguage`](../obj/script/trait.RustScriptLanguage.html). Generates a `ScriptLanguageExtension`, a `ScriptExtension`
+/// for script resources, as well as a loader and saver for the language's file extension. The language is registered with the engine
+/// while the `Scene` init level is loaded. Cannot be combined with `resource_format`.
+///
+/// See the `RustScriptLanguage` docs for an example. Available since Godot 4.2.
+///
+/// ## Editor icons
+///
+/// `#[class(icon = "res://icons/my_node.svg")]` declares the icon shown for the class in the editor's scene tree and class lists.
+/// Godot reads icons from the `[icons]` section of the `.gdextension` file. All declared icons are returned by
+/// [`class_icons()`](../init/fn.class_icons.html), to be written with `godot_bindings::GdextensionManifest`.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(base=Node2D, init, icon = "res://icons/player.svg")]
+/// pub struct Player {}
+/// ```
+///
 /// # Further field customization
 ///
 /// ## Fine-grained inference hints
@@ -544,7 +735,17 @@ use crate::util::{bail, ident, KvParser};
 )]
 #[proc_macro_derive(
     GodotClass,
-    attributes(class, base, hint, var, export, export_group, export_subgroup, init)
+    attributes(
+        class,
+        base,
+        hint,
+        var,
+        export,
+        export_group,
+        export_subgroup,
+        export_tool_button,
+        init
+    )
 )]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
@@ -1088,6 +1289,85 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for [`EngineEnum`](../obj/trait.EngineEnum.html) on C-style enums.
+///
+/// Together with `#[export_enum]` inside `#[godot_api]`, this registers a Rust enum as class-scoped enum in Godot. It is typically combined
+/// with [`GodotConvert`] using an integer `via` type, as well as [`Var`] and [`Export`]. The enum must implement `Copy` and `Eq`.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotConvert, Var, Export, EngineEnum, Clone, Copy, PartialEq, Eq, Debug)]
+/// #[godot(via = i64)]
+/// pub enum State {
+///     Idle,
+///     Running,
+///     Jumping,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[export]
+///     state: State,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     // Available as `Player.State` in GDScript, e.g. `Player.State.Running`.
+///     #[export_enum]
+///     type State = State;
+///
+///     #[func]
+///     fn set_state(&mut self, state: State) {
+///         self.state = state;
+///     }
+/// }
+/// ```
+///
+/// In GDScript, the property, the parameter of `set_state()` and all other uses of `State` in `#[var]`, `#[export]` and `#[func]` are then
+/// declared with type `Player.State` rather than `int`, which enables type checks and auto-completion.
+#[proc_macro_derive(EngineEnum)]
+pub fn derive_engine_enum(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_engine_enum)
+}
+
+/// Derive macro for [`EngineBitfield`](../obj/trait.EngineBitfield.html) on newtype structs.
+///
+/// Declares a set of flags similar to the `bitflags` crate: each flag listed in `#[godot(flags = (...))]` becomes an associated constant,
+/// and flags can be combined with `|`. The struct must wrap a single `u64` and implement `Copy` and `Eq`.
+///
+/// In addition to `EngineBitfield`, this derive implements [`GodotConvert`], [`Var`] and [`Export`] (showing a flags editor in the
+/// inspector), so those should not be derived separately. With `#[export_enum]`, the bitfield becomes class-scoped in Godot.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(EngineBitfield, Clone, Copy, PartialEq, Eq, Debug)]
+/// #[godot(flags = (FIRE = 1, WATER = 2, EARTH = 4))]
+/// pub struct Elements(u64);
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Spell {
+///     #[export]
+///     elements: Elements,
+/// }
+///
+/// #[godot_api]
+/// impl Spell {
+///     // Available as `Spell.Elements` in GDScript, e.g. `Spell.FIRE | Spell.WATER`.
+///     #[export_enum]
+///     type Elements = Elements;
+/// }
+///
+/// let steam = Elements::FIRE | Elements::WATER;
+/// ```
+#[proc_macro_derive(EngineBitfield, attributes(godot))]
+pub fn derive_engine_bitfield(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_engine_bitfield)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
@@ -429,6 +429,11 @@ pub fn format_class_base_field_macro(class_name: &Ident) -> Ident {
     format_ident!("__godot_{class_name}_has_base_field_macro")
 }
 
+/// Returns the name of the macro used to implement `Inherits` for classes deriving from a Rust class.
+pub fn format_class_inherits_macro(class_name: &Ident) -> Ident {
+    format_ident!("__godot_{class_name}_inherits_macro")
+}
+
 /// Returns the name of the macro used to deny manual `init()` for incompatible init strategies.
 pub fn format_class_deny_manual_init_macro(class_name: &Ident) -> Ident {
     format_ident!("__deny_manual_init_{class_name}")
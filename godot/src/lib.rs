@@ -134,7 +134,12 @@
 //!   Generates documentation for your structs from your Rust documentation.
 //!   Documentation is visible in Godot via `F1` -> searching for that class.
 //!   This feature requires at least Godot 4.3.
-//!   See also: [`#[derive(GodotClass)]`](register/derive.GodotClass.html#documentation)
+//!   See also: [`#[derive(GodotClass)]`](register/derive.GodotClass.html#documentation)<br><br>
+//!
+//! * **`translation-template`**
+//!
+//!   Records the messages of all `tr!` and `tr_n!` invocations when the library is loaded, so they can be written to a POT file with
+//!   `TranslationTemplate::update_file()`. Without it, the macros generate no registration code.
 //!
 //! _Integrations:_
 //!
@@ -143,6 +148,11 @@
 //!   Implement the [serde](https://serde.rs/) traits `Serialize` and `Deserialize` traits for certain built-in types.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!
+//! * **`futures`**
+//!
+//!   Implement the [`futures_core::Stream`](https://docs.rs/futures-core/latest/futures_core/stream/trait.Stream.html) trait for
+//!   [`SignalStream`](task/struct.SignalStream.html), so that signal emissions can be used with stream combinators.
+//!
 
 #![doc(
     html_logo_url = "https://raw.githubusercontent.com/godot-rust/assets/master/gdext/ferris.svg"
@@ -195,7 +205,9 @@ pub mod register {
     pub use godot_core::registry::signal::re_export::*;
     #[cfg(feature = "__codegen-full")]
     pub use godot_core::registry::RpcConfig;
-    pub use godot_macros::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+    pub use godot_macros::{
+        godot_api, godot_dyn, EngineBitfield, EngineEnum, Export, GodotClass, GodotConvert, Var,
+    };
 
     /// Re-exports used by proc-macro API.
     #[doc(hidden)]
@@ -203,7 +215,7 @@ pub mod register {
         #[cfg(feature = "__codegen-full")]
         pub use godot_core::registry::class::auto_register_rpcs;
         pub use godot_core::registry::godot_register_wrappers::*;
-        pub use godot_core::registry::{constant, method};
+        pub use godot_core::registry::{constant, method, resource_format, script_language};
     }
 }
 
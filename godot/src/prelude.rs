@@ -22,7 +22,9 @@ pub use super::obj::{
 };
 pub use super::register::property::{Export, PhantomVar, Var};
 // Re-export macros.
-pub use super::register::{godot_api, godot_dyn, Export, GodotClass, GodotConvert, Var};
+pub use super::register::{
+    godot_api, godot_dyn, EngineBitfield, EngineEnum, Export, GodotClass, GodotConvert, Var,
+};
 pub use super::tools::{load, save, try_load, try_save, GFile};
 
 // Make trait methods available.
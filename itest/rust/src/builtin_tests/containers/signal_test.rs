@@ -8,7 +8,7 @@
 use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
-use godot::builtin::{vslice, GString, Signal, StringName};
+use godot::builtin::{vslice, GString, Signal, StringName, Variant};
 use godot::classes::object::ConnectFlags;
 use godot::classes::{Node, Node3D, Object, RefCounted};
 use godot::meta::{FromGodot, GodotConvert, ParamType, ToGodot};
@@ -563,6 +563,289 @@ static LAST_STATIC_FUNCTION_ARG: Global<i64> = Global::default();
 // Separate module to test signal visibility.
 use emitter::Emitter;
 
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_builder_filter_map_take() {
+    let mut emitter = Emitter::new_alloc();
+    let received = Rc::new(RefCell::new(Vec::<String>::new()));
+
+    let tracker = received.clone();
+    let handle = emitter
+        .signals()
+        .signal_int()
+        .builder()
+        .filter(|(i,)| i % 2 == 0)
+        .map(|(i,)| (format!("#{i}"),))
+        .take(2)
+        .connect(move |s: String| tracker.borrow_mut().push(s));
+
+    for i in 1..=6 {
+        emitter.signals().signal_int().emit(i);
+    }
+    assert_eq!(*received.borrow(), vec!["#2", "#4"]);
+
+    // Whole chain is one connection.
+    assert!(handle.is_connected());
+    handle.disconnect();
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_builder_take_while_throttle() {
+    let mut emitter = Emitter::new_alloc();
+    let until_negative = Rc::new(RefCell::new(Vec::new()));
+    let throttled = Rc::new(Cell::new(0));
+
+    let tracker = until_negative.clone();
+    emitter
+        .signals()
+        .signal_int()
+        .builder()
+        .take_while(|(i,)| *i >= 0)
+        .connect(move |i| tracker.borrow_mut().push(i));
+
+    let tracker = throttled.clone();
+    emitter
+        .signals()
+        .signal_int()
+        .builder()
+        .throttle(std::time::Duration::from_secs(60))
+        .connect(move |_i| tracker.set(tracker.get() + 1));
+
+    for i in [1, 2, -1, 3] {
+        emitter.signals().signal_int().emit(i);
+    }
+
+    assert_eq!(*until_negative.borrow(), vec![1, 2]);
+    assert_eq!(throttled.get(), 1, "only first emission within interval");
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest(async)]
+fn signal_builder_debounce() -> godot::task::TaskHandle {
+    use std::time::Duration;
+
+    let emitter = Emitter::new_alloc();
+    let received = Rc::new(RefCell::new(Vec::new()));
+
+    let tracker = received.clone();
+    emitter
+        .signals()
+        .signal_int()
+        .builder()
+        .debounce(Duration::from_millis(30))
+        .connect(move |i| tracker.borrow_mut().push(i));
+
+    // Pending emissions are discarded once disconnected, or once the owner is freed.
+    let discarded = Rc::new(Cell::new(0));
+    let tracker = discarded.clone();
+    let handle = emitter
+        .signals()
+        .signal_int()
+        .builder()
+        .debounce(Duration::from_millis(30))
+        .connect(move |_i| tracker.set(tracker.get() + 1));
+
+    let owner = Object::new_alloc();
+    let tracker = discarded.clone();
+    emitter
+        .signals()
+        .signal_int()
+        .builder()
+        .owned_by(owner.instance_id())
+        .debounce(Duration::from_millis(30))
+        .connect(move |_i| tracker.set(tracker.get() + 1));
+
+    for i in 1..=3 {
+        emitter.signals().signal_int().emit(i);
+    }
+
+    handle.disconnect();
+    owner.free();
+
+    godot::task::spawn(async move {
+        assert!(received.borrow().is_empty(), "nothing forwarded yet");
+
+        let options = godot::task::TimerOptions {
+            ignore_time_scale: true,
+            ..Default::default()
+        };
+        godot::task::sleep(Duration::from_millis(100), options).await;
+
+        assert_eq!(*received.borrow(), vec![3], "only last emission of burst");
+        assert_eq!(
+            discarded.get(),
+            0,
+            "no emission after disconnect or owner free"
+        );
+        emitter.free();
+    })
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_connect_guard() {
+    let mut emitter = Emitter::new_alloc();
+    let count = Rc::new(Cell::new(0));
+
+    let tracker = count.clone();
+    let guard = emitter
+        .signals()
+        .signal_unit()
+        .connect(move || tracker.set(tracker.get() + 1))
+        .into_guard();
+
+    emitter.signals().signal_unit().emit();
+    assert!(guard.is_connected());
+    drop(guard);
+
+    emitter.signals().signal_unit().emit();
+    assert_eq!(count.get(), 1, "guard disconnects on drop");
+
+    // Released guard keeps the connection.
+    let tracker = count.clone();
+    let handle = emitter
+        .signals()
+        .signal_unit()
+        .connect(move || tracker.set(tracker.get() + 1))
+        .into_guard()
+        .release();
+
+    emitter.signals().signal_unit().emit();
+    assert_eq!(count.get(), 2);
+    assert!(handle.is_connected());
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_connection_group() {
+    let mut emitter = Emitter::new_alloc();
+    let count = Rc::new(Cell::new(0));
+
+    let mut group = godot::register::ConnectionGroup::new();
+    for _ in 0..3 {
+        let tracker = count.clone();
+        group.add(
+            emitter
+                .signals()
+                .signal_int()
+                .connect(move |_i| tracker.set(tracker.get() + 1)),
+        );
+    }
+    assert_eq!(group.len(), 3);
+
+    emitter.signals().signal_int().emit(7);
+    assert_eq!(count.get(), 3);
+
+    group.disconnect_all();
+    assert!(group.is_empty());
+
+    emitter.signals().signal_int().emit(7);
+    assert_eq!(count.get(), 3, "all connections removed");
+
+    // Dropping the group after the emitter is freed must not disconnect twice.
+    let tracker = count.clone();
+    group.add(
+        emitter
+            .signals()
+            .signal_unit()
+            .connect(move || tracker.set(tracker.get() + 1)),
+    );
+    emitter.free();
+    drop(group);
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_connect_owned_by() {
+    let mut emitter = Emitter::new_alloc();
+    let owner = Node::new_alloc();
+    let count = Rc::new(Cell::new(0));
+
+    let tracker = count.clone();
+    let handle = emitter
+        .signals()
+        .signal_int()
+        .builder()
+        .owned_by(owner.instance_id())
+        .connect(move |_i| tracker.set(tracker.get() + 1));
+
+    emitter.signals().signal_int().emit(1);
+    assert_eq!(count.get(), 1);
+
+    owner.free();
+    assert!(!handle.is_connected(), "freeing owner disconnects");
+
+    emitter.signals().signal_int().emit(2);
+    assert_eq!(count.get(), 1);
+
+    emitter.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_dyn_typed_access() {
+    use godot::builtin::{varray, vdict, VariantType};
+
+    let mut object = RefCounted::new_gd();
+    let args = varray![
+        vdict! { "name": "damage", "type": VariantType::INT },
+        vdict! { "name": "source", "type": VariantType::STRING },
+    ];
+    object.add_user_signal_ex("hit").arguments(&args).done();
+
+    let received = Rc::new(RefCell::new(None));
+    let tracker = received.clone();
+
+    let mut hit = object
+        .dyn_signal::<(i32, GString)>("hit")
+        .expect("signature matches");
+    hit.connect(move |damage, source| *tracker.borrow_mut() = Some((damage, source)));
+    hit.emit_tuple((10, GString::from("trap")));
+
+    assert_eq!(*received.borrow(), Some((10, GString::from("trap"))));
+
+    // Variant accepts any declared type.
+    assert!(object.dyn_signal::<(Variant, GString)>("hit").is_ok());
+
+    let err = object.dyn_signal::<(i32,)>("hit").unwrap_err();
+    assert!(!err.is_not_found());
+
+    let err = object.dyn_signal::<(GString, GString)>("hit").unwrap_err();
+    assert_eq!(err.signal_name(), "hit");
+
+    let err = object.dyn_signal::<()>("missing").unwrap_err();
+    assert!(err.is_not_found());
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn signal_dyn_untyped_params() {
+    let mut object = RefCounted::new_gd();
+    object
+        .add_user_signal_ex("changed")
+        .arguments(&godot::builtin::varray![
+            godot::builtin::vdict! { "name": "value" }
+        ])
+        .done();
+
+    // Untyped declaration is compatible with any Rust type.
+    let received = Rc::new(Cell::new(0));
+    let tracker = received.clone();
+
+    let mut changed = object.dyn_signal::<(i64,)>("changed").unwrap();
+    changed.connect(move |value| tracker.set(value));
+    changed.emit_tuple((42,));
+
+    assert_eq!(received.get(), 42);
+}
+
 mod emitter {
     use godot::obj::WithUserSignals;
 
@@ -43,6 +43,8 @@ mod string {
 }
 
 mod script {
+    #[cfg(since_api = "4.2")]
+    mod rust_script_language_test;
     mod script_instance_tests;
 }
 
@@ -0,0 +1,145 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::collections::HashMap;
+
+use godot::builtin::{GString, StringName, Variant};
+use godot::classes::{Object, Script};
+use godot::meta::{PropertyInfo, ToGodot};
+use godot::obj::script::{RustScriptLanguage, ScriptError};
+use godot::obj::{Gd, NewAlloc};
+use godot::register::GodotClass;
+use godot::tools::{load, try_load};
+
+use crate::framework::itest;
+
+/// Language in which each line `name = value` declares an integer property.
+#[derive(GodotClass)]
+#[class(no_init, script_language)]
+struct PropScript {}
+
+impl RustScriptLanguage for PropScript {
+    const NAME: &'static str = "PropScript";
+    const EXTENSION: &'static str = "propscript";
+
+    type Program = Vec<(String, i64)>;
+    type State = HashMap<String, i64>;
+
+    fn compile(source: &str) -> Result<Self::Program, ScriptError> {
+        let mut props = Vec::new();
+        for (line, text) in source.lines().enumerate() {
+            let Some((name, value)) = text.split_once('=') else {
+                continue;
+            };
+
+            let value = value
+                .trim()
+                .parse()
+                .map_err(|_| ScriptError::new("expected integer").at(line as u32 + 1, 1))?;
+
+            props.push((name.trim().to_string(), value));
+        }
+
+        Ok(props)
+    }
+
+    fn create_state(program: &Self::Program) -> Self::State {
+        program.iter().cloned().collect()
+    }
+
+    fn property_list(program: &Self::Program) -> Vec<PropertyInfo> {
+        program
+            .iter()
+            .map(|(name, _)| PropertyInfo::new_var::<i64>(name))
+            .collect()
+    }
+
+    fn get_property(
+        _program: &Self::Program,
+        state: &Self::State,
+        name: &StringName,
+    ) -> Option<Variant> {
+        state.get(&name.to_string()).map(|value| value.to_variant())
+    }
+
+    fn set_property(
+        _program: &Self::Program,
+        state: &mut Self::State,
+        name: &StringName,
+        value: &Variant,
+    ) -> bool {
+        match state.get_mut(&name.to_string()) {
+            Some(slot) => {
+                *slot = value.to();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn write_test_file(file_name: &str, contents: &str) {
+    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    std::fs::write(godot_path.join(file_name), contents).expect("write test file");
+}
+
+fn remove_test_file(file_name: &str) {
+    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    let file_path = godot_path.join(file_name);
+    std::fs::remove_file(&file_path)
+        .unwrap_or_else(|_| panic!("couldn't remove test file: {}", file_path.display()));
+}
+
+#[itest]
+fn script_language_load_and_attach() {
+    let file_name = "test_script_language_attach.propscript";
+    write_test_file(file_name, "health = 100\nlevel = 3\n");
+
+    let script = load::<Script>(&format!("res://{file_name}"));
+    assert!(script.can_instantiate());
+
+    let mut object = Object::new_alloc();
+    object.set_script(&script.to_variant());
+
+    assert_eq!(object.get("health"), 100.to_variant());
+    assert_eq!(object.get("level"), 3.to_variant());
+
+    object.set("level", &4.to_variant());
+    assert_eq!(object.get("level"), 4.to_variant());
+
+    // Properties not declared by the script are still handled by the object.
+    assert!(object.get("missing").is_nil());
+
+    object.free();
+    remove_test_file(file_name);
+}
+
+#[itest]
+fn script_language_load_parse_error() {
+    let file_name = "test_script_language_error.propscript";
+    write_test_file(file_name, "health = lots\n");
+
+    let result = try_load::<Script>(&format!("res://{file_name}"));
+    assert!(result.is_err());
+
+    remove_test_file(file_name);
+}
+
+#[itest]
+fn script_language_source_roundtrip() {
+    let file_name = "test_script_language_source.propscript";
+    let source = "speed = 7\n";
+    write_test_file(file_name, source);
+
+    let script: Gd<Script> = load(&format!("res://{file_name}"));
+    assert!(script.has_source_code());
+    assert_eq!(script.get_source_code(), GString::from(source));
+    assert_eq!(script.get_instance_base_type(), StringName::from("Object"));
+    assert!(!script.is_tool());
+
+    remove_test_file(file_name);
+}
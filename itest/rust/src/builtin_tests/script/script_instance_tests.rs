@@ -14,6 +14,8 @@ use godot::classes::{
 };
 use godot::global::{Error, MethodFlags};
 use godot::meta::{ClassName, FromGodot, MethodInfo, PropertyInfo, ToGodot};
+#[cfg(since_api = "4.2")]
+use godot::obj::script::PlaceholderInstances;
 use godot::obj::script::{create_script_instance, ScriptInstance, SiMut};
 use godot::obj::{Base, Gd, NewAlloc, WithBaseField};
 use godot::register::{godot_api, GodotClass};
@@ -25,12 +27,36 @@ use crate::framework::itest;
 #[class(base = ScriptExtension, no_init, tool)]
 struct TestScript {
     language: Gd<TestScriptLanguage>,
+
+    /// If set, objects get placeholder instances instead of `TestScriptInstance`.
+    #[cfg(since_api = "4.2")]
+    placeholders: Option<PlaceholderInstances>,
+
     base: Base<ScriptExtension>,
 }
 
 impl TestScript {
     fn new(language: Gd<TestScriptLanguage>) -> Gd<Self> {
-        Gd::from_init_fn(|base| Self { language, base })
+        Gd::from_init_fn(|base| Self {
+            language,
+            #[cfg(since_api = "4.2")]
+            placeholders: None,
+            base,
+        })
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn new_placeholder(language: Gd<TestScriptLanguage>) -> Gd<Self> {
+        Gd::from_init_fn(|base| Self {
+            language,
+            placeholders: Some(PlaceholderInstances::new()),
+            base,
+        })
+    }
+
+    #[cfg(since_api = "4.2")]
+    fn placeholders(&self) -> &PlaceholderInstances {
+        self.placeholders.as_ref().expect("placeholder script")
     }
 }
 
@@ -43,9 +69,30 @@ impl IScriptExtension for TestScript {
     }
 
     unsafe fn instance_create_rawptr(&self, for_object: Gd<Object>) -> *mut c_void {
+        // Godot attaches whatever instance is returned, so placeholders can be tested outside the editor.
+        #[cfg(since_api = "4.2")]
+        if let Some(placeholders) = &self.placeholders {
+            let language = self.language.clone().upcast();
+            let script = self.to_gd().upcast();
+
+            // SAFETY: Godot reports freed placeholders through placeholder_erased_rawptr() below.
+            return placeholders.create(&language, &script, &for_object);
+        }
+
         create_script_instance(TestScriptInstance::new(self.to_gd().upcast()), for_object)
     }
 
+    #[cfg(since_api = "4.2")]
+    unsafe fn placeholder_erased_rawptr(&mut self, placeholder: *mut c_void) {
+        if let Some(placeholders) = &self.placeholders {
+            placeholders.erase(placeholder);
+        }
+    }
+
+    fn is_placeholder_fallback_enabled(&self) -> bool {
+        false
+    }
+
     fn get_language(&self) -> Option<Gd<ScriptLanguage>> {
         Some(self.language.clone().upcast())
     }
@@ -78,7 +125,6 @@ impl IScriptExtension for TestScript {
     fn get_member_line(&self, _member: StringName) -> i32 { unreachable!() }
     fn get_constants(&self) -> Dictionary { unreachable!() }
     fn get_members(&self) -> Array<StringName> { unreachable!() }
-    fn is_placeholder_fallback_enabled(&self) -> bool { unreachable!() }
     fn get_rpc_config(&self) -> Variant { unreachable!() }
     
     #[cfg(since_api = "4.4")]
@@ -358,3 +404,45 @@ fn script_instance_exists() {
     object.free();
     language.free();
 }
+
+// Test that placeholder instances follow property updates, and are erased once Godot frees them.
+#[itest]
+#[cfg(since_api = "4.2")]
+fn script_placeholder_instances() {
+    let language = TestScriptLanguage::new_alloc();
+    let script = TestScript::new_placeholder(language.clone());
+    let mut object = Object::new_alloc();
+    let mut other = Object::new_alloc();
+
+    object.set_script(&script.to_variant());
+    other.set_script(&script.to_variant());
+    assert_eq!(script.bind().placeholders().len(), 2);
+    assert!(!property_names(&object).contains(&"health".to_string()));
+
+    script.bind().placeholders().update(
+        vec![PropertyInfo::new_export::<i64>("health")],
+        vec![(StringName::from("health"), 100.to_variant())],
+    );
+    assert!(property_names(&object).contains(&"health".to_string()));
+    assert!(property_names(&other).contains(&"health".to_string()));
+    assert_eq!(object.get("health"), 100.to_variant());
+
+    other.free();
+    assert_eq!(script.bind().placeholders().len(), 1);
+
+    object.set_script(&Variant::nil());
+    assert!(script.bind().placeholders().is_empty());
+
+    object.free();
+    language.free();
+}
+
+#[cfg(since_api = "4.2")]
+fn property_names(object: &Gd<Object>) -> Vec<String> {
+    object
+        .get_property_list()
+        .iter_shared()
+        .filter_map(|info| info.get("name"))
+        .map(|name| name.to_string())
+        .collect()
+}
@@ -5,13 +5,19 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::future::Future;
 use std::ops::Deref;
+use std::pin::Pin;
+use std::time::Duration;
 
 use godot::builtin::{array, vslice, Array, Callable, Signal, Variant};
-use godot::classes::{Object, RefCounted};
+use godot::classes::{Engine, Object, RefCounted, Time};
 use godot::obj::{Base, Gd, NewAlloc, NewGd};
 use godot::prelude::{godot_api, GodotClass};
-use godot::task::{self, create_test_signal_future_resolver, SignalFuture, TaskHandle};
+use godot::task::{
+    self, create_test_signal_future_resolver, CancellationToken, OverflowPolicy, SignalFuture,
+    SignalStream, TaskHandle, TimerOptions,
+};
 
 use crate::framework::{expect_async_panic, itest, TestContext};
 
@@ -233,3 +239,263 @@ fn async_typed_signal_with_array() -> TaskHandle {
 
     task_handle
 }
+
+#[itest(async)]
+fn async_next_frame() -> TaskHandle {
+    let start = Engine::singleton().get_process_frames();
+
+    task::spawn(async move {
+        task::next_frame().await;
+        assert!(Engine::singleton().get_process_frames() > start);
+
+        task::next_physics_frame().await;
+    })
+}
+
+#[itest(async)]
+fn async_sleep() -> TaskHandle {
+    let start = Time::singleton().get_ticks_msec();
+
+    task::spawn(async move {
+        task::sleep(Duration::from_millis(50), TimerOptions::default()).await;
+
+        // Timers are processed per frame, so they can't fire early; allow for some rounding.
+        assert!(Time::singleton().get_ticks_msec() - start >= 40);
+    })
+}
+
+#[itest(async)]
+fn async_timeout() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+
+    task::spawn(async move {
+        // Inner future completes first.
+        let result = task::timeout(Duration::from_secs(10), task::next_frame()).await;
+        assert_eq!(result, Ok(()));
+
+        // Signal is never emitted.
+        let never = object.signals().custom_signal().to_future();
+        let result = task::timeout(Duration::from_millis(20), never).await;
+        assert_eq!(result, Err(task::TimeoutError));
+    })
+}
+
+#[itest(async)]
+fn async_join_handle_result() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let copy = object.clone();
+
+    let producer = task::spawn(async move {
+        let (value,) = copy.signals().custom_signal().to_future().await;
+        value * 2
+    });
+
+    let consumer = task::spawn(async move {
+        let result = producer.await;
+        assert_eq!(result, Ok(42));
+    });
+
+    object.signals().custom_signal().emit(21);
+
+    consumer
+}
+
+#[itest(async)]
+fn async_join_all_and_select() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+
+    let tasks = (1..=3).map(|i| task::spawn(async move { i * 10 }));
+    let joined = task::join_all(tasks);
+
+    task::spawn(async move {
+        let results = joined.await;
+        assert_eq!(results, vec![Ok(10), Ok(20), Ok(30)]);
+
+        // Signal is never emitted, so the second future wins.
+        let futures: [Pin<Box<dyn Future<Output = (u32,)>>>; 2] = [
+            Box::pin(object.signals().custom_signal().to_future()),
+            Box::pin(async { (7,) }),
+        ];
+        let ((value,), index) = task::select(futures).await;
+        assert_eq!((value, index), (7, 1));
+    })
+}
+
+#[itest(async)]
+fn async_cancellation_token() -> TaskHandle {
+    let token = CancellationToken::new();
+    let task_token = token.clone();
+
+    let worker = task::spawn(async move {
+        let stop = task_token.cancelled();
+        let work = async {
+            loop {
+                task::next_frame().await;
+            }
+        };
+
+        let futures: [Pin<Box<dyn Future<Output = ()>>>; 2] = [Box::pin(stop), Box::pin(work)];
+        let ((), index) = task::select(futures).await;
+        assert_eq!(index, 0);
+        assert!(task_token.is_cancelled());
+    });
+
+    task::spawn(async move {
+        task::next_frame().await;
+        token.cancel();
+
+        assert_eq!(worker.await, Ok(()));
+    })
+}
+
+#[itest(async)]
+fn async_task_cancellation_token() -> TaskHandle {
+    assert!(CancellationToken::current().is_none());
+
+    let worker = task::spawn(async {
+        let token = CancellationToken::current().expect("token inside task");
+
+        let mut frames = 0;
+        while !token.is_cancelled() {
+            task::next_frame().await;
+            frames += 1;
+        }
+        frames
+    });
+    let token = worker.cancellation_token();
+
+    task::spawn(async move {
+        task::next_frame().await;
+        token.cancel();
+
+        let frames = worker.await.expect("task finishes on its own");
+        assert!(frames > 0);
+    })
+}
+
+#[itest(async)]
+fn async_spawn_blocking() -> TaskHandle {
+    let main_thread = std::thread::current().id();
+
+    let worker = task::spawn_blocking(move || {
+        assert_ne!(std::thread::current().id(), main_thread);
+        (1..=100).sum::<i32>()
+    });
+
+    task::spawn(async move {
+        assert_eq!(worker.await, Ok(5050));
+        assert_eq!(std::thread::current().id(), main_thread);
+
+        let worker = task::spawn_worker(async { "done".to_string() });
+        assert_eq!(worker.await.as_deref(), Ok("done"));
+    })
+}
+
+#[cfg(feature = "experimental-threads")]
+#[itest(async)]
+fn async_run_on_main() -> TaskHandle {
+    let main_thread = std::thread::current().id();
+
+    let worker = task::spawn_worker(async move {
+        let on_main = task::run_on_main(move || std::thread::current().id() == main_thread).await;
+        let blocking = task::run_on_main_blocking(|| 42);
+
+        (on_main, blocking)
+    });
+
+    task::spawn(async move {
+        let (on_main, blocking) = worker.await.expect("worker panicked");
+
+        assert_eq!(on_main, Ok(true));
+        assert_eq!(blocking, Ok(42));
+    })
+}
+
+#[cfg(feature = "experimental-threads")]
+#[itest]
+fn run_on_main_blocking_detects_deadlock() {
+    crate::framework::expect_panic("blocking on the main thread", || {
+        let _ = task::run_on_main_blocking(|| ());
+    });
+}
+
+#[itest(async)]
+fn async_signal_stream() -> TaskHandle {
+    let object = AsyncRefCounted::new_gd();
+    let mut stream = object.signals().custom_signal().to_stream();
+
+    // Emissions before the task awaits are buffered.
+    object.signals().custom_signal().emit(1);
+    object.signals().custom_signal().emit(2);
+
+    let mut emitter = Some(object);
+
+    task::spawn(async move {
+        assert_eq!(stream.next().await, Some((1,)));
+        assert_eq!(stream.next().await, Some((2,)));
+
+        let object = emitter.take().unwrap();
+        object.signals().custom_signal().emit(3);
+        drop(object);
+
+        // Last reference is gone: buffered emission is still delivered, then the stream ends.
+        assert_eq!(stream.next().await, Some((3,)));
+        assert_eq!(stream.next().await, None);
+        assert!(stream.is_ended());
+    })
+}
+
+#[itest]
+fn signal_stream_overflow() {
+    let object = AsyncRefCounted::new_gd();
+
+    let mut oldest = object
+        .signals()
+        .custom_signal()
+        .to_stream_with(2, OverflowPolicy::DropOldest);
+    let mut newest = object
+        .signals()
+        .custom_signal()
+        .to_stream_with(2, OverflowPolicy::DropNewest);
+
+    for i in 1..=3 {
+        object.signals().custom_signal().emit(i);
+    }
+
+    let waker = std::task::Waker::noop();
+    let mut cx = std::task::Context::from_waker(waker);
+    let mut drain = |stream: &mut SignalStream<(u32,)>| {
+        let mut values = vec![];
+        while let std::task::Poll::Ready(Some((value,))) = stream.poll_next(&mut cx) {
+            values.push(value);
+        }
+        values
+    };
+
+    assert_eq!(drain(&mut oldest), vec![2, 3]);
+    assert_eq!(drain(&mut newest), vec![1, 2]);
+    assert_eq!(oldest.overflow_count(), 1);
+    assert_eq!(newest.overflow_count(), 1);
+}
+
+#[cfg(feature = "futures")]
+#[itest]
+fn signal_stream_futures_core() {
+    use futures_core::Stream;
+
+    let object = AsyncRefCounted::new_gd();
+    let mut stream = object.signals().custom_signal().to_stream();
+
+    let waker = std::task::Waker::noop();
+    let mut cx = std::task::Context::from_waker(waker);
+    let mut poll = |stream: &mut SignalStream<(u32,)>| Stream::poll_next(Pin::new(stream), &mut cx);
+
+    assert_eq!(poll(&mut stream), std::task::Poll::Pending);
+
+    object.signals().custom_signal().emit(7);
+    assert_eq!(poll(&mut stream), std::task::Poll::Ready(Some((7,))));
+
+    // Freeing the signal object ends the stream.
+    drop(object);
+    assert_eq!(poll(&mut stream), std::task::Poll::Ready(None));
+}
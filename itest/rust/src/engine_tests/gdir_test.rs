@@ -0,0 +1,181 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::io::ErrorKind;
+
+use godot::builtin::GString;
+use godot::tools::{DirEntry, GDir};
+
+use crate::framework::itest;
+
+/// Creates `res://{root}` with the given files; directories are created as needed.
+fn create_test_tree(root: &str, files: &[&str]) {
+    let root_path =
+        std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/")).join(root);
+
+    for file in files {
+        let file_path = root_path.join(file);
+        std::fs::create_dir_all(file_path.parent().unwrap()).expect("create test dir");
+        std::fs::write(&file_path, "").expect("write test file");
+    }
+}
+
+fn relative_paths(
+    root: &str,
+    entries: impl Iterator<Item = std::io::Result<DirEntry>>,
+) -> Vec<String> {
+    let prefix = format!("res://{root}/");
+
+    entries
+        .map(|entry| {
+            let path = entry.expect("walk entry").path().to_string();
+            path.strip_prefix(&prefix).unwrap().to_string()
+        })
+        .collect()
+}
+
+#[itest]
+fn gdir_read_dir() {
+    let root = "dir_tests_read";
+    create_test_tree(root, &["a.txt", "b.txt", "sub/c.txt"]);
+
+    let mut dir = GDir::open(&format!("res://{root}")).unwrap();
+    let mut entries: Vec<DirEntry> = dir.read_dir().unwrap().collect();
+    entries.sort_by(|a, b| a.name().cmp(b.name()));
+
+    let names: Vec<&GString> = entries.iter().map(DirEntry::name).collect();
+    assert_eq!(
+        names,
+        [
+            &GString::from("a.txt"),
+            &GString::from("b.txt"),
+            &GString::from("sub")
+        ]
+    );
+
+    assert!(entries[0].is_file());
+    assert!(entries[2].is_dir());
+    assert_eq!(
+        entries[2].path(),
+        &GString::from(format!("res://{root}/sub"))
+    );
+    assert_eq!(entries[2].depth(), 1);
+
+    // Listing can be restarted after the previous iterator is dropped early.
+    let first = dir.read_dir().unwrap().next();
+    assert!(first.is_some());
+    assert_eq!(dir.read_dir().unwrap().count(), 3);
+
+    GDir::remove_dir_all(&format!("res://{root}")).unwrap();
+}
+
+#[itest]
+fn gdir_walk() {
+    let root = "dir_tests_walk";
+    create_test_tree(root, &["b.txt", "a/x.txt", "a/deep/y.txt", "c/z.txt"]);
+
+    let dir = GDir::open(&format!("res://{root}")).unwrap();
+
+    let all = relative_paths(root, dir.walk());
+    assert_eq!(
+        all,
+        [
+            "a",
+            "b.txt",
+            "c",
+            "a/deep",
+            "a/x.txt",
+            "a/deep/y.txt",
+            "c/z.txt"
+        ]
+    );
+
+    let shallow = relative_paths(root, dir.walk().max_depth(2));
+    assert_eq!(shallow, ["a", "b.txt", "c", "a/deep", "a/x.txt", "c/z.txt"]);
+
+    let without_a = relative_paths(
+        root,
+        dir.walk().filter_entry(|e| e.name() != &GString::from("a")),
+    );
+    assert_eq!(without_a, ["b.txt", "c", "c/z.txt"]);
+
+    GDir::remove_dir_all(&format!("res://{root}")).unwrap();
+}
+
+#[itest]
+fn gdir_glob() {
+    let root = "dir_tests_glob";
+    create_test_tree(
+        root,
+        &[
+            "one.tscn",
+            "two.tres",
+            "levels/three.tscn",
+            "levels/four.gd",
+        ],
+    );
+
+    let dir = GDir::open(&format!("res://{root}")).unwrap();
+
+    let scenes = relative_paths(root, dir.glob("*.tscn"));
+    assert_eq!(scenes, ["one.tscn", "levels/three.tscn"]);
+
+    let levels = relative_paths(root, dir.glob("levels/*"));
+    assert_eq!(levels, ["levels/four.gd", "levels/three.tscn"]);
+
+    GDir::remove_dir_all(&format!("res://{root}")).unwrap();
+}
+
+#[itest]
+fn gdir_create_copy_rename_remove() {
+    let root = "res://dir_tests_fs";
+    let nested = format!("{root}/one/two");
+
+    GDir::create_dir_all(&nested).unwrap();
+    assert!(GDir::exists(&nested));
+
+    // Already exists.
+    GDir::create_dir_all(&nested).unwrap();
+    let err = GDir::create_dir(&nested).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+
+    create_test_tree("dir_tests_fs", &["one/file.txt"]);
+    GDir::copy(
+        &format!("{root}/one/file.txt"),
+        &format!("{nested}/copy.txt"),
+    )
+    .unwrap();
+    GDir::rename(
+        &format!("{root}/one/file.txt"),
+        &format!("{root}/moved.txt"),
+    )
+    .unwrap();
+
+    let dir = GDir::open(root).unwrap();
+    let files = relative_paths("dir_tests_fs", dir.walk());
+    assert_eq!(files, ["moved.txt", "one", "one/two", "one/two/copy.txt"]);
+
+    // Directory is not empty.
+    assert!(GDir::remove(&format!("{root}/one")).is_err());
+
+    GDir::remove_dir_all(root).unwrap();
+    assert!(!GDir::exists(root));
+}
+
+#[itest]
+fn gdir_errors() {
+    let err = GDir::open("res://dir_tests_does_not_exist").unwrap_err();
+    assert!(err.to_string().contains("dir_tests_does_not_exist"));
+
+    let err = GDir::copy(
+        "res://dir_tests_does_not_exist.txt",
+        "res://dir_tests_copy.txt",
+    )
+    .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotFound);
+    assert!(err.to_string().contains("GodotError"));
+}
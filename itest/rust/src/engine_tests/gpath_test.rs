@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::GString;
+use godot::classes::file_access::ModeFlags;
+use godot::classes::Resource;
+use godot::obj::NewGd;
+use godot::tools::{save, try_load, GFile, GPath, GPathBuf};
+
+use crate::framework::itest;
+
+#[itest]
+fn gpath_components() {
+    let path = GPath::new("res://levels/intro.tscn");
+
+    assert_eq!(path.scheme(), Some("res"));
+    assert!(path.is_resource_path());
+    assert!(path.is_absolute());
+    assert_eq!(path.file_name(), Some("intro.tscn"));
+    assert_eq!(path.file_stem(), Some("intro"));
+    assert_eq!(path.extension(), Some("tscn"));
+    assert_eq!(path.parent(), Some(GPath::new("res://levels")));
+    assert_eq!(path.parent().unwrap().parent(), Some(GPath::new("res://")));
+    assert_eq!(GPath::new("res://").parent(), None);
+    assert_eq!(GPath::new("res://").file_name(), None);
+
+    let hidden = GPath::new("user://saves/.import");
+    assert!(hidden.is_user_path());
+    assert_eq!(hidden.file_stem(), Some(".import"));
+    assert_eq!(hidden.extension(), None);
+
+    let archive = GPath::new("/tmp/data.tar.gz");
+    assert_eq!(archive.scheme(), None);
+    assert!(archive.is_absolute());
+    assert_eq!(archive.file_stem(), Some("data.tar"));
+    assert_eq!(archive.extension(), Some("gz"));
+    assert_eq!(archive.parent(), Some(GPath::new("/tmp")));
+
+    let relative = GPath::new("icons/tool.svg");
+    assert!(relative.is_relative());
+    assert_eq!(relative.parent(), Some(GPath::new("icons")));
+    assert_eq!(GPath::new("icons").parent(), Some(GPath::new("")));
+
+    let uid = GPath::new("uid://cecaux1sm7mo0");
+    assert!(uid.is_uid());
+    assert_eq!(uid.parent(), None);
+    assert_eq!(uid.file_name(), None);
+    assert_eq!(uid.extension(), None);
+}
+
+#[itest]
+fn gpath_join_and_extension() {
+    let root = GPath::new("res://");
+    assert_eq!(root.join("icon.svg").as_str(), "res://icon.svg");
+    assert_eq!(
+        GPath::new("res://levels").join("intro.tscn").as_str(),
+        "res://levels/intro.tscn"
+    );
+
+    // Absolute paths replace the base.
+    assert_eq!(
+        GPath::new("res://levels").join("user://save.tres").as_str(),
+        "user://save.tres"
+    );
+
+    let path = GPath::new("res://player.gd");
+    assert_eq!(path.with_extension("tscn").as_str(), "res://player.tscn");
+    assert_eq!(path.with_extension("").as_str(), "res://player");
+    assert_eq!(
+        GPath::new("res://README").with_extension("md").as_str(),
+        "res://README.md"
+    );
+    assert_eq!(root.with_extension("md").as_str(), "res://");
+
+    let mut buf = GPathBuf::from("res://a");
+    buf.push("b");
+    buf.push("c.txt");
+    assert_eq!(buf.as_str(), "res://a/b/c.txt");
+    assert!(buf.pop());
+    assert_eq!(buf.as_str(), "res://a/b");
+    assert!(buf.set_extension("d"));
+    assert_eq!(buf.to_string(), "res://a/b.d");
+}
+
+#[itest]
+fn gpath_globalize_localize() {
+    let path = GPath::new("res://icon.svg");
+
+    let global = path.globalize();
+    assert!(global.is_absolute());
+    assert_eq!(global.scheme(), None);
+    assert_eq!(global.file_name(), Some("icon.svg"));
+
+    assert_eq!(global.localize(), path.to_gpath_buf());
+}
+
+#[itest]
+fn gpath_as_arg() {
+    let path = GPath::new("res://").join("gpath_test_file.txt");
+
+    let mut file = GFile::open(&path, ModeFlags::WRITE).unwrap();
+    file.write_gstring_line("gpath").unwrap();
+    drop(file);
+
+    let mut file = GFile::open(path.as_path(), ModeFlags::READ).unwrap();
+    assert_eq!(file.read_gstring_line().unwrap(), GString::from("gpath"));
+    drop(file);
+
+    std::fs::remove_file(path.globalize().as_str()).expect("remove test file");
+
+    let resource_path = GPath::new("res://gpath_test_resource.tres");
+    save(&Resource::new_gd(), resource_path);
+    assert!(try_load::<Resource>(resource_path).is_ok());
+
+    std::fs::remove_file(resource_path.globalize().as_str()).expect("remove test resource");
+}
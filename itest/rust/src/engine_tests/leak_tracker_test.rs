@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::panic::Location;
+
+use godot::classes::Node;
+use godot::obj::{Gd, GodotClass, NewAlloc};
+use godot::register::GodotClass;
+use godot::tools::{disable_leak_tracking, enable_leak_tracking, leak_report, LeakReport};
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct LeakTracked {}
+
+fn count_at(report: &LeakReport, location: &Location) -> usize {
+    report
+        .entries()
+        .iter()
+        .filter(|e| e.location.is_some_and(|loc| loc == location))
+        .map(|e| e.count)
+        .sum()
+}
+
+#[itest]
+fn leak_tracker_reports_alive_objects() {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+
+    enable_leak_tracking();
+
+    let location = Location::caller();
+    let nodes: Vec<_> = (0..3).map(|_| Node::new_alloc()).collect();
+    let report = leak_report();
+
+    let entry = report
+        .entries()
+        .iter()
+        .find(|e| e.class_name == Node::class_name() && e.count >= 3)
+        .expect("report lists alive nodes");
+    let node_location = entry.location.expect("new_alloc() records location");
+    assert_eq!(node_location.file(), location.file());
+    assert!(report.to_string().contains("Node, created at"));
+
+    for node in nodes {
+        node.free();
+    }
+
+    assert_eq!(count_at(&leak_report(), node_location), 0);
+    disable_leak_tracking();
+}
+
+#[itest]
+fn leak_tracker_user_objects() {
+    if !cfg!(debug_assertions) {
+        return;
+    }
+
+    enable_leak_tracking();
+
+    let obj: Gd<LeakTracked> = Gd::from_object(LeakTracked {});
+    let report = leak_report();
+    let entry = report
+        .entries()
+        .iter()
+        .find(|e| e.class_name == LeakTracked::class_name())
+        .expect("report lists user object");
+    assert_eq!(entry.location.map(|loc| loc.file()), Some(file!()));
+
+    obj.free();
+    let report = leak_report();
+    assert!(report
+        .entries()
+        .iter()
+        .all(|e| e.class_name != LeakTracked::class_name()));
+
+    disable_leak_tracking();
+}
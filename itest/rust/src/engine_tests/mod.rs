@@ -10,12 +10,16 @@ mod async_test;
 mod codegen_enums_test;
 mod codegen_test;
 mod engine_enum_test;
+mod gdir_test;
 mod gfile_test;
+mod gpath_test;
+mod leak_tracker_test;
 mod match_class_test;
 mod native_st_niche_audio_test;
 mod native_st_niche_pointer_test;
 mod native_structures_test;
 mod node_test;
 mod save_load_test;
+mod text_format_test;
 mod translate_test;
 mod utilities_test;
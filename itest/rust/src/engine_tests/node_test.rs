@@ -9,7 +9,7 @@ use std::str::FromStr;
 
 use godot::builtin::{vslice, NodePath};
 use godot::classes::{Node, Node3D};
-use godot::obj::NewAlloc;
+use godot::obj::{Gd, Inherits, NewAlloc};
 
 use crate::framework::{itest, TestContext};
 
@@ -74,3 +74,107 @@ fn node_call_group(ctx: &TestContext) {
     tree.call_group("group", "remove_meta", vslice!["something"]);
     assert!(!node.has_meta("something"));
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Tree traversal
+
+/// Creates the following tree, with `*` marking `Node3D` (others are `Node`):
+/// ```text
+/// root
+/// ├── a*
+/// │   └── a1*
+/// ├── b
+/// │   └── b1*
+/// └── c*
+/// ```
+fn make_tree() -> Gd<Node> {
+    fn add<T: Inherits<Node> + NewAlloc>(parent: &mut Gd<Node>, name: &str) -> Gd<Node> {
+        let mut child = T::new_alloc().upcast::<Node>();
+        child.set_name(name);
+        parent.add_child(&child);
+        child
+    }
+
+    let mut root = Node::new_alloc();
+    root.set_name("root");
+
+    let mut a = add::<Node3D>(&mut root, "a");
+    add::<Node3D>(&mut a, "a1");
+    let mut b = add::<Node>(&mut root, "b");
+    add::<Node3D>(&mut b, "b1");
+    add::<Node3D>(&mut root, "c");
+
+    root
+}
+
+fn names<T: Inherits<Node>>(nodes: impl Iterator<Item = Gd<T>>) -> Vec<String> {
+    nodes
+        .map(|node| node.upcast::<Node>().get_name().to_string())
+        .collect()
+}
+
+#[itest]
+fn node_children_of() {
+    let root = make_tree();
+
+    assert_eq!(names(root.children_of::<Node3D>()), ["a", "c"]);
+    assert_eq!(names(root.children_of::<Node>()), ["a", "b", "c"]);
+
+    root.free();
+}
+
+#[itest]
+fn node_descendants_of() {
+    let root = make_tree();
+
+    let depth_first = names(root.descendants_of::<Node3D>());
+    assert_eq!(depth_first, ["a", "a1", "b1", "c"]);
+
+    let breadth_first = names(root.descendants_of::<Node3D>().breadth_first());
+    assert_eq!(breadth_first, ["a", "c", "a1", "b1"]);
+
+    let all = names(root.descendants_of::<Node>());
+    assert_eq!(all, ["a", "a1", "b", "b1", "c"]);
+
+    root.free();
+}
+
+#[itest]
+fn node_ancestors() {
+    let root = make_tree();
+    let a1 = root.get_node_as::<Node3D>("a/a1");
+
+    assert_eq!(names(a1.ancestors()), ["a", "root"]);
+    assert_eq!(root.ancestors().count(), 0);
+
+    root.free();
+}
+
+#[itest]
+fn node_find_first_and_group() {
+    let root = make_tree();
+
+    let found = root.find_first::<Node3D>(|node| node.get_name() == "b1".into());
+    assert_eq!(
+        found.map(|n| n.get_name().to_string()),
+        Some("b1".to_string())
+    );
+
+    let missing = root.find_first::<Node3D>(|node| node.get_name() == "b".into());
+    assert!(missing.is_none(), "b is not a Node3D");
+
+    root.get_node_as::<Node>("a/a1").add_to_group("marked");
+    root.get_node_as::<Node>("b").add_to_group("marked");
+    root.get_node_as::<Node>("b/b1").add_to_group("marked");
+
+    assert_eq!(
+        names(root.nodes_in_group_as::<Node3D>("marked")),
+        ["a1", "b1"]
+    );
+    assert_eq!(
+        names(root.nodes_in_group_as::<Node>("marked")),
+        ["a1", "b", "b1"]
+    );
+
+    root.free();
+}
@@ -7,9 +7,10 @@
 
 use godot::classes;
 use godot::classes::notify::NodeNotification;
+use godot::global::Error;
 use godot::obj::{Base, Gd, NewAlloc, NewGd, OnReady};
 use godot::register::GodotClass;
-use godot::tools::{load, save, try_load, try_save};
+use godot::tools::{load, save, try_load, try_save, ResourceFormat};
 
 use crate::framework::itest;
 
@@ -38,6 +39,25 @@ struct GameLoader {
     _base: Base<classes::Node>,
 }
 
+// Custom binary format: level as little-endian u32.
+#[derive(GodotClass)]
+#[class(no_init, resource_format = (extensions = ["savedgame"], resource = SavedGame))]
+struct SavedGameFormat {}
+
+impl ResourceFormat<SavedGame> for SavedGameFormat {
+    fn load(bytes: &[u8]) -> Result<Gd<SavedGame>, Error> {
+        let bytes: [u8; 4] = bytes.try_into().map_err(|_| Error::ERR_FILE_CORRUPT)?;
+
+        let mut game = SavedGame::new_gd();
+        game.bind_mut().level = u32::from_le_bytes(bytes);
+        Ok(game)
+    }
+
+    fn save(resource: &Gd<SavedGame>) -> Result<Vec<u8>, Error> {
+        Ok(resource.bind().level.to_le_bytes().to_vec())
+    }
+}
+
 const RESOURCE_NAME: &str = "test_resource.tres";
 const FAULTY_PATH: &str = "no_such_path";
 
@@ -99,3 +119,46 @@ fn load_with_onready() {
 
     remove_test_file(RESOURCE_NAME);
 }
+
+#[itest]
+fn resource_format_save() {
+    let file_name = "test_resource_format_save.savedgame";
+
+    let mut resource = SavedGame::new_gd();
+    resource.bind_mut().set_level(0x01020304);
+    save(&resource, &format!("res://{file_name}"));
+
+    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    let bytes = std::fs::read(godot_path.join(file_name)).expect("file written by saver");
+    assert_eq!(bytes, [4, 3, 2, 1]);
+
+    remove_test_file(file_name);
+}
+
+#[itest]
+fn resource_format_load() {
+    let file_name = "test_resource_format_load.savedgame";
+    let res_path = format!("res://{file_name}");
+
+    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    std::fs::write(godot_path.join(file_name), 7531u32.to_le_bytes()).expect("write test file");
+
+    let loaded = load::<SavedGame>(&res_path);
+    assert_eq!(loaded.bind().get_level(), 7531);
+
+    remove_test_file(file_name);
+}
+
+#[itest]
+fn resource_format_load_error() {
+    let file_name = "test_resource_format_corrupt.savedgame";
+    let res_path = format!("res://{file_name}");
+
+    let godot_path = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../godot/"));
+    std::fs::write(godot_path.join(file_name), [1, 2]).expect("write test file");
+
+    let res = try_load::<SavedGame>(&res_path);
+    assert!(res.is_err());
+
+    remove_test_file(file_name);
+}
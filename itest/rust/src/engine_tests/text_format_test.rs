@@ -0,0 +1,287 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::builtin::*;
+use godot::global::{str_to_var, var_to_str};
+use godot::meta::ToGodot;
+use godot::tools::{TextResource, TextSection, TextValue};
+
+use crate::framework::itest;
+
+fn sample_variants() -> Vec<Variant> {
+    vec![
+        Variant::nil(),
+        true.to_variant(),
+        (-42).to_variant(),
+        1.5.to_variant(),
+        (-0.25e-7).to_variant(),
+        "multi\nline \"quoted\" \\ text ✓".to_variant(),
+        StringName::from("name").to_variant(),
+        NodePath::from("Parent/Child:position").to_variant(),
+        Vector2::new(1.0, -2.5).to_variant(),
+        Vector2i::new(3, -4).to_variant(),
+        Rect2::new(Vector2::new(0.5, 1.0), Vector2::new(2.0, 3.0)).to_variant(),
+        Rect2i::new(Vector2i::new(1, 2), Vector2i::new(3, 4)).to_variant(),
+        Vector3::new(1.0, 2.25, -3.0).to_variant(),
+        Vector3i::new(1, 2, 3).to_variant(),
+        Transform2D::from_cols(
+            Vector2::new(1.0, 2.0),
+            Vector2::new(3.0, 4.0),
+            Vector2::new(5.0, 6.0),
+        )
+        .to_variant(),
+        Vector4::new(1.0, 2.0, 3.0, 4.5).to_variant(),
+        Vector4i::new(-1, 0, 1, 2).to_variant(),
+        Plane {
+            normal: Vector3::new(0.0, 1.0, 0.0),
+            d: 2.5,
+        }
+        .to_variant(),
+        Quaternion::new(0.0, 0.5, 0.5, 0.0).to_variant(),
+        Aabb::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 5.0, 6.0)).to_variant(),
+        Basis::from_cols(
+            Vector3::new(1.0, 2.0, 3.0),
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        )
+        .to_variant(),
+        Transform3D::new(
+            Basis::from_cols(
+                Vector3::new(1.0, 2.0, 3.0),
+                Vector3::new(4.0, 5.0, 6.0),
+                Vector3::new(7.0, 8.0, 9.0),
+            ),
+            Vector3::new(10.0, 11.0, 12.0),
+        )
+        .to_variant(),
+        Projection::from_cols(
+            Vector4::new(1.0, 2.0, 3.0, 4.0),
+            Vector4::new(5.0, 6.0, 7.0, 8.0),
+            Vector4::new(9.0, 10.0, 11.0, 12.0),
+            Vector4::new(13.0, 14.0, 15.0, 16.0),
+        )
+        .to_variant(),
+        Color::from_rgba(1.0, 0.5, 0.25, 0.75).to_variant(),
+        PackedByteArray::from(&[0u8, 1, 254, 255][..]).to_variant(),
+        PackedInt32Array::from(&[1, -2, 3][..]).to_variant(),
+        PackedInt64Array::from(&[i64::MAX, i64::MIN][..]).to_variant(),
+        PackedFloat32Array::from(&[0.5f32, -1.0][..]).to_variant(),
+        PackedFloat64Array::from(&[0.1f64, 1e20][..]).to_variant(),
+        PackedStringArray::from(&[GString::from("a"), GString::from("b\"c")][..]).to_variant(),
+        PackedVector2Array::from(&[Vector2::new(1.0, 2.0), Vector2::new(3.0, 4.5)][..])
+            .to_variant(),
+        PackedVector3Array::from(&[Vector3::new(1.0, 2.0, 3.0)][..]).to_variant(),
+        PackedColorArray::from(&[Color::from_rgba(0.0, 0.5, 1.0, 1.0)][..]).to_variant(),
+        varray![1, "two", Vector2i::new(3, 4), varray![]].to_variant(),
+        vdict! {
+            "key": 1,
+            2: varray![true, Variant::nil()],
+            (StringName::from("nested")): vdict! { "x": 1.5 },
+        }
+        .to_variant(),
+    ]
+}
+
+#[itest]
+fn text_format_parse_var_to_str() {
+    for variant in sample_variants() {
+        let text = var_to_str(&variant).to_string();
+
+        let value =
+            TextValue::parse(&text).unwrap_or_else(|err| panic!("failed to parse {text:?}: {err}"));
+        let parsed = value.to_variant().expect("builtin value");
+
+        assert_eq!(parsed, variant, "parsed from {text:?}");
+    }
+}
+
+#[itest]
+fn text_format_write_str_to_var() {
+    for variant in sample_variants() {
+        let value = TextValue::from_variant(&variant).expect("builtin value");
+        let text = value.to_string();
+
+        assert_eq!(str_to_var(&text), variant, "written as {text:?}");
+
+        // Writing is stable across a roundtrip through the parser.
+        assert_eq!(TextValue::parse(&text).unwrap().to_string(), text);
+    }
+}
+
+#[itest]
+fn text_format_write_matches_var_to_str() {
+    // Floats are only compared if Godot's fixed precision and the shortest representation agree (see `TextValue` docs); other floats are
+    // covered by the roundtrip tests above.
+    let variants = [
+        Variant::nil(),
+        false.to_variant(),
+        (-7).to_variant(),
+        2.0.to_variant(),
+        1e20.to_variant(),
+        (-0.5).to_variant(),
+        "text \"quoted\"".to_variant(),
+        StringName::from("name").to_variant(),
+        Vector2::new(1.0, 2.5).to_variant(),
+        Vector3i::new(1, 2, 3).to_variant(),
+        varray![1, "two"].to_variant(),
+        vdict! { "a": 1 }.to_variant(),
+        VariantArray::new().to_variant(),
+        PackedInt32Array::from(&[1, 2][..]).to_variant(),
+        PackedFloat64Array::from(&[0.5, 1e20][..]).to_variant(),
+        PackedStringArray::from(&[GString::from("a")][..]).to_variant(),
+        // Written as list by `var_to_str()`; base64 is only used in resource files.
+        PackedByteArray::from(&[0u8, 1, 254, 255][..]).to_variant(),
+    ];
+
+    for variant in variants {
+        let value = TextValue::from_variant(&variant).unwrap();
+        assert_eq!(value.to_string(), var_to_str(&variant).to_string());
+    }
+
+    // Differences: base64 byte arrays are parsed, but written as list.
+    let bytes = TextValue::parse(r#"PackedByteArray("AAH+/w==")"#).unwrap();
+    assert_eq!(bytes.to_string(), "PackedByteArray(0, 1, 254, 255)");
+    assert_eq!(
+        bytes.to_variant(),
+        Some(PackedByteArray::from(&[0u8, 1, 254, 255][..]).to_variant())
+    );
+}
+
+#[itest]
+fn text_format_parse_syntax() {
+    let value = TextValue::parse(
+        r#"; comment
+        {
+            ^"path": Array[ExtResource("1_abc")]([SubResource("Res_1"), Resource("res://a.tres")]),
+            "typed": Dictionary[String, int]({ "a": 1, }),
+            "object": Object(Node,"name":"Child","visible":false),
+            "old": Quat(0, 0, 0, 1),
+            "bytes": PackedByteArray("AAH+/w=="),
+            "escapes": "\té\U01F600",
+        }"#,
+    )
+    .unwrap();
+
+    let entries = value.as_dictionary().unwrap();
+    assert_eq!(entries.len(), 6);
+    assert_eq!(entries[0].0, TextValue::NodePath("path".into()));
+    assert_eq!(
+        entries[0].1.to_string(),
+        r#"Array[ExtResource("1_abc")]([SubResource("Res_1"), Resource("res://a.tres")])"#
+    );
+    assert_eq!(
+        entries[1].1.to_string(),
+        "Dictionary[String, int]({\n\"a\": 1\n})"
+    );
+    assert_eq!(
+        entries[2].1,
+        TextValue::Object {
+            class: "Node".into(),
+            properties: vec![
+                ("name".into(), TextValue::String("Child".into())),
+                ("visible".into(), TextValue::Bool(false)),
+            ],
+        }
+    );
+    assert_eq!(entries[3].1, TextValue::Quaternion(Quaternion::default()));
+    assert_eq!(
+        entries[4].1,
+        TextValue::PackedByteArray(vec![0, 1, 254, 255])
+    );
+    assert_eq!(entries[5].1.as_str(), Some("\té😀"));
+
+    let err = TextValue::parse("Vector2(1, 2, 3)").unwrap_err();
+    assert_eq!((err.line(), err.column()), (1, 17));
+    assert!(TextValue::parse("[1, 2").is_err());
+    assert!(TextValue::parse("Unknown(1)").is_err());
+    assert!(TextValue::parse("1 2").is_err());
+}
+
+#[itest]
+fn text_format_resource_file() {
+    let text = r#"[gd_scene load_steps=3 format=3 uid="uid://b8lxd1s0xkq6n"]
+
+[ext_resource type="Script" path="res://player.gd" id="1_x1"]
+[ext_resource type="Texture2D" path="res://icon.svg" id="2_y2"]
+
+[sub_resource type="RectangleShape2D" id="RectangleShape2D_a"]
+size = Vector2(32, 64)
+
+[node name="Player" type="CharacterBody2D"]
+script = ExtResource("1_x1")
+metadata/_edit_group_ = true
+
+[node name="Sprite" type="Sprite2D" parent="."]
+texture = ExtResource("2_y2")
+"animation data" = {
+"idle": PackedInt32Array(0, 1, 2)
+}
+"#;
+
+    let scene = TextResource::parse(text).unwrap();
+    assert_eq!(scene.sections.len(), 6);
+    assert_eq!(scene.sections_with_tag("ext_resource").count(), 2);
+
+    let shape = scene.section("sub_resource").unwrap();
+    assert_eq!(
+        shape.property("size"),
+        Some(&TextValue::Vector2(Vector2::new(32.0, 64.0)))
+    );
+
+    let player = scene.section("node").unwrap();
+    assert_eq!(
+        player.attribute("name").and_then(TextValue::as_str),
+        Some("Player")
+    );
+    assert_eq!(
+        player.property("metadata/_edit_group_"),
+        Some(&TextValue::Bool(true))
+    );
+
+    // Roundtrip, including blank lines between sections and quoted keys.
+    assert_eq!(scene.to_string(), text);
+}
+
+#[itest]
+fn text_format_config_file() {
+    let text = r#"; Engine configuration file.
+
+config_version=5
+
+[application]
+
+config/name="Demo"
+config/features=PackedStringArray("4.3")
+
+[display]
+
+window/size/viewport_width=1280
+"#;
+
+    let config = TextResource::parse(text).unwrap();
+    assert_eq!(config.property("config_version"), Some(&TextValue::Int(5)));
+
+    let application = config.section("application").unwrap();
+    assert_eq!(
+        application
+            .property("config/name")
+            .and_then(TextValue::as_str),
+        Some("Demo")
+    );
+
+    // Comments are not preserved.
+    let expected = text.trim_start_matches("; Engine configuration file.\n\n");
+    assert_eq!(config.to_config_string(), expected);
+
+    let mut built = TextResource::new();
+    let mut section = TextSection::new("input");
+    section
+        .properties
+        .push(("ui_accept".into(), TextValue::Dictionary(Vec::new())));
+    built.sections.push(section);
+    assert_eq!(built.to_config_string(), "[input]\n\nui_accept={}\n");
+}
@@ -7,6 +7,8 @@
 
 use godot::builtin::Vector2;
 use godot::tools::{tr, tr_n};
+#[cfg(feature = "translation-template")]
+use godot::tools::{TranslationMessage, TranslationTemplate};
 
 use crate::framework::itest;
 
@@ -47,3 +49,41 @@ fn tr_n_macro_format_plural() {
     let hello = tr_n!(n; "Hello singular {}!", "Hello plural {}s!", "world");
     assert_eq!(hello.to_string(), "Hello plural worlds!");
 }
+
+#[cfg(feature = "translation-template")]
+#[itest]
+fn tr_messages_recorded() {
+    // Recorded at load time, even if never executed.
+    if false {
+        tr!("menu"; "Quit");
+        tr_n!(1, "menu"; "{{1}} file", "{{n}} files");
+        tr!("Score: {}", 10);
+    }
+
+    let messages: Vec<TranslationMessage> = TranslationMessage::all()
+        .into_iter()
+        .filter(|message| message.file().ends_with("translate_test.rs"))
+        .collect();
+
+    let quit = messages
+        .iter()
+        .find(|message| message.singular() == "Quit")
+        .expect("tr!() message recorded");
+    assert_eq!(quit.context(), Some("menu"));
+    assert_eq!(quit.plural(), None);
+
+    let files = messages
+        .iter()
+        .find(|message| message.plural() == Some("{{n}} files"))
+        .expect("tr_n!() message recorded");
+    assert_eq!(files.context(), Some("menu"));
+    assert_eq!(files.line(), quit.line() + 1);
+
+    let template = TranslationTemplate::from_recorded();
+    assert!(template.contains("Quit", Some("menu")));
+    assert!(template.contains("{1} file", Some("menu")));
+    assert!(!template.contains("Score: {}", None));
+
+    let pot = template.to_string();
+    assert!(pot.contains("msgctxt \"menu\"\nmsgid \"{1} file\"\nmsgid_plural \"{n} files\"\n"));
+}
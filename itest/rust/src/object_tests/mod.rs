@@ -27,6 +27,9 @@ mod phantom_var_test;
 mod property_template_test;
 mod property_test;
 mod reentrant_test;
+// `#[func(virtual)]` is only supported in Godot 4.3+.
+#[cfg(since_api = "4.3")]
+mod rust_inheritance_test;
 mod singleton_test;
 // `validate_property` is only supported in Godot 4.2+.
 #[cfg(since_api = "4.2")]
@@ -35,6 +38,7 @@ mod base_init_test;
 mod validate_property_test;
 mod virtual_methods_niche_test;
 mod virtual_methods_test;
+mod weak_gd_test;
 
 // Need to test this in the init level method.
 pub use init_level_test::initialize_init_level_test;
@@ -236,6 +236,37 @@ fn object_user_bind_after_free() {
     });
 }
 
+#[itest]
+fn object_user_try_bind() {
+    let mut obj = RefcPayload::new_gd();
+    let mut copy = obj.clone();
+
+    {
+        let guard = obj.bind();
+        let shared = obj.try_bind().expect("shared borrows can coexist");
+        assert_eq!(guard.value, shared.value);
+
+        let err = copy
+            .try_bind_mut()
+            .expect_err("try_bind_mut() while bound must fail");
+        assert!(err.is_mut());
+        assert!(err.type_name().contains("RefcPayload"));
+    }
+
+    {
+        let _guard = obj.bind_mut();
+        let err = copy
+            .try_bind()
+            .expect_err("try_bind() while mutably bound must fail");
+        assert!(!err.is_mut());
+    }
+
+    copy.try_bind_mut()
+        .expect("try_bind_mut() succeeds after guards are dropped")
+        .value = 42;
+    assert_eq!(obj.bind().value, 42);
+}
+
 #[itest]
 fn object_user_free_during_bind() {
     let obj = Gd::from_object(ObjPayload {});
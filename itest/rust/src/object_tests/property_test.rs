@@ -718,3 +718,204 @@ fn test_duplicate_retains_properties() {
     duplicated.free();
     original.free();
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct OnChangeProperty {
+    #[var(on_change = on_int_val_changed)]
+    int_val: i32,
+
+    #[export(on_change = on_string_val_changed, notify_property_list)]
+    string_val: GString,
+
+    // (old, new) pairs, in order of invocation.
+    int_changes: Vec<(i32, i32)>,
+    string_changes: Vec<(GString, GString)>,
+
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl OnChangeProperty {
+    fn on_int_val_changed(&mut self, old: i32, new: i32) {
+        self.int_changes.push((old, new));
+    }
+
+    fn on_string_val_changed(&mut self, old: GString, new: GString) {
+        self.string_changes.push((old, new));
+    }
+}
+
+#[itest]
+fn var_on_change_hook() {
+    let mut obj = OnChangeProperty::new_alloc();
+
+    obj.set("int_val", &7.to_variant());
+    obj.bind_mut().set_int_val(-3);
+    obj.call("set_int_val", vslice![-3]);
+
+    {
+        let guard = obj.bind();
+        assert_eq!(guard.int_val, -3);
+        assert_eq!(guard.int_changes, vec![(0, 7), (7, -3), (-3, -3)]);
+    }
+
+    // Direct field assignment does not go through the setter.
+    obj.bind_mut().int_val = 100;
+    assert_eq!(obj.bind().int_changes.len(), 3);
+
+    obj.free();
+}
+
+#[cfg(since_api = "4.2")]
+#[itest]
+fn export_on_change_hook_notify() {
+    let mut obj = OnChangeProperty::new_alloc();
+
+    let notified = std::rc::Rc::new(std::cell::Cell::new(0));
+    let notified_clone = notified.clone();
+    obj.signals()
+        .property_list_changed()
+        .connect(move || notified_clone.set(notified_clone.get() + 1));
+
+    obj.set("string_val", &"hello".to_variant());
+
+    assert_eq!(obj.bind().string_val, GString::from("hello"));
+    assert_eq!(
+        obj.bind().string_changes,
+        vec![(GString::new(), GString::from("hello"))]
+    );
+    assert_eq!(notified.get(), 1);
+
+    obj.free();
+}
+
+#[cfg(since_api = "4.2")]
+mod on_change_signal {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use godot::prelude::*;
+
+    use crate::framework::itest;
+
+    #[derive(GodotClass)]
+    #[class(init, base=Node)]
+    struct OnChangeSignal {
+        #[var(emit_changed)]
+        level: i64,
+
+        // `pub` field in a private class: accessor must not be more visible than the class.
+        #[var(emit_changed)]
+        pub title: GString,
+
+        base: Base<Node>,
+    }
+
+    #[godot_api]
+    impl OnChangeSignal {}
+
+    #[itest]
+    fn var_on_change_emits_signal() {
+        let mut obj = OnChangeSignal::new_alloc();
+
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let received_clone = received.clone();
+        obj.signals()
+            .level_changed()
+            .connect(move |level| received_clone.borrow_mut().push(level));
+
+        obj.set("level", &5.to_variant());
+        obj.bind_mut().set_level(6);
+
+        assert_eq!(*received.borrow(), vec![5, 6]);
+        assert!(obj.has_signal("level_changed"));
+
+        obj.free();
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(since_api = "4.4")]
+mod tool_button {
+    use godot::builtin::VariantType;
+    use godot::global::{PropertyHint, PropertyUsageFlags};
+    use godot::prelude::*;
+
+    use super::check_property;
+    use crate::framework::itest;
+
+    #[derive(GodotClass)]
+    #[class(init, tool, base=Node)]
+    struct HasToolButton {
+        #[export_tool_button(fn = bake, text = "Bake", icon = "Bake")]
+        bake_button: PhantomVar<Callable>,
+
+        #[export_tool_button(fn = reset, text = "Reset")]
+        reset_button: PhantomVar<Callable>,
+
+        bake_count: i32,
+
+        base: Base<Node>,
+    }
+
+    #[godot_api]
+    impl HasToolButton {
+        #[func]
+        fn bake(&mut self) {
+            self.bake_count += 1;
+        }
+
+        #[func(rename = reset_all)]
+        fn reset(&mut self) {
+            self.bake_count = 0;
+        }
+    }
+
+    #[itest]
+    fn export_tool_button_property() {
+        let obj = HasToolButton::new_alloc();
+
+        let property = obj
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == "bake_button".to_variant())
+            .unwrap();
+
+        check_property(&property, "type", VariantType::CALLABLE.ord());
+        check_property(&property, "hint", PropertyHint::TOOL_BUTTON.ord());
+        check_property(&property, "hint_string", "Bake,Bake");
+        check_property(&property, "usage", PropertyUsageFlags::EDITOR.ord());
+
+        let property = obj
+            .get_property_list()
+            .iter_shared()
+            .find(|c| c.get_or_nil("name") == "reset_button".to_variant())
+            .unwrap();
+
+        check_property(&property, "hint_string", "Reset");
+
+        obj.free();
+    }
+
+    #[itest]
+    fn export_tool_button_invokes_method() {
+        let obj = HasToolButton::new_alloc();
+
+        let bake = obj.get("bake_button").to::<Callable>();
+        bake.call(&[]);
+        bake.call(&[]);
+        assert_eq!(obj.bind().bake_count, 2);
+
+        // Renamed #[func] is resolved through its registered name.
+        let reset = obj.get("reset_button").to::<Callable>();
+        assert_eq!(reset.method_name(), Some(StringName::from("reset_all")));
+        reset.call(&[]);
+        assert_eq!(obj.bind().bake_count, 0);
+
+        obj.free();
+    }
+}
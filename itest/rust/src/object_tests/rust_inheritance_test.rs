@@ -0,0 +1,114 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::classes::ClassDb;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(base=RefCounted)]
+struct Character {
+    #[var]
+    health: i64,
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl IRefCounted for Character {
+    fn init(base: Base<RefCounted>) -> Self {
+        Self { health: 100, base }
+    }
+}
+
+#[godot_api]
+impl Character {
+    #[func]
+    fn heal(&mut self, amount: i64) {
+        self.health += amount;
+    }
+
+    #[func(virtual)]
+    fn damage(&mut self, amount: i64) {
+        self.health -= amount;
+    }
+
+    #[func]
+    fn take_hit(&mut self) {
+        self.damage(10);
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, base=Character, rust_base)]
+struct Enemy {
+    hits_blocked: i32,
+    base: Base<Character>,
+}
+
+#[godot_api]
+impl Enemy {
+    #[func(virtual)]
+    fn damage(&mut self, _amount: i64) {
+        self.hits_blocked += 1;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[itest]
+fn rust_inheritance_registers_parent_class() {
+    let parent = ClassDb::singleton().get_parent_class("Enemy");
+    assert_eq!(parent, StringName::from("Character"));
+}
+
+#[itest]
+fn rust_inheritance_upcast() {
+    let enemy = Enemy::new_gd();
+    let character: Gd<Character> = enemy.clone().upcast();
+    assert_eq!(character.bind().health, 100);
+
+    let object: Gd<RefCounted> = enemy.clone().upcast();
+    let back: Gd<Enemy> = object.cast();
+    assert_eq!(back, enemy);
+
+    let as_character = enemy.upcast::<Object>().try_cast::<Character>();
+    assert!(as_character.is_ok());
+}
+
+#[itest]
+fn rust_inheritance_inherited_func_and_var() {
+    let enemy = Enemy::new_gd();
+    let mut object = enemy.clone().upcast::<Object>();
+
+    object.call("heal", &[25.to_variant()]);
+    assert_eq!(object.get("health"), 125.to_variant());
+
+    let character: Gd<Character> = enemy.upcast();
+    assert_eq!(character.bind().health, 125);
+}
+
+#[itest]
+fn rust_inheritance_virtual_override() {
+    let enemy = Enemy::new_gd();
+    let mut character: Gd<Character> = enemy.clone().upcast();
+
+    // Called from Rust, dispatched to the override.
+    character.bind_mut().take_hit();
+    assert_eq!(character.bind().health, 100);
+    assert_eq!(enemy.bind().hits_blocked, 1);
+
+    // Called from Godot, also dispatched to the override.
+    character.call("_damage", &[5.to_variant()]);
+    assert_eq!(character.bind().health, 100);
+    assert_eq!(enemy.bind().hits_blocked, 2);
+
+    // Plain instances of the base class keep their own implementation.
+    let mut plain = Character::new_gd();
+    plain.bind_mut().take_hit();
+    assert_eq!(plain.bind().health, 90);
+}
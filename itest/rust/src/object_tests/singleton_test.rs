@@ -6,8 +6,9 @@
  */
 
 use godot::builtin::GString;
-use godot::classes::{Input, Os};
+use godot::classes::{ClassDb, Engine, Input, Object, Os};
 use godot::obj::Gd;
+use godot::register::GodotClass;
 
 use crate::framework::itest;
 
@@ -44,3 +45,42 @@ fn singleton_is_operational() {
     let read_value = os.get_environment(&key);
     assert_eq!(read_value, value);
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// User-defined singletons
+
+#[derive(GodotClass)]
+#[class(base=Object, init, singleton)]
+struct UserSingleton {
+    counter: i32,
+}
+
+#[itest]
+fn user_singleton_is_unique() {
+    let a: Gd<UserSingleton> = UserSingleton::singleton();
+    let b: Gd<UserSingleton> = UserSingleton::singleton();
+    assert_eq!(a.instance_id(), b.instance_id());
+
+    let before = a.bind().counter;
+    UserSingleton::singleton().bind_mut().counter += 1;
+    assert_eq!(b.bind().counter, before + 1);
+}
+
+#[itest]
+fn user_singleton_registered_with_engine() {
+    let engine = Engine::singleton();
+    assert!(engine.has_singleton("UserSingleton"));
+
+    let from_engine: Gd<Object> = engine
+        .get_singleton("UserSingleton")
+        .expect("singleton registered");
+    assert_eq!(
+        from_engine.instance_id(),
+        UserSingleton::singleton().instance_id()
+    );
+}
+
+#[itest]
+fn user_singleton_not_instantiable() {
+    assert!(!ClassDb::singleton().can_instantiate("UserSingleton"));
+}
@@ -0,0 +1,77 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::obj::WeakGd;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[itest]
+fn weak_gd_does_not_keep_alive() {
+    let obj = RefCounted::new_gd();
+    let weak = obj.downgrade();
+
+    assert_eq!(obj.get_reference_count(), 1);
+    assert!(weak.is_alive());
+    assert_eq!(weak.instance_id(), Some(obj.instance_id()));
+
+    let upgraded = weak.upgrade().expect("object is alive");
+    assert_eq!(upgraded, obj);
+    assert_eq!(obj.get_reference_count(), 2);
+    drop(upgraded);
+
+    drop(obj);
+    assert!(!weak.is_alive());
+    assert!(weak.upgrade().is_none());
+    assert!(!weak.is_empty(), "handle stays non-empty after destruction");
+}
+
+#[itest]
+fn weak_gd_manual_free() {
+    let node = Node::new_alloc();
+    let weak: WeakGd<Node> = node.downgrade();
+    assert_eq!(weak.upgrade(), Some(node.clone()));
+
+    node.free();
+    assert!(weak.upgrade().is_none());
+}
+
+#[itest]
+fn weak_gd_empty() {
+    let weak = WeakGd::<Object>::default();
+    assert!(weak.is_empty());
+    assert!(!weak.is_alive());
+    assert_eq!(weak.instance_id(), None);
+    assert!(weak.upgrade().is_none());
+    assert_eq!(weak, WeakGd::default());
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct WeakGdHolder {
+    #[var]
+    target: WeakGd<RefCounted>,
+}
+
+#[itest]
+fn weak_gd_property() {
+    let mut holder = WeakGdHolder::new_gd();
+    assert_eq!(holder.get("target"), Variant::nil());
+
+    let target = RefCounted::new_gd();
+    holder.set("target", &target.to_variant());
+    assert_eq!(
+        target.get_reference_count(),
+        1,
+        "property must not hold a strong reference"
+    );
+    assert_eq!(holder.get("target"), target.to_variant());
+    assert_eq!(holder.bind().target.upgrade(), Some(target.clone()));
+
+    drop(target);
+    assert_eq!(holder.get("target"), Variant::nil());
+}
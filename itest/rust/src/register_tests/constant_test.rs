@@ -232,3 +232,104 @@ test_enum_export!(
     #[itest]
     fn bitfield_export_correct_values() { .. }
 );
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// #[export_enum]
+
+#[derive(GodotConvert, Var, Export, EngineEnum, Copy, Clone, Default, Eq, PartialEq, Debug)]
+#[godot(via = i64)]
+enum ExportedState {
+    #[default]
+    Idle = 0,
+    Running = 3,
+    Jumping = 7,
+}
+
+#[derive(EngineBitfield, Copy, Clone, Default, Eq, PartialEq, Debug)]
+#[godot(flags = (FIRE = 1, WATER = 2, EARTH = 4))]
+struct ExportedElements(u64);
+
+#[derive(GodotClass)]
+#[class(init)]
+struct HasExportedEnums {
+    #[var]
+    state: ExportedState,
+
+    #[var]
+    elements: ExportedElements,
+}
+
+#[godot_api]
+impl HasExportedEnums {
+    #[export_enum]
+    type State = ExportedState;
+
+    #[export_enum]
+    type Elements = ExportedElements;
+
+    #[func]
+    fn set_state(&mut self, state: ExportedState) {
+        self.state = state;
+    }
+}
+
+#[itest]
+fn export_enum_registers_class_enum() {
+    let class_name = HasExportedEnums::class_name().to_string_name();
+    let db = ClassDb::singleton();
+
+    assert!(db
+        .class_has_enum_ex(&class_name, "State")
+        .no_inheritance(true)
+        .done());
+    assert!(!db.is_class_enum_bitfield(&class_name, "State"));
+
+    let constants = db
+        .class_get_enum_constants_ex(&class_name, "State")
+        .no_inheritance(true)
+        .done();
+    for name in ["Idle", "Running", "Jumping"] {
+        assert!(constants.contains(&GString::from(name)));
+    }
+    assert_eq!(db.class_get_integer_constant(&class_name, "Running"), 3);
+
+    assert!(db.is_class_enum_bitfield(&class_name, "Elements"));
+    assert_eq!(db.class_get_integer_constant(&class_name, "EARTH"), 4);
+}
+
+#[itest]
+fn export_enum_class_name_in_metadata() {
+    let class_name = HasExportedEnums::class_name().to_string_name();
+    let db = ClassDb::singleton();
+
+    let methods = db
+        .class_get_method_list_ex(&class_name)
+        .no_inheritance(true)
+        .done();
+    let set_state = methods
+        .iter_shared()
+        .find(|method| method.at("name") == "set_state".to_variant())
+        .expect("set_state registered");
+    let args = set_state.at("args").to::<VariantArray>();
+    assert_eq!(
+        args.at(0).to::<Dictionary>().at("class_name"),
+        "HasExportedEnums.State".to_variant()
+    );
+
+    let properties = db
+        .class_get_property_list_ex(&class_name)
+        .no_inheritance(true)
+        .done();
+    let elements = properties
+        .iter_shared()
+        .find(|prop| prop.at("name") == "elements".to_variant())
+        .expect("elements property registered");
+    assert_eq!(
+        elements.at("class_name"),
+        "HasExportedEnums.Elements".to_variant()
+    );
+
+    let bits = ExportedElements::FIRE | ExportedElements::EARTH;
+    assert_eq!(bits.ord(), 5);
+    assert_eq!(ExportedState::from_ord(7), ExportedState::Jumping);
+}
@@ -0,0 +1,38 @@
+/*
+ * Copyright (c) godot-rust; Bromeon and contributors.
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::init::{class_icons, compatibility_minimum, ExtensionEntrySymbol, GdextBuild};
+use godot::prelude::*;
+
+use crate::framework::{itest, IntegrationTests};
+
+#[derive(GodotClass)]
+#[class(no_init, icon = "res://icons/manifest_icon.svg")]
+struct ManifestIconClass {}
+
+#[itest]
+fn manifest_entry_symbol() {
+    assert_eq!(IntegrationTests::ENTRY_SYMBOL, "itest_init");
+}
+
+#[itest]
+fn manifest_compatibility_minimum() {
+    let (major, minor, _patch) = GdextBuild::godot_static_version_triple();
+
+    assert_eq!(compatibility_minimum(), format!("{major}.{minor}"));
+}
+
+#[itest]
+fn manifest_class_icons() {
+    let icons = class_icons();
+
+    assert!(icons.contains(&(
+        "ManifestIconClass".to_string(),
+        "res://icons/manifest_icon.svg".to_string()
+    )));
+    assert!(icons.is_sorted());
+}